@@ -0,0 +1,916 @@
+use std::{collections::HashMap, slice};
+
+use crate::{
+    assembly_gen::*,
+    ast::Constant,
+    code_emission::is_zero,
+    elf::{Object, Relocation, RelocationKind, Section, SectionKind, Symbol, SymbolKind},
+    sema::StaticInit,
+};
+
+const TEXT: usize = 0;
+const DATA: usize = 1;
+const BSS: usize = 2;
+const RODATA: usize = 3;
+
+/// Assembles `program` into an ELF relocatable object, with the same contents the system
+/// assembler makes of the assembly [`emit_asm`](crate::code_emission::emit_asm) writes for
+/// Linux.
+pub fn assemble(program: &Program) -> Object {
+    let section = |name, kind| Section {
+        name,
+        kind,
+        data: vec![],
+        alignment: 1,
+        relocations: vec![],
+    };
+    let mut assembler = Assembler {
+        object: Object {
+            sections: vec![
+                section(".text", SectionKind::Text),
+                section(".data", SectionKind::Data),
+                section(".bss", SectionKind::Bss),
+                section(".rodata", SectionKind::ReadOnly),
+                // without this note the linker assumes the program needs an executable stack
+                section(".note.GNU-stack", SectionKind::Note),
+            ],
+            symbols: vec![],
+        },
+        symbols: HashMap::new(),
+    };
+    for sv in program.static_variables.iter() {
+        let section = if sv.read_only {
+            RODATA
+        } else if sv.init.iter().all(is_zero) {
+            BSS
+        } else {
+            DATA
+        };
+        assembler.object_symbol(sv.global, &sv.name, section, sv.alignment, &sv.init);
+    }
+    for sc in program.static_constants.iter() {
+        let init = slice::from_ref(&sc.init);
+        assembler.object_symbol(false, &sc.name, RODATA, sc.alignment, init);
+    }
+    assembler.text(program);
+    assembler.resolve_local_references();
+    assembler.object
+}
+
+struct Assembler {
+    object: Object,
+    /// The indices of the symbols in the object, by name.
+    symbols: HashMap<String, usize>,
+}
+
+impl Assembler {
+    /// The index of the symbol `name`, which is taken to be defined in another object until
+    /// it is defined in this one.
+    fn symbol(&mut self, name: &str) -> usize {
+        if let Some(&i) = self.symbols.get(name) {
+            return i;
+        }
+        self.object.symbols.push(Symbol {
+            name: name.to_string(),
+            section: None,
+            value: 0,
+            size: 0,
+            global: true,
+            kind: SymbolKind::NoType,
+        });
+        self.symbols
+            .insert(name.to_string(), self.object.symbols.len() - 1);
+        self.object.symbols.len() - 1
+    }
+
+    fn define(&mut self, name: &str, global: bool, section: usize, value: u64, kind: SymbolKind) {
+        let i = self.symbol(name);
+        self.object.symbols[i] = Symbol {
+            name: name.to_string(),
+            section: Some(section),
+            value,
+            size: 0,
+            global,
+            kind,
+        };
+    }
+
+    fn relocate(
+        &mut self,
+        section: usize,
+        offset: usize,
+        name: &str,
+        kind: RelocationKind,
+        addend: i64,
+    ) {
+        let symbol = self.symbol(name);
+        self.object.sections[section].relocations.push(Relocation {
+            offset: offset as u64,
+            symbol,
+            kind,
+            addend,
+        });
+    }
+
+    /// Settles the relocations against symbols that are local to this object, as the system
+    /// assembler does: references from the same section are filled in, and the others refer to
+    /// the section of the symbol instead, so that local symbols are only there for debuggers.
+    fn resolve_local_references(&mut self) {
+        let mut section_symbols = HashMap::new();
+        for section in 0..self.object.sections.len() {
+            let relocations = std::mem::take(&mut self.object.sections[section].relocations);
+            for mut relocation in relocations {
+                let symbol = &self.object.symbols[relocation.symbol];
+                let Some(symbol_section) = symbol.section.filter(|_| !symbol.global) else {
+                    self.object.sections[section].relocations.push(relocation);
+                    continue;
+                };
+                let value = symbol.value as i64 + relocation.addend;
+                if symbol_section == section && relocation.kind != RelocationKind::Abs64 {
+                    let offset = relocation.offset as usize;
+                    let displacement = (value - offset as i64) as i32;
+                    let data = &mut self.object.sections[section].data;
+                    data[offset..offset + 4].copy_from_slice(&displacement.to_le_bytes());
+                    continue;
+                }
+                relocation.symbol = *section_symbols.entry(symbol_section).or_insert_with(|| {
+                    self.object.symbols.push(Symbol {
+                        name: String::new(),
+                        section: Some(symbol_section),
+                        value: 0,
+                        size: 0,
+                        global: false,
+                        kind: SymbolKind::Section,
+                    });
+                    self.object.symbols.len() - 1
+                });
+                // a call to a local function cannot go through the procedure linkage table
+                if relocation.kind == RelocationKind::Plt32 {
+                    relocation.kind = RelocationKind::Pc32;
+                }
+                relocation.addend = value;
+                self.object.sections[section].relocations.push(relocation);
+            }
+        }
+    }
+
+    /// Lays out a static object at the end of `section`.
+    fn object_symbol(
+        &mut self,
+        global: bool,
+        name: &str,
+        section: usize,
+        alignment: i32,
+        init: &[StaticInit],
+    ) {
+        let data = &mut self.object.sections[section].data;
+        let start = data.len().next_multiple_of(alignment as usize);
+        data.resize(start, 0);
+        self.define(name, global, section, start as u64, SymbolKind::Object);
+        for init in init {
+            let data = &mut self.object.sections[section].data;
+            match init {
+                StaticInit::Zero(n) => data.resize(data.len() + *n as usize, 0),
+                StaticInit::Scalar(Constant::Float(f)) => {
+                    data.extend_from_slice(&f.to_bits().to_le_bytes())
+                }
+                StaticInit::Scalar(Constant::Double(d)) => {
+                    data.extend_from_slice(&d.to_bits().to_le_bytes())
+                }
+                StaticInit::Scalar(c) => {
+                    data.extend_from_slice(&c.as_long().to_le_bytes()[..c.ty().size() as usize])
+                }
+                StaticInit::String(bytes, null_terminated) => {
+                    data.extend_from_slice(bytes);
+                    if *null_terminated {
+                        data.push(0);
+                    }
+                }
+                StaticInit::Pointer(target, addend) => {
+                    let offset = data.len();
+                    data.extend_from_slice(&[0; 8]);
+                    self.relocate(section, offset, target, RelocationKind::Abs64, *addend);
+                }
+            }
+        }
+        let section = &mut self.object.sections[section];
+        section.alignment = section.alignment.max(alignment as u64);
+        let i = self.symbols[name];
+        self.object.symbols[i].size = (section.data.len() - start) as u64;
+    }
+
+    fn text(&mut self, program: &Program) {
+        let defined = program.defined_symbols();
+        let mut items = vec![];
+        // the items of every function, by index
+        let mut functions = vec![];
+        for function in program.definitions.iter() {
+            let start = items.len();
+            items.push(Item::Code(prologue(function)));
+            for instruction in function.instructions.iter() {
+                items.push(match instruction {
+                    Instruction::Jmp(label) => Item::Jump(None, label.clone()),
+                    Instruction::JmpCC(cond_code, label) => {
+                        Item::Jump(Some(cond_code.clone()), label.clone())
+                    }
+                    Instruction::Label(label) => Item::Label(label.clone()),
+                    // only a symbol defined in this object is sure to be close enough to be
+                    // addressed directly, the others are loaded from the global offset table
+                    Instruction::Lea {
+                        src: Operand::Data(name, offset, _),
+                        dst: Operand::Register(dst, _),
+                    } if !defined.contains(name.as_str()) => {
+                        Item::Code(got_address(name, *offset, *dst))
+                    }
+                    instruction => Item::Code(encode(instruction)),
+                });
+            }
+            functions.push((function, start..items.len()));
+        }
+
+        // jumps start out short and are made long when their label turns out to be too far
+        // away, until they all fit
+        let mut long = vec![false; items.len()];
+        let (offsets, labels) = loop {
+            let (offsets, labels) = layout(&items, &long);
+            let mut changed = false;
+            for (i, item) in items.iter().enumerate() {
+                if let Item::Jump(_, label) = item
+                    && !long[i]
+                    && i8::try_from(labels[label.as_str()] as i64 - offsets[i + 1] as i64).is_err()
+                {
+                    long[i] = true;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break (offsets, labels);
+            }
+        };
+
+        for (function, range) in functions {
+            let start = offsets[range.start] as u64;
+            let kind = SymbolKind::Function;
+            self.define(&function.name, function.global, TEXT, start, kind);
+            let i = self.symbols[&function.name];
+            self.object.symbols[i].size = offsets[range.end] as u64 - start;
+        }
+        for (i, item) in items.iter().enumerate() {
+            match item {
+                Item::Code(code) => {
+                    for (offset, name, kind, addend) in code.relocations.iter() {
+                        self.relocate(TEXT, offsets[i] + offset, name, *kind, *addend);
+                    }
+                    self.object.sections[TEXT]
+                        .data
+                        .extend_from_slice(&code.bytes);
+                }
+                Item::Jump(cond_code, label) => {
+                    let displacement = labels[label.as_str()] as i64 - offsets[i + 1] as i64;
+                    let data = &mut self.object.sections[TEXT].data;
+                    match (cond_code, long[i]) {
+                        (None, false) => data.push(0xEB),
+                        (None, true) => data.push(0xE9),
+                        (Some(cc), false) => data.push(0x70 + condition(cc)),
+                        (Some(cc), true) => data.extend_from_slice(&[0x0F, 0x80 + condition(cc)]),
+                    }
+                    match long[i] {
+                        false => data.push(displacement as i8 as u8),
+                        true => data.extend_from_slice(&(displacement as i32).to_le_bytes()),
+                    }
+                }
+                Item::Label(_) => {}
+            }
+        }
+    }
+}
+
+/// A piece of the text section.
+enum Item {
+    Code(Code),
+    /// A jump to a label, which takes its short form when the label is close enough.
+    Jump(Option<CondCode>, String),
+    Label(String),
+}
+
+/// The offsets of `items` in the text section, with the offset of its end last, and the
+/// offsets of the labels.
+fn layout<'a>(items: &'a [Item], long: &[bool]) -> (Vec<usize>, HashMap<&'a str, usize>) {
+    let mut offsets = Vec::with_capacity(items.len() + 1);
+    let mut labels = HashMap::new();
+    let mut offset = 0;
+    for (i, item) in items.iter().enumerate() {
+        offsets.push(offset);
+        offset += match item {
+            Item::Code(code) => code.bytes.len(),
+            Item::Jump(_, _) if !long[i] => 2,
+            Item::Jump(None, _) => 5,
+            Item::Jump(Some(_), _) => 6,
+            Item::Label(label) => {
+                labels.insert(label.as_str(), offset);
+                0
+            }
+        };
+    }
+    offsets.push(offset);
+    (offsets, labels)
+}
+
+/// Machine code, with relocations for the symbols it refers to.
+#[derive(Default)]
+struct Code {
+    bytes: Vec<u8>,
+    /// `(offset, symbol, kind, addend)`
+    relocations: Vec<(usize, String, RelocationKind, i64)>,
+}
+
+/// The reg field of a ModRM byte, which holds either a register operand or a digit that
+/// extends the opcode.
+#[derive(Clone, Copy)]
+enum Field {
+    Reg(Reg, Width),
+    Digit(u8),
+}
+
+impl Code {
+    /// Encodes an instruction with a ModRM byte, as in `prefix REX opcode ModRM SIB
+    /// displacement immediate`, where `rm` is a register or memory operand.
+    fn modrm(
+        &mut self,
+        prefix: &[u8],
+        w: bool,
+        opcode: &[u8],
+        reg: Field,
+        rm: &Operand,
+        imm: &[u8],
+    ) {
+        let (reg, reg_is_byte) = match reg {
+            Field::Reg(reg, width) => (number(reg), width == Width::One && !reg.is_xmm()),
+            Field::Digit(digit) => (digit, false),
+        };
+        let (base, index) = match rm {
+            Operand::Register(reg, _) | Operand::Memory(reg, _, _) => (number(*reg), 0),
+            Operand::Stack(_, _) => (BP, 0),
+            Operand::Indexed(base, index, _) => (number(*base), number(*index)),
+            Operand::Data(_, _, _) => (0, 0),
+            Operand::Immediate(_, _) | Operand::Psuedo(_, _) | Operand::PsuedoMem(_, _, _) => {
+                unreachable!("{rm:?} is not a register or memory operand")
+            }
+        };
+        let rm_is_byte = matches!(rm, Operand::Register(reg, Width::One) if !reg.is_xmm());
+        self.bytes.extend_from_slice(prefix);
+        let byte_rex = needs_rex(reg_is_byte, reg) || needs_rex(rm_is_byte, base);
+        self.rex(w, reg, index, base, byte_rex);
+        self.bytes.extend_from_slice(opcode);
+
+        let reg = (reg & 7) << 3;
+        let displacement = match rm {
+            Operand::Register(rm, _) => {
+                self.bytes.push(0xC0 | reg | (number(*rm) & 7));
+                return self.bytes.extend_from_slice(imm);
+            }
+            Operand::Data(name, offset, _) => {
+                // the displacement is relative to the end of the instruction
+                self.bytes.push(0x05 | reg);
+                let addend = *offset as i64 - 4 - imm.len() as i64;
+                (self.relocations).push((
+                    self.bytes.len(),
+                    name.clone(),
+                    RelocationKind::Pc32,
+                    addend,
+                ));
+                self.bytes.extend_from_slice(&[0; 4]);
+                return self.bytes.extend_from_slice(imm);
+            }
+            Operand::Stack(offset, _) => -offset,
+            Operand::Memory(_, offset, _) => *offset,
+            _ => 0,
+        };
+        // %rbp and %r13 can only be a base with a displacement
+        let mode = match displacement {
+            0 if base & 7 != BP => 0x00,
+            d if i8::try_from(d).is_ok() => 0x40,
+            _ => 0x80,
+        };
+        match rm {
+            Operand::Indexed(_, _, scale) => {
+                self.bytes.push(mode | reg | 0b100);
+                let scale = scale.trailing_zeros() as u8;
+                self.bytes
+                    .push((scale << 6) | ((index & 7) << 3) | (base & 7));
+            }
+            // %rsp and %r12 can only be a base with a SIB byte
+            _ if base & 7 == SP => {
+                self.bytes.push(mode | reg | 0b100);
+                self.bytes.push(0x24);
+            }
+            _ => self.bytes.push(mode | reg | (base & 7)),
+        }
+        match mode {
+            0x40 => self.bytes.push(displacement as i8 as u8),
+            0x80 => self.bytes.extend_from_slice(&displacement.to_le_bytes()),
+            _ => {}
+        }
+        self.bytes.extend_from_slice(imm);
+    }
+
+    /// Encodes an instruction with a register in the low bits of its opcode.
+    fn plus_reg(&mut self, prefix: &[u8], w: bool, opcode: u8, reg: Reg, width: Width, imm: &[u8]) {
+        let reg = number(reg);
+        self.bytes.extend_from_slice(prefix);
+        self.rex(w, 0, 0, reg, needs_rex(width == Width::One, reg));
+        self.bytes.push(opcode + (reg & 7));
+        self.bytes.extend_from_slice(imm);
+    }
+
+    /// Writes the REX prefix if one is needed, for a 64-bit operand size, for registers
+    /// numbered 8 and up, or if `byte_rex` says so.
+    fn rex(&mut self, w: bool, reg: u8, index: u8, base: u8, byte_rex: bool) {
+        let rex = 0x40 | ((w as u8) << 3) | ((reg >> 3) << 2) | ((index >> 3) << 1) | (base >> 3);
+        if rex != 0x40 || byte_rex {
+            self.bytes.push(rex);
+        }
+    }
+}
+
+/// Whether a register needs a REX prefix, even an empty one, to be addressed. Without one, the
+/// byte registers `%spl`, `%bpl`, `%sil` and `%dil` would be `%ah`, `%ch`, `%dh` and `%bh`.
+fn needs_rex(is_byte: bool, reg: u8) -> bool {
+    is_byte && (SP..8).contains(&reg)
+}
+
+const SP: u8 = 4;
+const BP: u8 = 5;
+
+/// The number of a register in instruction encodings.
+const fn number(reg: Reg) -> u8 {
+    match reg {
+        Reg::AX | Reg::XMM0 => 0,
+        Reg::CX | Reg::XMM1 => 1,
+        Reg::DX | Reg::XMM2 => 2,
+        Reg::XMM3 => 3,
+        Reg::SP | Reg::XMM4 => SP,
+        Reg::XMM5 => BP,
+        Reg::SI | Reg::XMM6 => 6,
+        Reg::DI | Reg::XMM7 => 7,
+        Reg::R8 => 8,
+        Reg::R9 => 9,
+        Reg::R10 => 10,
+        Reg::R11 => 11,
+        Reg::XMM14 => 14,
+        Reg::XMM15 => 15,
+    }
+}
+
+/// The condition code in the low bits of the opcodes of `jcc` and `setcc`.
+const fn condition(cond_code: &CondCode) -> u8 {
+    match cond_code {
+        CondCode::B => 0x2,
+        CondCode::AE => 0x3,
+        CondCode::E => 0x4,
+        CondCode::NE => 0x5,
+        CondCode::BE => 0x6,
+        CondCode::A => 0x7,
+        CondCode::L => 0xC,
+        CondCode::GE => 0xD,
+        CondCode::LE => 0xE,
+        CondCode::G => 0xF,
+    }
+}
+
+/// The operand size prefix and REX.W bit of an integer instruction on `ty`.
+fn operand_size(ty: AsmType) -> (&'static [u8], bool) {
+    match ty {
+        AsmType::Byte | AsmType::Longword => (&[], false),
+        AsmType::Word => (&[0x66], false),
+        AsmType::Quadword => (&[], true),
+        AsmType::Float | AsmType::Double | AsmType::ByteArray { .. } => {
+            unreachable!("{ty:?} is not an integer type")
+        }
+    }
+}
+
+/// The bytes of an immediate for an integer instruction on `ty`, which are at most four.
+fn immediate(value: i64, ty: AsmType) -> Vec<u8> {
+    let len = match ty {
+        AsmType::Byte => 1,
+        AsmType::Word => 2,
+        _ => 4,
+    };
+    value.to_le_bytes()[..len].to_vec()
+}
+
+/// `value` as the processor sees it in an instruction on `ty`, which is truncated to the
+/// operand size and sign extended.
+fn sign_extend(value: i64, ty: AsmType) -> i64 {
+    match ty {
+        AsmType::Byte => value as i8 as i64,
+        AsmType::Word => value as i16 as i64,
+        AsmType::Longword => value as i32 as i64,
+        _ => value,
+    }
+}
+
+fn is_xmm(operand: &Operand) -> bool {
+    matches!(operand, Operand::Register(reg, _) if reg.is_xmm())
+}
+
+/// The reg field for the register `operand`.
+fn field(operand: &Operand) -> Field {
+    match operand {
+        Operand::Register(reg, width) => Field::Reg(*reg, *width),
+        _ => unreachable!("{operand:?} is not a register"),
+    }
+}
+
+/// Encodes `add`, `or`, `and`, `sub`, `xor` or `cmp`, which are told apart by `digit`.
+fn arithmetic(code: &mut Code, digit: u8, ty: AsmType, src: &Operand, dst: &Operand) {
+    let (prefix, w) = operand_size(ty);
+    let byte = ty == AsmType::Byte;
+    match src {
+        Operand::Immediate(value, _) => {
+            let value = sign_extend(*value, ty);
+            let accumulator = matches!(dst, Operand::Register(Reg::AX, _));
+            if byte && accumulator {
+                code.bytes.push(digit * 8 + 4);
+                code.bytes.push(value as u8);
+            } else if byte {
+                code.modrm(prefix, w, &[0x80], Field::Digit(digit), dst, &[value as u8]);
+            } else if i8::try_from(value).is_ok() {
+                code.modrm(prefix, w, &[0x83], Field::Digit(digit), dst, &[value as u8]);
+            } else if accumulator {
+                code.bytes.extend_from_slice(prefix);
+                code.rex(w, 0, 0, 0, false);
+                code.bytes.push(digit * 8 + 5);
+                code.bytes.extend(immediate(value, ty));
+            } else {
+                let imm = immediate(value, ty);
+                code.modrm(prefix, w, &[0x81], Field::Digit(digit), dst, &imm);
+            }
+        }
+        Operand::Register(_, _) => code.modrm(
+            prefix,
+            w,
+            &[digit * 8 + 1 - byte as u8],
+            field(src),
+            dst,
+            &[],
+        ),
+        _ => code.modrm(
+            prefix,
+            w,
+            &[digit * 8 + 3 - byte as u8],
+            field(dst),
+            src,
+            &[],
+        ),
+    }
+}
+
+/// Encodes a shift of `dst` by `src`, which is an immediate or `%cl`.
+fn shift(code: &mut Code, digit: u8, ty: AsmType, src: &Operand, dst: &Operand) {
+    let (prefix, w) = operand_size(ty);
+    let byte = ty == AsmType::Byte;
+    let digit = Field::Digit(digit);
+    match src {
+        Operand::Immediate(1, _) => code.modrm(prefix, w, &[0xD1 - byte as u8], digit, dst, &[]),
+        Operand::Immediate(count, _) => {
+            code.modrm(prefix, w, &[0xC1 - byte as u8], digit, dst, &[*count as u8])
+        }
+        Operand::Register(Reg::CX, _) => {
+            code.modrm(prefix, w, &[0xD3 - byte as u8], digit, dst, &[])
+        }
+        _ => unreachable!("shift count {src:?} is not an immediate or %cl"),
+    }
+}
+
+/// Encodes a move with an XMM register as one of its operands.
+fn move_xmm(code: &mut Code, ty: AsmType, src: &Operand, dst: &Operand) {
+    let gp_register =
+        |operand: &Operand| matches!(operand, Operand::Register(..)) && !is_xmm(operand);
+    match ty {
+        AsmType::Float | AsmType::Double => {
+            let prefix: &[u8] = if ty == AsmType::Float {
+                &[0xF3]
+            } else {
+                &[0xF2]
+            };
+            match is_xmm(dst) {
+                true => code.modrm(prefix, false, &[0x0F, 0x10], field(dst), src, &[]),
+                false => code.modrm(prefix, false, &[0x0F, 0x11], field(src), dst, &[]),
+            }
+        }
+        AsmType::Longword => match is_xmm(dst) {
+            true => code.modrm(&[0x66], false, &[0x0F, 0x6E], field(dst), src, &[]),
+            false => code.modrm(&[0x66], false, &[0x0F, 0x7E], field(src), dst, &[]),
+        },
+        AsmType::Quadword if is_xmm(dst) && gp_register(src) => {
+            code.modrm(&[0x66], true, &[0x0F, 0x6E], field(dst), src, &[])
+        }
+        AsmType::Quadword if is_xmm(src) && gp_register(dst) => {
+            code.modrm(&[0x66], true, &[0x0F, 0x7E], field(src), dst, &[])
+        }
+        AsmType::Quadword if is_xmm(dst) => {
+            code.modrm(&[0xF3], false, &[0x0F, 0x7E], field(dst), src, &[])
+        }
+        AsmType::Quadword => code.modrm(&[0x66], false, &[0x0F, 0xD6], field(src), dst, &[]),
+        AsmType::Byte | AsmType::Word | AsmType::ByteArray { .. } => {
+            unreachable!("{ty:?} values are never moved to or from XMM registers")
+        }
+    }
+}
+
+/// Encodes `pushq %rbp; movq %rsp, %rbp; subq $stack_size, %rsp`, and for variadic
+/// functions the spilling of the argument registers into the register save area.
+fn prologue(function: &Function) -> Code {
+    let mut code = Code::default();
+    code.bytes.extend_from_slice(&[0x55, 0x48, 0x89, 0xE5]);
+    let stack_size = Operand::Immediate(function.stack_size.into(), Width::Eight);
+    let sp = Operand::Register(Reg::SP, Width::Eight);
+    arithmetic(&mut code, 5, AsmType::Quadword, &stack_size, &sp);
+    if function.variadic {
+        for (i, reg) in REG_ARGS.iter().enumerate() {
+            let slot = Operand::Stack(REGISTER_SAVE_AREA - 8 * i as i32, Width::Eight);
+            let reg = Operand::Register(*reg, Width::Eight);
+            code.modrm(&[], true, &[0x89], field(&reg), &slot, &[]);
+        }
+        for (i, reg) in XMM_REG_ARGS.iter().enumerate() {
+            let offset = 8 * REG_ARGS.len() as i32 + 16 * i as i32;
+            let slot = Operand::Stack(REGISTER_SAVE_AREA - offset, Width::Eight);
+            let reg = Operand::Register(*reg, Width::Eight);
+            move_xmm(&mut code, AsmType::Double, &reg, &slot);
+        }
+    }
+    code
+}
+
+/// Encodes `movq name@GOTPCREL(%rip), dst`, followed by an `addq` of `offset`.
+fn got_address(name: &str, offset: i32, dst: Reg) -> Code {
+    let mut code = Code::default();
+    let got = Operand::Data(name.to_string(), 0, Width::Eight);
+    let dst = Operand::Register(dst, Width::Eight);
+    code.modrm(&[], true, &[0x8B], field(&dst), &got, &[]);
+    code.relocations.last_mut().unwrap().2 = RelocationKind::RexGotPcRelX;
+    if offset != 0 {
+        let offset = Operand::Immediate(offset.into(), Width::Eight);
+        arithmetic(&mut code, 0, AsmType::Quadword, &offset, &dst);
+    }
+    code
+}
+
+/// Encodes an instruction other than a jump or label.
+fn encode(instruction: &Instruction) -> Code {
+    let mut code = Code::default();
+    let c = &mut code;
+    match instruction {
+        Instruction::Move {
+            asm_type,
+            source,
+            destination,
+        } if asm_type.is_floating() || is_xmm(source) || is_xmm(destination) => {
+            move_xmm(c, *asm_type, source, destination)
+        }
+        Instruction::Move {
+            asm_type: ty,
+            source: Operand::Immediate(value, _),
+            destination: Operand::Register(reg, width),
+        } => match ty {
+            AsmType::Quadword if i32::try_from(*value).is_ok() => {
+                let imm = immediate(*value, *ty);
+                c.modrm(
+                    &[],
+                    true,
+                    &[0xC7],
+                    Field::Digit(0),
+                    &Operand::Register(*reg, *width),
+                    &imm,
+                )
+            }
+            AsmType::Quadword => c.plus_reg(&[], true, 0xB8, *reg, *width, &value.to_le_bytes()),
+            AsmType::Byte => c.plus_reg(&[], false, 0xB0, *reg, *width, &immediate(*value, *ty)),
+            _ => {
+                let (prefix, _) = operand_size(*ty);
+                c.plus_reg(prefix, false, 0xB8, *reg, *width, &immediate(*value, *ty))
+            }
+        },
+        Instruction::Move {
+            asm_type: ty,
+            source: Operand::Immediate(value, _),
+            destination,
+        } => {
+            let (prefix, w) = operand_size(*ty);
+            let opcode = if *ty == AsmType::Byte { 0xC6 } else { 0xC7 };
+            let imm = immediate(*value, *ty);
+            c.modrm(prefix, w, &[opcode], Field::Digit(0), destination, &imm)
+        }
+        Instruction::Move {
+            asm_type: ty,
+            source: source @ Operand::Register(_, _),
+            destination,
+        } => {
+            let (prefix, w) = operand_size(*ty);
+            let opcode = if *ty == AsmType::Byte { 0x88 } else { 0x89 };
+            c.modrm(prefix, w, &[opcode], field(source), destination, &[])
+        }
+        Instruction::Move {
+            asm_type: ty,
+            source,
+            destination,
+        } => {
+            let (prefix, w) = operand_size(*ty);
+            let opcode = if *ty == AsmType::Byte { 0x8A } else { 0x8B };
+            c.modrm(prefix, w, &[opcode], field(destination), source, &[])
+        }
+        Instruction::Movesx {
+            source,
+            destination,
+        } => {
+            let (prefix, w) = operand_size(destination.width().into());
+            let opcode: &[u8] = match source.width() {
+                Width::One => &[0x0F, 0xBE],
+                Width::Two => &[0x0F, 0xBF],
+                Width::Four | Width::Eight => &[0x63],
+            };
+            c.modrm(prefix, w, opcode, field(destination), source, &[])
+        }
+        Instruction::MovZeroExtend {
+            source,
+            destination,
+        } => {
+            let (prefix, w) = operand_size(destination.width().into());
+            match source.width() {
+                Width::One => c.modrm(prefix, w, &[0x0F, 0xB6], field(destination), source, &[]),
+                Width::Two => c.modrm(prefix, w, &[0x0F, 0xB7], field(destination), source, &[]),
+                // a move to a 32-bit register clears the upper half
+                Width::Four | Width::Eight => {
+                    c.modrm(&[], false, &[0x8B], field(destination), source, &[])
+                }
+            }
+        }
+        Instruction::Unary(ty, op, operand) => {
+            let (prefix, w) = operand_size(*ty);
+            let byte = *ty == AsmType::Byte;
+            let (opcode, digit) = match op {
+                UnaryOperator::Not => (0xF7, 2),
+                UnaryOperator::Neg => (0xF7, 3),
+                UnaryOperator::Shr => (0xD1, 5),
+            };
+            c.modrm(
+                prefix,
+                w,
+                &[opcode - byte as u8],
+                Field::Digit(digit),
+                operand,
+                &[],
+            )
+        }
+        Instruction::Binary(ty, op, src, dst) if ty.is_floating() => {
+            let scalar: &[u8] = if *ty == AsmType::Float {
+                &[0xF3]
+            } else {
+                &[0xF2]
+            };
+            let (prefix, opcode): (&[u8], u8) = match op {
+                BinaryOperator::Add => (scalar, 0x58),
+                BinaryOperator::Mult => (scalar, 0x59),
+                BinaryOperator::Sub => (scalar, 0x5C),
+                BinaryOperator::DivDouble => (scalar, 0x5E),
+                // xorps and xorpd
+                BinaryOperator::Xor if *ty == AsmType::Float => (&[], 0x57),
+                BinaryOperator::Xor => (&[0x66], 0x57),
+                _ => unreachable!("{op:?} of floating values"),
+            };
+            c.modrm(prefix, false, &[0x0F, opcode], field(dst), src, &[])
+        }
+        Instruction::Binary(ty, op, src, dst) => match op {
+            BinaryOperator::Add => arithmetic(c, 0, *ty, src, dst),
+            BinaryOperator::Or => arithmetic(c, 1, *ty, src, dst),
+            BinaryOperator::And => arithmetic(c, 4, *ty, src, dst),
+            BinaryOperator::Sub => arithmetic(c, 5, *ty, src, dst),
+            BinaryOperator::Xor => arithmetic(c, 6, *ty, src, dst),
+            BinaryOperator::Mult => {
+                let (prefix, w) = operand_size(*ty);
+                match src {
+                    Operand::Immediate(value, _) => {
+                        let value = sign_extend(*value, *ty);
+                        match i8::try_from(value) {
+                            Ok(value) => {
+                                c.modrm(prefix, w, &[0x6B], field(dst), dst, &[value as u8])
+                            }
+                            Err(_) => {
+                                let imm = immediate(value, *ty);
+                                c.modrm(prefix, w, &[0x69], field(dst), dst, &imm)
+                            }
+                        }
+                    }
+                    _ => c.modrm(prefix, w, &[0x0F, 0xAF], field(dst), src, &[]),
+                }
+            }
+            BinaryOperator::SignedLeftShift | BinaryOperator::LeftShift => {
+                shift(c, 4, *ty, src, dst)
+            }
+            BinaryOperator::RightShift => shift(c, 5, *ty, src, dst),
+            BinaryOperator::SignedRightShift => shift(c, 7, *ty, src, dst),
+            BinaryOperator::DivDouble
+            | BinaryOperator::Equals
+            | BinaryOperator::NotEquals
+            | BinaryOperator::LessThan
+            | BinaryOperator::LessThanOrEqual
+            | BinaryOperator::GreaterThan
+            | BinaryOperator::GreaterThanOrEqual => unreachable!("{op:?} of integers"),
+        },
+        Instruction::Cmp(ty, lhs, rhs) if ty.is_floating() => {
+            let prefix: &[u8] = if *ty == AsmType::Float { &[] } else { &[0x66] };
+            c.modrm(prefix, false, &[0x0F, 0x2F], field(rhs), lhs, &[])
+        }
+        Instruction::Cmp(ty, lhs, rhs) => arithmetic(c, 7, *ty, lhs, rhs),
+        Instruction::Idiv(ty, operand) | Instruction::Div(ty, operand) => {
+            let (prefix, w) = operand_size(*ty);
+            let opcode = if *ty == AsmType::Byte { 0xF6 } else { 0xF7 };
+            let digit = if matches!(instruction, Instruction::Idiv(..)) {
+                7
+            } else {
+                6
+            };
+            c.modrm(prefix, w, &[opcode], Field::Digit(digit), operand, &[])
+        }
+        Instruction::Cdq(AsmType::Longword) => c.bytes.push(0x99),
+        Instruction::Cdq(AsmType::Quadword) => c.bytes.extend_from_slice(&[0x48, 0x99]),
+        Instruction::Cdq(ty) => unreachable!("cdq of {ty:?}"),
+        Instruction::SetCC(cond_code, operand) => {
+            let opcode = [0x0F, 0x90 + condition(cond_code)];
+            c.modrm(&[], false, &opcode, Field::Digit(0), operand, &[])
+        }
+        Instruction::AllocateStack(bytes) | Instruction::DeallocateStack(bytes) => {
+            let digit = if matches!(instruction, Instruction::AllocateStack(_)) {
+                5
+            } else {
+                0
+            };
+            let bytes = Operand::Immediate((*bytes).into(), Width::Eight);
+            let sp = Operand::Register(Reg::SP, Width::Eight);
+            arithmetic(c, digit, AsmType::Quadword, &bytes, &sp)
+        }
+        Instruction::Push(Operand::Register(reg, _)) => {
+            c.plus_reg(&[], false, 0x50, *reg, Width::Eight, &[])
+        }
+        Instruction::Push(Operand::Immediate(value, _)) => match i8::try_from(*value) {
+            Ok(value) => c.bytes.extend_from_slice(&[0x6A, value as u8]),
+            Err(_) => {
+                c.bytes.push(0x68);
+                c.bytes.extend(immediate(*value, AsmType::Longword));
+            }
+        },
+        Instruction::Push(operand) => c.modrm(&[], false, &[0xFF], Field::Digit(6), operand, &[]),
+        Instruction::Call(name) => {
+            c.bytes.push(0xE8);
+            (c.relocations).push((c.bytes.len(), name.clone(), RelocationKind::Plt32, -4));
+            c.bytes.extend_from_slice(&[0; 4]);
+        }
+        Instruction::IndirectCall(target) => {
+            c.modrm(&[], false, &[0xFF], Field::Digit(2), target, &[])
+        }
+        Instruction::Comment(_) => {}
+        Instruction::Cvtsi2sd {
+            src_type,
+            dst_type,
+            src,
+            dst,
+        } => {
+            let prefix: &[u8] = if *dst_type == AsmType::Float {
+                &[0xF3]
+            } else {
+                &[0xF2]
+            };
+            let w = *src_type == AsmType::Quadword;
+            c.modrm(prefix, w, &[0x0F, 0x2A], field(dst), src, &[])
+        }
+        Instruction::Cvttsd2si {
+            src_type,
+            dst_type,
+            src,
+            dst,
+        } => {
+            let prefix: &[u8] = if *src_type == AsmType::Float {
+                &[0xF3]
+            } else {
+                &[0xF2]
+            };
+            let w = *dst_type == AsmType::Quadword;
+            c.modrm(prefix, w, &[0x0F, 0x2C], field(dst), src, &[])
+        }
+        Instruction::Cvtss2sd { src, dst } => {
+            c.modrm(&[0xF3], false, &[0x0F, 0x5A], field(dst), src, &[])
+        }
+        Instruction::Cvtsd2ss { src, dst } => {
+            c.modrm(&[0xF2], false, &[0x0F, 0x5A], field(dst), src, &[])
+        }
+        Instruction::Lea { src, dst } => c.modrm(&[], true, &[0x8D], field(dst), src, &[]),
+        // movq %rbp, %rsp; popq %rbp; ret
+        Instruction::Ret => c.bytes.extend_from_slice(&[0x48, 0x89, 0xEC, 0x5D, 0xC3]),
+        Instruction::Jmp(_) | Instruction::JmpCC(_, _) | Instruction::Label(_) => {
+            unreachable!("jumps and labels are laid out by the assembler")
+        }
+    }
+    code
+}
@@ -1,8 +1,12 @@
-use std::{collections::HashMap, fmt::Debug, iter::empty};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    iter::empty,
+};
 
 use crate::{
     ast::{self, Constant},
-    sema::{Symbol, SymbolAttributes, SymbolTable, Type},
+    sema::{StaticInit, Symbol, SymbolAttributes, SymbolTable, Type},
     tacky::{self, Val},
 };
 
@@ -13,10 +17,26 @@ pub struct Program {
     pub static_constants: Vec<StaticConstant>,
 }
 
+impl Program {
+    /// The names of the functions and objects that the program defines, as opposed to those it
+    /// only refers to.
+    pub fn defined_symbols(&self) -> HashSet<&str> {
+        (self.definitions.iter().map(|f| &f.name))
+            .chain(self.static_variables.iter().map(|sv| &sv.name))
+            .chain(self.static_constants.iter().map(|sc| &sc.name))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
 #[derive(Debug)]
 pub struct Function {
     pub name: String,
     pub global: bool,
+    /// The bytes the prologue reserves below the frame pointer.
+    pub stack_size: u32,
+    /// Whether the prologue spills the argument registers into the register save area.
+    pub variadic: bool,
     pub instructions: Vec<Instruction>,
 }
 
@@ -47,6 +67,10 @@ pub enum Instruction {
         source: Operand,
         destination: Operand,
     },
+    MovZeroExtend {
+        source: Operand,
+        destination: Operand,
+    },
     Unary(AsmType, UnaryOperator, Operand),
     Binary(AsmType, BinaryOperator, Operand, Operand),
     Cmp(AsmType, Operand, Operand),
@@ -61,17 +85,35 @@ pub enum Instruction {
     DeallocateStack(u32),
     Push(Operand),
     Call(String),
+    /// A call through a function pointer.
+    IndirectCall(Operand),
     Comment(String),
+    /// Converts an integer to a floating type; `dst_type` is `Float` or `Double`.
     Cvtsi2sd {
         src_type: AsmType,
+        dst_type: AsmType,
         src: Operand,
         dst: Operand,
     },
+    /// Truncates a floating value to an integer; `src_type` is `Float` or `Double`.
     Cvttsd2si {
+        src_type: AsmType,
         dst_type: AsmType,
         src: Operand,
         dst: Operand,
     },
+    Cvtss2sd {
+        src: Operand,
+        dst: Operand,
+    },
+    Cvtsd2ss {
+        src: Operand,
+        dst: Operand,
+    },
+    Lea {
+        src: Operand,
+        dst: Operand,
+    },
     Ret,
 }
 
@@ -128,18 +170,31 @@ impl From<&Type> for AsmType {
     fn from(value: &Type) -> Self {
         match value {
             Type::Function { .. } => AsmType::Quadword,
+            Type::Char | Type::SChar | Type::UChar | Type::Bool => AsmType::Byte,
+            Type::Short | Type::UShort => AsmType::Word,
             Type::Int | Type::UInt => AsmType::Longword,
-            Type::Long | Type::ULong => AsmType::Quadword,
+            Type::Long | Type::ULong | Type::Pointer(_) => AsmType::Quadword,
+            Type::Float => AsmType::Float,
             Type::Double => AsmType::Double,
+            Type::Array { .. } | Type::Struct(_) => AsmType::ByteArray {
+                size: value.size(),
+                alignment: value.variable_alignment(),
+            },
+            Type::Qualified(ty, _) => ty.as_ref().into(),
+            Type::Void => unreachable!("void values are never stored"),
         }
     }
 }
 impl std::fmt::Display for AsmType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            AsmType::Byte => write!(f, "b"),
+            AsmType::Word => write!(f, "w"),
             AsmType::Longword => write!(f, "l"),
             AsmType::Quadword => write!(f, "q"),
+            AsmType::Float => write!(f, "ss"),
             AsmType::Double => write!(f, "sd"),
+            AsmType::ByteArray { .. } => unreachable!("byte arrays have no instruction suffix"),
         }
     }
 }
@@ -148,18 +203,37 @@ impl From<Type> for AsmType {
         (&value).into()
     }
 }
+impl From<Width> for AsmType {
+    fn from(value: Width) -> Self {
+        match value {
+            Width::One => AsmType::Byte,
+            Width::Two => AsmType::Word,
+            Width::Four => AsmType::Longword,
+            Width::Eight => AsmType::Quadword,
+        }
+    }
+}
 
 impl IntoOperand for &Val {
     fn into_operand(&self) -> (Operand, AsmType) {
         let asm_type = self.ty().into();
         let op = match self {
             tacky::Val::Constant(c) => match c {
+                ast::Constant::Char(_) | ast::Constant::UChar(_) => {
+                    Operand::Immediate(c.as_long(), Width::One)
+                }
+                ast::Constant::Short(_) | ast::Constant::UShort(_) => {
+                    Operand::Immediate(c.as_long(), Width::Two)
+                }
                 ast::Constant::Int(_) | ast::Constant::UInt(_) => {
                     Operand::Immediate(c.as_long(), Width::Four)
                 }
                 ast::Constant::Long(_) | ast::Constant::ULong(_) => {
                     Operand::Immediate(c.as_long(), Width::Eight)
                 }
+                ast::Constant::Float(d) => {
+                    Operand::Immediate(d.to_bits() as i32 as i64, Width::Four)
+                }
                 ast::Constant::Double(d) => Operand::Immediate(d.to_bits() as i64, Width::Eight),
             },
             tacky::Val::Var(id, ty) => Operand::Psuedo(id.clone(), ty.width()),
@@ -170,9 +244,19 @@ impl IntoOperand for &Val {
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum AsmType {
+    Byte,
+    Word,
     Longword,
     Quadword,
+    Float,
     Double,
+    ByteArray { size: u64, alignment: u64 },
+}
+
+impl AsmType {
+    pub const fn is_floating(self) -> bool {
+        matches!(self, AsmType::Float | AsmType::Double)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -208,7 +292,13 @@ pub enum Operand {
     Register(Reg, Width),
     Psuedo(String, Width),
     Stack(i32, Width),
-    Data(String, Width),
+    /// A static object, addressed at a byte offset.
+    Data(String, i32, Width),
+    Memory(Reg, i32, Width),
+    /// A pseudoregister holding an aggregate, addressed at a byte offset.
+    PsuedoMem(String, i32, Width),
+    /// `(base, index, scale)`
+    Indexed(Reg, Reg, i32),
 }
 
 impl Debug for Operand {
@@ -222,7 +312,16 @@ impl Debug for Operand {
             }
             Self::Psuedo(arg0, arg1) => write!(f, "Psuedo({arg0:?}, {arg1:?})"),
             Self::Stack(arg0, arg1) => write!(f, "Stack({arg0:?}, {arg1:?})"),
-            Self::Data(arg0, arg1) => write!(f, "Data({arg0:?}, {arg1:?})"),
+            Self::Data(arg0, arg1, arg2) => write!(f, "Data({arg0:?}, {arg1:?}, {arg2:?})"),
+            Self::Memory(arg0, arg1, arg2) => {
+                write!(f, "Memory({arg0:?}, {arg1:?}, {arg2:?})")
+            }
+            Self::PsuedoMem(arg0, arg1, arg2) => {
+                write!(f, "PsuedoMem({arg0:?}, {arg1:?}, {arg2:?})")
+            }
+            Self::Indexed(arg0, arg1, arg2) => {
+                write!(f, "Indexed({arg0:?}, {arg1:?}, {arg2:?})")
+            }
         }
     }
 }
@@ -234,10 +333,24 @@ impl Operand {
             | Operand::Register(_, width)
             | Operand::Psuedo(_, width)
             | Operand::Stack(_, width)
-            | Operand::Data(_, width) => *width,
+            | Operand::Data(_, _, width)
+            | Operand::Memory(_, _, width)
+            | Operand::PsuedoMem(_, _, width) => *width,
+            Operand::Indexed(..) => Width::Eight,
         }
     }
 
+    pub const fn is_memory(&self) -> bool {
+        matches!(
+            self,
+            Operand::Stack(_, _)
+                | Operand::Data(_, _, _)
+                | Operand::Memory(_, _, _)
+                | Operand::PsuedoMem(_, _, _)
+                | Operand::Indexed(_, _, _)
+        )
+    }
+
     pub const fn outside_int_range(&self) -> bool {
         match self {
             Operand::Immediate(v, _) => *v > i32::MAX as i64 || *v < i32::MIN as i64,
@@ -257,6 +370,7 @@ pub enum Reg {
     R9,
     R10,
     R11,
+    SP,
     XMM0,
     XMM1,
     XMM2,
@@ -277,11 +391,20 @@ impl Reg {
     const fn width(self, width: Width) -> Operand {
         Operand::Register(self, width)
     }
+
+    pub const fn is_xmm(self) -> bool {
+        use Reg::*;
+        matches!(
+            self,
+            XMM0 | XMM1 | XMM2 | XMM3 | XMM4 | XMM5 | XMM6 | XMM7 | XMM14 | XMM15
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Width {
     One,
+    Two,
     Four,
     Eight,
 }
@@ -290,6 +413,7 @@ impl Width {
     pub const fn bytes(&self) -> i32 {
         match self {
             Width::One => 1,
+            Width::Two => 2,
             Width::Four => 4,
             Width::Eight => 8,
         }
@@ -300,6 +424,7 @@ impl From<Width> for u32 {
     fn from(value: Width) -> Self {
         match value {
             Width::One => 1,
+            Width::Two => 2,
             Width::Four => 4,
             Width::Eight => 8,
         }
@@ -310,14 +435,15 @@ impl From<Width> for u32 {
 pub struct StaticVariable {
     pub global: bool,
     pub name: String,
-    pub value: Constant,
+    pub init: Vec<StaticInit>,
     pub alignment: i32,
+    pub read_only: bool,
 }
 
 #[derive(Debug)]
 pub struct StaticConstant {
     pub name: String,
-    pub value: Constant,
+    pub init: StaticInit,
     pub alignment: i32,
 }
 
@@ -326,8 +452,9 @@ impl From<&tacky::StaticVariable> for StaticVariable {
         StaticVariable {
             global: value.global,
             name: value.identifier.clone(),
-            value: value.init,
-            alignment: value.width.bytes(),
+            init: value.init.clone(),
+            alignment: value.alignment as i32,
+            read_only: value.read_only,
         }
     }
 }
@@ -362,6 +489,7 @@ impl From<&tacky::BinaryOperator> for BinaryOperator {
             tacky::BinaryOperator::NotEqual => Self::NotEquals,
             tacky::BinaryOperator::And => Self::And,
             tacky::BinaryOperator::Or => Self::Or,
+            tacky::BinaryOperator::Comma => unreachable!(),
         }
     }
 }
@@ -390,14 +518,7 @@ impl tacky::BinaryOperator {
 impl IntoOperand for (i64, Width) {
     fn into_operand(&self) -> (Operand, AsmType) {
         let (i, w) = self;
-        (
-            Operand::Immediate(*i, *w),
-            match w {
-                Width::One => unreachable!(),
-                Width::Four => AsmType::Longword,
-                Width::Eight => AsmType::Quadword,
-            },
-        )
+        (Operand::Immediate(*i, *w), (*w).into())
     }
 }
 
@@ -407,18 +528,22 @@ impl IntoOperand for (Reg, Width) {
         use Width::*;
 
         match (self.0, self.1) {
+            (XMM0 | XMM1 | XMM2 | XMM3 | XMM4 | XMM5 | XMM6 | XMM7 | XMM14 | XMM15, Four) => {
+                (Operand::Register(self.0, Four), AsmType::Float)
+            }
             (XMM0 | XMM1 | XMM2 | XMM3 | XMM4 | XMM5 | XMM6 | XMM7 | XMM14 | XMM15, _) => {
                 (Operand::Register(self.0, Eight), AsmType::Double)
             }
+            (_, Two) => (Operand::Register(self.0, Two), AsmType::Word),
             (_, Four) => (Operand::Register(self.0, Four), AsmType::Longword),
             (_, Eight) => (Operand::Register(self.0, Eight), AsmType::Quadword),
-            (_, One) => unreachable!(),
+            (_, One) => (Operand::Register(self.0, One), AsmType::Byte),
         }
     }
 }
 
-const REG_ARGS: [Reg; 6] = [Reg::DI, Reg::SI, Reg::DX, Reg::CX, Reg::R8, Reg::R9];
-const XMM_REG_ARGS: [Reg; 8] = [
+pub const REG_ARGS: [Reg; 6] = [Reg::DI, Reg::SI, Reg::DX, Reg::CX, Reg::R8, Reg::R9];
+pub const XMM_REG_ARGS: [Reg; 8] = [
     Reg::XMM0,
     Reg::XMM1,
     Reg::XMM2,
@@ -429,21 +554,447 @@ const XMM_REG_ARGS: [Reg; 8] = [
     Reg::XMM7,
 ];
 
+/// The System V class of one eightbyte of a structure, which decides how it is passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Integer,
+    Sse,
+    Memory,
+}
+
+/// Classifies each eightbyte of the structure type `ty`. Structures larger than 16 bytes are
+/// always passed in memory; otherwise an eightbyte goes in an SSE register only if it holds
+/// nothing but floating values.
+fn classify(ty: &Type) -> Vec<Class> {
+    let size = ty.size();
+    let eightbytes = size.div_ceil(8) as usize;
+    if size > 16 {
+        return vec![Class::Memory; eightbytes];
+    }
+    let mut classes = vec![Class::Sse; eightbytes];
+    classify_scalars(ty, 0, &mut classes);
+    classes
+}
+
+fn classify_scalars(ty: &Type, offset: u64, classes: &mut [Class]) {
+    match ty.unqualified() {
+        Type::Struct(s) => {
+            for member in s.layout_unchecked().members.iter() {
+                classify_scalars(&member.ty, offset + member.offset, classes);
+            }
+        }
+        Type::Array { element, size } => {
+            for i in 0..*size {
+                classify_scalars(element, offset + i * element.size(), classes);
+            }
+        }
+        Type::Float | Type::Double => {}
+        _ => classes[offset as usize / 8] = Class::Integer,
+    }
+}
+
+fn returned_in_memory(ty: &Type) -> bool {
+    ty.is_struct() && classify(ty)[0] == Class::Memory
+}
+
+/// A scalar argument, or one eightbyte of a structure argument, passed in a register.
+struct RegArg<'a> {
+    val: &'a Val,
+    eightbyte: Option<usize>,
+}
+
+/// How the arguments of a call are split between registers and the stack.
+#[derive(Default)]
+struct Args<'a> {
+    int: Vec<RegArg<'a>>,
+    sse: Vec<RegArg<'a>>,
+    stack: Vec<&'a Val>,
+}
+
+impl<'a> Args<'a> {
+    /// Assigns `args` to registers in order. A structure is passed in registers only if all of
+    /// its eightbytes fit, and `%rdi` is reserved when the return value goes in memory.
+    fn assign(args: &'a [Val], return_in_memory: bool) -> Self {
+        let int_regs = REG_ARGS.len() - return_in_memory as usize;
+        let mut assigned = Args::default();
+        for val in args {
+            match val.ty() {
+                ty if ty.is_floating() && assigned.sse.len() < XMM_REG_ARGS.len() => {
+                    assigned.sse.push(RegArg {
+                        val,
+                        eightbyte: None,
+                    });
+                }
+                ty @ Type::Struct(_) => {
+                    let classes = classify(&ty);
+                    let count = |class| classes.iter().filter(|c| **c == class).count();
+                    if classes[0] == Class::Memory
+                        || assigned.int.len() + count(Class::Integer) > int_regs
+                        || assigned.sse.len() + count(Class::Sse) > XMM_REG_ARGS.len()
+                    {
+                        assigned.stack.push(val);
+                        continue;
+                    }
+                    for (i, class) in classes.into_iter().enumerate() {
+                        let arg = RegArg {
+                            val,
+                            eightbyte: Some(i),
+                        };
+                        match class {
+                            Class::Integer => assigned.int.push(arg),
+                            Class::Sse => assigned.sse.push(arg),
+                            Class::Memory => unreachable!(),
+                        }
+                    }
+                }
+                ty if ty.is_floating() => assigned.stack.push(val),
+                _ if assigned.int.len() < int_regs => assigned.int.push(RegArg {
+                    val,
+                    eightbyte: None,
+                }),
+                _ => assigned.stack.push(val),
+            }
+        }
+        assigned
+    }
+
+    fn int_regs(return_in_memory: bool) -> &'static [Reg] {
+        &REG_ARGS[return_in_memory as usize..]
+    }
+}
+
+/// The number of eightbytes `val` takes up on the stack.
+fn stack_eightbytes(val: &Val) -> u32 {
+    match val.ty() {
+        ty @ Type::Struct(_) => ty.size().div_ceil(8) as u32,
+        _ => 1,
+    }
+}
+
+fn aggregate_name(val: &Val) -> &str {
+    match val {
+        Val::Var(name, _) => name,
+        Val::Constant(c) => unreachable!("{c:?} is not an aggregate"),
+    }
+}
+
+/// Copies `size` bytes from `src` to `dst`, which give the operand at a byte offset and width,
+/// eight bytes at a time where possible.
+fn copy_bytes(
+    size: u64,
+    src: impl Fn(i32, Width) -> Operand,
+    dst: impl Fn(i32, Width) -> Operand,
+) -> Vec<Instruction> {
+    let mut instructions = vec![];
+    let mut offset = 0;
+    while offset < size {
+        let width = match size - offset {
+            8.. => Width::Eight,
+            4..=7 => Width::Four,
+            2..=3 => Width::Two,
+            _ => Width::One,
+        };
+        instructions.push(Instruction::Move {
+            asm_type: width.into(),
+            source: src(offset as i32, width),
+            destination: dst(offset as i32, width),
+        });
+        offset += width.bytes() as u64;
+    }
+    instructions
+}
+
+/// The operand at byte `offset` of the aggregate `val`.
+fn sub_object(val: &Val, offset: i32) -> impl Fn(i32, Width) -> Operand {
+    let name = aggregate_name(val).to_string();
+    move |o, width| Operand::PsuedoMem(name.clone(), offset + o, width)
+}
+
+/// The type of an SSE eightbyte `len` bytes long, where a final eightbyte of four bytes holds
+/// a lone float.
+fn sse_eightbyte(len: i32) -> (AsmType, Width) {
+    match len {
+        4 => (AsmType::Float, Width::Four),
+        _ => (AsmType::Double, Width::Eight),
+    }
+}
+
+/// Moves eightbyte `i` of the structure `val` into `reg`. A partial final eightbyte is
+/// assembled a byte at a time so that nothing past the end of the structure is read.
+fn load_eightbyte(val: &Val, i: usize, reg: Reg) -> Vec<Instruction> {
+    let offset = 8 * i as i32;
+    let len = (val.ty().size() as i32 - offset).min(8);
+    let name = aggregate_name(val);
+    if reg.is_xmm() {
+        let (asm_type, width) = sse_eightbyte(len);
+        return vec![Instruction::Move {
+            asm_type,
+            source: Operand::PsuedoMem(name.to_string(), offset, width),
+            destination: reg.width(width),
+        }];
+    }
+    if len == 8 {
+        return vec![Instruction::Move {
+            asm_type: AsmType::Quadword,
+            source: Operand::PsuedoMem(name.to_string(), offset, Width::Eight),
+            destination: reg.width(Width::Eight),
+        }];
+    }
+    let mut instructions = vec![];
+    for byte in (0..len).rev() {
+        instructions.push(Instruction::Move {
+            asm_type: AsmType::Byte,
+            source: Operand::PsuedoMem(name.to_string(), offset + byte, Width::One),
+            destination: reg.word(),
+        });
+        if byte > 0 {
+            instructions.push(Instruction::Binary(
+                AsmType::Quadword,
+                BinaryOperator::LeftShift,
+                Operand::Immediate(8, Width::Eight),
+                reg.width(Width::Eight),
+            ));
+        }
+    }
+    instructions
+}
+
+/// Moves `reg` into eightbyte `i` of the structure `val`, the inverse of [`load_eightbyte`].
+fn store_eightbyte(reg: Reg, val: &Val, i: usize) -> Vec<Instruction> {
+    let offset = 8 * i as i32;
+    let len = (val.ty().size() as i32 - offset).min(8);
+    let name = aggregate_name(val);
+    if reg.is_xmm() {
+        let (asm_type, width) = sse_eightbyte(len);
+        return vec![Instruction::Move {
+            asm_type,
+            source: reg.width(width),
+            destination: Operand::PsuedoMem(name.to_string(), offset, width),
+        }];
+    }
+    if len == 8 {
+        return vec![Instruction::Move {
+            asm_type: AsmType::Quadword,
+            source: reg.width(Width::Eight),
+            destination: Operand::PsuedoMem(name.to_string(), offset, Width::Eight),
+        }];
+    }
+    let mut instructions = vec![];
+    for byte in 0..len {
+        instructions.push(Instruction::Move {
+            asm_type: AsmType::Byte,
+            source: reg.word(),
+            destination: Operand::PsuedoMem(name.to_string(), offset + byte, Width::One),
+        });
+        if byte + 1 < len {
+            instructions.push(Instruction::Binary(
+                AsmType::Quadword,
+                BinaryOperator::RightShift,
+                Operand::Immediate(8, Width::Eight),
+                reg.width(Width::Eight),
+            ));
+        }
+    }
+    instructions
+}
+
+/// Moves a register argument into place, or out of place in the callee when `incoming`.
+fn move_reg_arg(arg: &RegArg, reg: Reg, incoming: bool) -> Vec<Instruction> {
+    match (arg.eightbyte, incoming) {
+        (Some(i), false) => load_eightbyte(arg.val, i, reg),
+        (Some(i), true) => store_eightbyte(reg, arg.val, i),
+        // callers extend byte and word arguments to 32 bits
+        (None, false) if arg.val.ty().is_promotable() => {
+            vec![extend(arg.val, reg.width(Width::Four))]
+        }
+        (None, false) => vec![Instruction::mov(arg.val, reg.width(arg.val.ty().width()))],
+        (None, true) => vec![Instruction::mov((reg, arg.val.ty().width()), arg.val)],
+    }
+}
+
+/// The size of the register save area at the top of a variadic function's frame, which holds
+/// the general purpose argument registers followed by the vector ones, sixteen bytes apiece.
+pub const REGISTER_SAVE_AREA: i32 = 8 * REG_ARGS.len() as i32 + 16 * XMM_REG_ARGS.len() as i32;
+
+/// Initializes the `va_list` element at `ap`. The variable arguments follow the named ones in
+/// the register save area, and in the caller's frame past the named stack arguments.
+fn va_start(
+    ap: &Val,
+    gp_offset: usize,
+    fp_offset: usize,
+    overflow_arg_area: u32,
+) -> Vec<Instruction> {
+    let field = |offset, width| Operand::Memory(Reg::AX, offset, width);
+    vec![
+        Instruction::mov(ap, Reg::AX.width(Width::Eight)),
+        Instruction::mov((gp_offset as i64, Width::Four), field(0, Width::Four)),
+        Instruction::mov((fp_offset as i64, Width::Four), field(4, Width::Four)),
+        Instruction::Lea {
+            src: Operand::Stack(-(overflow_arg_area as i32), Width::Eight),
+            dst: field(8, Width::Eight),
+        },
+        Instruction::Lea {
+            src: Operand::Stack(REGISTER_SAVE_AREA, Width::Eight),
+            dst: field(16, Width::Eight),
+        },
+    ]
+}
+
+/// Takes the next variable argument from the `va_list` element at `ap` into `dst`. It is read
+/// from the register save area if all of its eightbytes still fit in the registers of their
+/// class, and from the overflow area on the stack otherwise.
+fn va_arg(ap: &Val, dst: &Val) -> Vec<Instruction> {
+    let ty = dst.ty();
+    let classes = match ty {
+        Type::Struct(_) => classify(&ty),
+        Type::Float | Type::Double => vec![Class::Sse],
+        _ => vec![Class::Integer],
+    };
+    let count = |class| classes.iter().filter(|c| **c == class).count() as i32;
+    let stack_label = format!("{}.va_arg.stack", aggregate_name(dst));
+    let end_label = format!("{}.va_arg.end", aggregate_name(dst));
+    let field = |offset, width| Operand::Memory(Reg::AX, offset, width);
+    // copies `len` bytes at `src` into `dst` at `offset`
+    let copy = |src: Operand, offset: i32, len: u64| match (&ty, src) {
+        (Type::Struct(_), Operand::Memory(reg, base, _)) => copy_bytes(
+            len,
+            |o, w| Operand::Memory(reg, base + o, w),
+            sub_object(dst, offset),
+        ),
+        (_, src) => vec![Instruction::Move {
+            asm_type: (&ty).into(),
+            source: src,
+            destination: dst.into(),
+        }],
+    };
+
+    let mut instructions = vec![Instruction::mov(ap, Reg::AX.width(Width::Eight))];
+    if classes[0] != Class::Memory {
+        // `(eightbytes, offset field, end of the registers, register size, cursor)`, where the
+        // cursor ends up pointing into the save area at the registers to read
+        let kinds = [
+            (
+                count(Class::Integer),
+                0,
+                8 * REG_ARGS.len() as i32,
+                8,
+                Reg::CX,
+            ),
+            (count(Class::Sse), 4, REGISTER_SAVE_AREA, 16, Reg::DX),
+        ];
+        let kinds = kinds.iter().filter(|(count, ..)| *count > 0);
+        for (count, offset, end, size, cursor) in kinds.clone() {
+            instructions.extend([
+                Instruction::Move {
+                    asm_type: AsmType::Longword,
+                    source: field(*offset, Width::Four),
+                    destination: cursor.width(Width::Four),
+                },
+                Instruction::Cmp(
+                    AsmType::Longword,
+                    Operand::Immediate((end - count * size) as i64, Width::Four),
+                    cursor.width(Width::Four),
+                ),
+                Instruction::JmpCC(CondCode::A, stack_label.clone()),
+            ]);
+        }
+        for (count, offset, _, size, cursor) in kinds {
+            instructions.extend([
+                Instruction::Binary(
+                    AsmType::Longword,
+                    BinaryOperator::Add,
+                    Operand::Immediate((count * size) as i64, Width::Four),
+                    field(*offset, Width::Four),
+                ),
+                Instruction::Binary(
+                    AsmType::Quadword,
+                    BinaryOperator::Add,
+                    field(16, Width::Eight),
+                    cursor.width(Width::Eight),
+                ),
+            ]);
+        }
+        let (mut ints, mut sses) = (0, 0);
+        for (i, class) in classes.iter().enumerate() {
+            let src = match class {
+                Class::Integer => {
+                    ints += 1;
+                    Operand::Memory(Reg::CX, 8 * (ints - 1), ty.width())
+                }
+                Class::Sse => {
+                    sses += 1;
+                    Operand::Memory(Reg::DX, 16 * (sses - 1), ty.width())
+                }
+                Class::Memory => unreachable!(),
+            };
+            let len = (ty.size() - 8 * i as u64).min(8);
+            instructions.extend(copy(src, 8 * i as i32, len));
+        }
+        instructions.push(Instruction::Jmp(end_label.clone()));
+    }
+    instructions.extend([
+        Instruction::Label(stack_label),
+        Instruction::Move {
+            asm_type: AsmType::Quadword,
+            source: field(8, Width::Eight),
+            destination: Reg::CX.width(Width::Eight),
+        },
+        Instruction::Binary(
+            AsmType::Quadword,
+            BinaryOperator::Add,
+            Operand::Immediate(ty.size().next_multiple_of(8) as i64, Width::Eight),
+            field(8, Width::Eight),
+        ),
+    ]);
+    instructions.extend(copy(Operand::Memory(Reg::CX, 0, ty.width()), 0, ty.size()));
+    instructions.push(Instruction::Label(end_label));
+    instructions
+}
+
+/// Where the callee keeps the address of a return value that is passed in memory.
+const RETURN_ADDRESS: &str = "return.address";
+
 impl From<&tacky::Instruction> for Vec<Instruction> {
     fn from(insn: &tacky::Instruction) -> Self {
         match insn {
-            tacky::Instruction::Return(val) if matches!(val.ty(), Type::Double) => {
+            tacky::Instruction::Return(Some(val)) if returned_in_memory(&val.ty()) => {
+                let mut instructions = vec![Instruction::Move {
+                    asm_type: AsmType::Quadword,
+                    source: Operand::Psuedo(RETURN_ADDRESS.into(), Width::Eight),
+                    destination: Reg::AX.width(Width::Eight),
+                }];
+                instructions.extend(copy_bytes(val.ty().size(), sub_object(val, 0), |o, w| {
+                    Operand::Memory(Reg::AX, o, w)
+                }));
+                instructions.push(Instruction::Ret);
+                instructions
+            }
+            tacky::Instruction::Return(Some(val)) if val.ty().is_struct() => {
+                let (mut int, mut sse) = ([Reg::AX, Reg::DX].iter(), [Reg::XMM0, Reg::XMM1].iter());
+                let mut instructions = vec![];
+                for (i, class) in classify(&val.ty()).into_iter().enumerate() {
+                    let reg = match class {
+                        Class::Integer => int.next(),
+                        _ => sse.next(),
+                    };
+                    instructions.extend(load_eightbyte(val, i, *reg.unwrap()));
+                }
+                instructions.push(Instruction::Ret);
+                instructions
+            }
+            tacky::Instruction::Return(Some(val)) if val.ty().is_floating() => {
                 vec![
                     Instruction::mov(val, Reg::XMM0.width(val.ty().width())),
                     Instruction::Ret,
                 ]
             }
-            tacky::Instruction::Return(val) => {
+            tacky::Instruction::Return(Some(val)) => {
                 vec![
                     Instruction::mov(val, Reg::AX.width(val.ty().width())),
                     Instruction::Ret,
                 ]
             }
+            tacky::Instruction::Return(None) => vec![Instruction::Ret],
             tacky::Instruction::Unary { op, src, dst } => match op {
                 tacky::UnaryOperator::Not => {
                     vec![
@@ -452,13 +1003,18 @@ impl From<&tacky::Instruction> for Vec<Instruction> {
                         Instruction::SetCC(CondCode::E, dst.into()),
                     ]
                 }
-                tacky::UnaryOperator::Negate if src.ty() == Type::Double => {
+                // flip the sign bit
+                tacky::UnaryOperator::Negate if src.ty().is_floating() => {
+                    let negative_zero = match src.ty() {
+                        Type::Float => Constant::Float(-0.0),
+                        _ => Constant::Double(-0.0),
+                    };
                     vec![
                         Instruction::mov(src, dst),
                         Instruction::Binary(
-                            AsmType::Quadword,
+                            src.ty().width().into(),
                             BinaryOperator::Xor,
-                            (&tacky::Val::Constant(Constant::Double(-0.0))).into(),
+                            (&tacky::Val::Constant(negative_zero)).into(),
                             dst.into(),
                         ),
                     ]
@@ -466,6 +1022,12 @@ impl From<&tacky::Instruction> for Vec<Instruction> {
                 op => vec![Instruction::mov(src, dst), Instruction::unary(op, dst)],
             },
             tacky::Instruction::Binary { op, lhs, rhs, dst } => match op {
+                tacky::BinaryOperator::Divide if dst.ty().is_floating() => {
+                    vec![
+                        Instruction::mov(lhs, dst),
+                        Instruction::binary(BinaryOperator::DivDouble, rhs, dst),
+                    ]
+                }
                 tacky::BinaryOperator::Divide if dst.ty().signed() => {
                     vec![
                         Instruction::mov(lhs, Reg::AX.width(lhs.ty().width())),
@@ -515,7 +1077,7 @@ impl From<&tacky::Instruction> for Vec<Instruction> {
                     vec![
                         Instruction::cmp(rhs, lhs),
                         Instruction::mov((0, dst.ty().width()), dst),
-                        Instruction::SetCC(op.cond_code(&dst.ty()), dst.into()),
+                        Instruction::SetCC(op.cond_code(&lhs.ty()), dst.into()),
                     ]
                 }
                 tacky::BinaryOperator::LeftShift if !lhs.ty().signed() => {
@@ -537,6 +1099,9 @@ impl From<&tacky::Instruction> for Vec<Instruction> {
                     ]
                 }
             },
+            tacky::Instruction::Copy { src, dst } if src.ty().is_struct() => {
+                copy_bytes(src.ty().size(), sub_object(src, 0), sub_object(dst, 0))
+            }
             tacky::Instruction::Copy { src, dst } => vec![Instruction::mov(src, dst)],
             tacky::Instruction::Jump(dst) => vec![Instruction::Jmp(dst.clone())],
             tacky::Instruction::JumpIfZero(val, target) => vec![
@@ -548,80 +1113,120 @@ impl From<&tacky::Instruction> for Vec<Instruction> {
                 Instruction::JmpCC(CondCode::NE, target.into()),
             ],
             tacky::Instruction::Label(label) => vec![Instruction::Label(label.clone())],
-            tacky::Instruction::Call(func, args, ret) => {
+            tacky::Instruction::Call(callee, args, ret) => {
                 let mut instructions = vec![];
 
-                let mut gen_reg_args = vec![];
-                let mut xmm_reg_args = vec![];
-                let mut stack_args = vec![];
-
-                for arg in args.iter() {
-                    match arg.ty() {
-                        Type::Function { .. }
-                        | Type::Int
-                        | Type::Long
-                        | Type::UInt
-                        | Type::ULong => {
-                            if gen_reg_args.len() == REG_ARGS.len() {
-                                stack_args.push(arg);
-                            } else {
-                                gen_reg_args.push(arg);
-                            }
-                        }
-                        Type::Double => {
-                            if xmm_reg_args.len() == XMM_REG_ARGS.len() {
-                                stack_args.push(arg);
-                            } else {
-                                xmm_reg_args.push(arg);
-                            }
-                        }
-                    }
-                }
+                let return_in_memory = ret.as_ref().is_some_and(|r| returned_in_memory(&r.ty()));
+                let Args {
+                    int: gen_reg_args,
+                    sse: xmm_reg_args,
+                    stack: mut stack_args,
+                } = Args::assign(args, return_in_memory);
 
                 stack_args.reverse();
-                let stack_args_len = stack_args.len() as u32;
+                let stack_args_len: u32 = stack_args.iter().map(|a| stack_eightbytes(a)).sum();
                 let stack_padding = (stack_args_len * 8) % 16;
                 if !stack_padding.is_multiple_of(16) {
                     instructions.push(Instruction::AllocateStack(stack_padding))
                 };
 
-                for (param, reg) in gen_reg_args.into_iter().zip(&REG_ARGS) {
-                    instructions.push(Instruction::mov(param, reg.width(param.ty().width())));
+                if let Some(ret) = ret.as_ref().filter(|_| return_in_memory) {
+                    instructions.push(Instruction::Lea {
+                        src: ret.into(),
+                        dst: Reg::DI.width(Width::Eight),
+                    });
                 }
-                for (param, reg) in xmm_reg_args.into_iter().zip(&XMM_REG_ARGS) {
-                    instructions.push(Instruction::mov(param, reg.width(param.ty().width())));
+                for (param, reg) in gen_reg_args.iter().zip(Args::int_regs(return_in_memory)) {
+                    instructions.extend(move_reg_arg(param, *reg, false));
+                }
+                for (param, reg) in xmm_reg_args.iter().zip(&XMM_REG_ARGS) {
+                    instructions.extend(move_reg_arg(param, *reg, false));
                 }
 
                 for param in stack_args {
-                    let (param, asm_type) = param.into_operand();
+                    if param.ty().is_struct() {
+                        let eightbytes = stack_eightbytes(param);
+                        instructions.push(Instruction::AllocateStack(8 * eightbytes));
+                        instructions.extend(copy_bytes(
+                            param.ty().size(),
+                            sub_object(param, 0),
+                            |o, w| Operand::Memory(Reg::SP, o, w),
+                        ));
+                        continue;
+                    }
+                    if param.ty().is_promotable() {
+                        instructions.push(extend(param, Reg::AX.width(Width::Four)));
+                        instructions.push(Instruction::Push(Reg::AX.width(Width::Eight)));
+                        continue;
+                    }
+                    let param = Operand::from(param);
                     match param {
                         Operand::Immediate(_, _) | Operand::Register(_, _) => {
                             instructions.push(Instruction::Push(param));
                         }
-                        Operand::Psuedo(_, width) | Operand::Data(_, width) => {
+                        // floating values are pushed through %rax like the integers
+                        Operand::Psuedo(_, width)
+                        | Operand::Data(_, _, width)
+                        | Operand::Memory(_, _, width) => {
                             instructions.push(Instruction::Move {
-                                asm_type,
+                                asm_type: width.into(),
                                 source: param,
                                 destination: Reg::AX.width(width),
                             });
                             instructions.push(Instruction::Push(Reg::AX.width(Width::Eight)));
                         }
-                        Operand::Stack(_, _) => unreachable!(),
+                        Operand::Stack(_, _)
+                        | Operand::PsuedoMem(_, _, _)
+                        | Operand::Indexed(_, _, _) => unreachable!(),
                     }
                 }
 
-                instructions.push(Instruction::Call(func.clone()));
+                // variadic functions expect the number of vector registers used in %al
+                if matches!(callee.ty(), Type::Function { variadic: true, .. }) {
+                    instructions.push(Instruction::mov(
+                        (xmm_reg_args.len() as i64, Width::Four),
+                        Reg::AX.width(Width::Four),
+                    ));
+                }
+                match callee {
+                    tacky::Callee::Function(name, _) => {
+                        instructions.push(Instruction::Call(name.clone()))
+                    }
+                    // r11 is not used to pass arguments, so the pointer can wait there
+                    tacky::Callee::Pointer(ptr) => {
+                        let target = Reg::R11.width(Width::Eight);
+                        instructions.push(Instruction::mov(ptr, target.clone()));
+                        instructions.push(Instruction::IndirectCall(target));
+                    }
+                }
 
                 let bytes_to_remove = (8 * stack_args_len) + stack_padding;
                 if bytes_to_remove != 0 {
                     instructions.push(Instruction::DeallocateStack(bytes_to_remove));
                 }
 
-                let reg = match ret.ty() {
-                    Type::Double => (Reg::XMM0, ret.ty().width()),
-                    _ => (Reg::AX, ret.ty().width()),
-                };
-                instructions.push(Instruction::mov(reg, ret));
+                match ret {
+                    Some(_) if return_in_memory => {}
+                    Some(ret) if ret.ty().is_struct() => {
+                        let mut int = [Reg::AX, Reg::DX].iter();
+                        let mut sse = [Reg::XMM0, Reg::XMM1].iter();
+                        for (i, class) in classify(&ret.ty()).into_iter().enumerate() {
+                            let reg = match class {
+                                Class::Integer => int.next(),
+                                _ => sse.next(),
+                            };
+                            instructions.extend(store_eightbyte(*reg.unwrap(), ret, i));
+                        }
+                    }
+                    Some(ret) => {
+                        let reg = match ret.ty() {
+                            ty if ty.is_floating() => (Reg::XMM0, ty.width()),
+                            ty => (Reg::AX, ty.width()),
+                        };
+                        instructions.push(Instruction::mov(reg, ret));
+                    }
+                    None => {}
+                }
                 instructions
             }
             tacky::Instruction::SignExtend { src, dst } => vec![Instruction::Movesx {
@@ -632,13 +1237,25 @@ impl From<&tacky::Instruction> for Vec<Instruction> {
                 asm_type: dst.ty().into(),
                 source: match src {
                     tacky::Val::Constant(constant) => {
-                        Operand::Immediate(constant.as_long() as i32 as i64, dst.ty().width())
+                        let value = match dst.ty().width() {
+                            Width::One => constant.as_long() as i8 as i64,
+                            Width::Two => constant.as_long() as i16 as i64,
+                            Width::Four => constant.as_long() as i32 as i64,
+                            Width::Eight => constant.as_long(),
+                        };
+                        Operand::Immediate(value, dst.ty().width())
                     }
                     tacky::Val::Var(name, _) => Operand::Psuedo(name.clone(), dst.ty().width()),
                 },
                 destination: dst.into(),
             }],
 
+            tacky::Instruction::ZeroExtend { src, dst } if src.ty().is_promotable() => {
+                vec![Instruction::MovZeroExtend {
+                    source: src.into(),
+                    destination: dst.into(),
+                }]
+            }
             tacky::Instruction::ZeroExtend { src, dst } => {
                 vec![
                     Instruction::mov(src, Reg::R11.width(src.ty().width())),
@@ -646,79 +1263,357 @@ impl From<&tacky::Instruction> for Vec<Instruction> {
                 ]
             }
             tacky::Instruction::DoubleToInt { src, dst } => {
+                let (src, src_type) = src.into_operand();
                 let (dst, dst_type) = dst.into_operand();
                 vec![Instruction::Cvttsd2si {
+                    src_type,
                     dst_type,
-                    src: src.into(),
+                    src,
                     dst,
                 }]
             }
+            // truncate to a long and keep the low half
             tacky::Instruction::DoubleToUInt { src, dst } if dst.ty() == Type::UInt => {
-                let tmp = mktmp(AsmType::Quadword, true);
+                let (src, src_type) = src.into_operand();
                 vec![
                     Instruction::Cvttsd2si {
+                        src_type,
                         dst_type: AsmType::Quadword,
-                        src: src.into(),
-                        dst: tmp.clone(),
+                        src,
+                        dst: Reg::R10.width(Width::Eight),
                     },
-                    Instruction::Move {
-                        asm_type: AsmType::Longword,
-                        source: tmp,
-                        destination: dst.into(),
+                    Instruction::mov((Reg::R10, Width::Four), dst),
+                ]
+            }
+            tacky::Instruction::DoubleToUInt { src, dst } => double_to_ulong(src, dst),
+            tacky::Instruction::IntToDouble { src, dst } => {
+                let (src, src_type) = src.into_operand();
+                let (dst, dst_type) = dst.into_operand();
+                vec![Instruction::Cvtsi2sd {
+                    src_type,
+                    dst_type,
+                    src,
+                    dst,
+                }]
+            }
+            // zero extend to a long, which is then never negative
+            tacky::Instruction::UIntToDouble { src, dst } if src.ty() == Type::UInt => {
+                let (dst, dst_type) = dst.into_operand();
+                vec![
+                    Instruction::mov(src, Reg::R11.width(Width::Four)),
+                    Instruction::Cvtsi2sd {
+                        src_type: AsmType::Quadword,
+                        dst_type,
+                        src: Reg::R11.width(Width::Eight),
+                        dst,
                     },
                 ]
             }
-            tacky::Instruction::DoubleToUInt { src, dst } => {
-                let tmp = mktmp(AsmType::Quadword, true);
+            tacky::Instruction::UIntToDouble { src, dst } => ulong_to_double(src, dst),
+            tacky::Instruction::FloatToDouble { src, dst } => vec![Instruction::Cvtss2sd {
+                src: src.into(),
+                dst: dst.into(),
+            }],
+            tacky::Instruction::DoubleToFloat { src, dst } => vec![Instruction::Cvtsd2ss {
+                src: src.into(),
+                dst: dst.into(),
+            }],
+            tacky::Instruction::GetAddress { src, dst } => vec![Instruction::Lea {
+                src: src.into(),
+                dst: dst.into(),
+            }],
+            tacky::Instruction::Load { src_ptr, dst, .. } if dst.ty().is_struct() => {
+                let mut instructions = vec![Instruction::mov(src_ptr, Reg::AX.width(Width::Eight))];
+                instructions.extend(copy_bytes(
+                    dst.ty().size(),
+                    |o, w| Operand::Memory(Reg::AX, o, w),
+                    sub_object(dst, 0),
+                ));
+                instructions
+            }
+            tacky::Instruction::Load { src_ptr, dst, .. } => {
+                let (dst, asm_type) = dst.into_operand();
                 vec![
-                    Instruction::Cvttsd2si {
-                        dst_type: AsmType::Quadword,
-                        src: src.into(),
-                        dst: tmp.clone(),
+                    Instruction::mov(src_ptr, Reg::AX.width(Width::Eight)),
+                    Instruction::Move {
+                        asm_type,
+                        source: Operand::Memory(Reg::AX, 0, dst.width()),
+                        destination: dst,
                     },
+                ]
+            }
+            tacky::Instruction::Store { src, dst_ptr, .. } if src.ty().is_struct() => {
+                let mut instructions = vec![Instruction::mov(dst_ptr, Reg::AX.width(Width::Eight))];
+                instructions.extend(copy_bytes(src.ty().size(), sub_object(src, 0), |o, w| {
+                    Operand::Memory(Reg::AX, o, w)
+                }));
+                instructions
+            }
+            tacky::Instruction::Store { src, dst_ptr, .. } => {
+                let (src, asm_type) = src.into_operand();
+                vec![
+                    Instruction::mov(dst_ptr, Reg::AX.width(Width::Eight)),
                     Instruction::Move {
-                        asm_type: AsmType::Longword,
-                        source: tmp,
-                        destination: dst.into(),
+                        asm_type,
+                        destination: Operand::Memory(Reg::AX, 0, src.width()),
+                        source: src,
                     },
                 ]
             }
-            tacky::Instruction::IntToDouble { src, dst } => {
-                let (src, src_type) = src.into_operand();
-                vec![Instruction::Cvtsi2sd {
-                    src_type,
-                    src,
+            tacky::Instruction::AddPtr {
+                ptr,
+                index: tacky::Val::Constant(index),
+                scale,
+                dst,
+            } => vec![
+                Instruction::mov(ptr, Reg::AX.width(Width::Eight)),
+                Instruction::Lea {
+                    src: Operand::Memory(Reg::AX, (index.as_long() * scale) as i32, Width::Eight),
                     dst: dst.into(),
+                },
+            ],
+            tacky::Instruction::AddPtr {
+                ptr,
+                index,
+                scale: scale @ (1 | 2 | 4 | 8),
+                dst,
+            } => vec![
+                Instruction::mov(ptr, Reg::AX.width(Width::Eight)),
+                Instruction::mov(index, Reg::DX.width(Width::Eight)),
+                Instruction::Lea {
+                    src: Operand::Indexed(Reg::AX, Reg::DX, *scale as i32),
+                    dst: dst.into(),
+                },
+            ],
+            tacky::Instruction::AddPtr {
+                ptr,
+                index,
+                scale,
+                dst,
+            } => vec![
+                Instruction::mov(ptr, Reg::AX.width(Width::Eight)),
+                Instruction::mov(index, Reg::DX.width(Width::Eight)),
+                Instruction::Binary(
+                    AsmType::Quadword,
+                    BinaryOperator::Mult,
+                    Operand::Immediate(*scale, Width::Eight),
+                    Reg::DX.width(Width::Eight),
+                ),
+                Instruction::Lea {
+                    src: Operand::Indexed(Reg::AX, Reg::DX, 1),
+                    dst: dst.into(),
+                },
+            ],
+            tacky::Instruction::CopyToOffset {
+                src, dst, offset, ..
+            } if src.ty().is_struct() => {
+                let dst = dst.clone();
+                copy_bytes(src.ty().size(), sub_object(src, 0), move |o, w| {
+                    Operand::PsuedoMem(dst.clone(), *offset as i32 + o, w)
+                })
+            }
+            tacky::Instruction::CopyToOffset {
+                src, dst, offset, ..
+            } => {
+                let (source, asm_type) = src.into_operand();
+                vec![Instruction::Move {
+                    asm_type,
+                    destination: Operand::PsuedoMem(dst.clone(), *offset as i32, source.width()),
+                    source,
+                }]
+            }
+            tacky::Instruction::CopyFromOffset {
+                src, offset, dst, ..
+            } if dst.ty().is_struct() => {
+                let src = src.clone();
+                copy_bytes(
+                    dst.ty().size(),
+                    move |o, w| Operand::PsuedoMem(src.clone(), *offset as i32 + o, w),
+                    sub_object(dst, 0),
+                )
+            }
+            tacky::Instruction::CopyFromOffset {
+                src, offset, dst, ..
+            } => {
+                let (destination, asm_type) = dst.into_operand();
+                vec![Instruction::Move {
+                    asm_type,
+                    source: Operand::PsuedoMem(src.clone(), *offset as i32, destination.width()),
+                    destination,
                 }]
             }
-            tacky::Instruction::UIntToDouble { src, dst } => todo!(),
+            tacky::Instruction::VaStart(_) => {
+                unreachable!("va_start depends on the parameters of the enclosing function")
+            }
+            tacky::Instruction::VaArg { ap, dst } => va_arg(ap, dst),
         }
     }
 }
 
-/// Returns the size of stack that needs to be allocated
-fn replace_pseudo(instructions: &mut [Instruction], symbols: &SymbolTable) -> u32 {
-    let mut max = 8;
+/// Sign or zero extends a byte `src` into `dst`, depending on its signedness.
+fn extend(src: &Val, dst: Operand) -> Instruction {
+    if src.ty().signed() {
+        Instruction::Movesx {
+            source: src.into(),
+            destination: dst,
+        }
+    } else {
+        Instruction::MovZeroExtend {
+            source: src.into(),
+            destination: dst,
+        }
+    }
+}
+
+/// Converts the floating `src` to an unsigned long. Values of at least 2^63 are out of range
+/// for `cvttsd2si`, so they are brought into range first and the top bit is set afterwards.
+fn double_to_ulong(src: &Val, dst: &Val) -> Vec<Instruction> {
+    let (src, src_type) = src.into_operand();
+    let upper_bound = match src_type {
+        AsmType::Float => Operand::Immediate(((1u64 << 63) as f32).to_bits() as i64, Width::Four),
+        _ => Operand::Immediate(((1u64 << 63) as f64).to_bits() as i64, Width::Eight),
+    };
+    let out_of_range = format!("{}.double_to_ulong.out_of_range", aggregate_name(dst));
+    let end = format!("{}.double_to_ulong.end", aggregate_name(dst));
+    let scratch = Reg::XMM1.width(src.width());
+    vec![
+        Instruction::Cmp(src_type, upper_bound.clone(), src.clone()),
+        Instruction::JmpCC(CondCode::AE, out_of_range.clone()),
+        Instruction::Cvttsd2si {
+            src_type,
+            dst_type: AsmType::Quadword,
+            src: src.clone(),
+            dst: dst.into(),
+        },
+        Instruction::Jmp(end.clone()),
+        Instruction::Label(out_of_range),
+        Instruction::Move {
+            asm_type: src_type,
+            source: src,
+            destination: scratch.clone(),
+        },
+        Instruction::Binary(src_type, BinaryOperator::Sub, upper_bound, scratch.clone()),
+        Instruction::Cvttsd2si {
+            src_type,
+            dst_type: AsmType::Quadword,
+            src: scratch,
+            dst: dst.into(),
+        },
+        Instruction::Binary(
+            AsmType::Quadword,
+            BinaryOperator::Add,
+            Operand::Immediate(i64::MIN, Width::Eight),
+            dst.into(),
+        ),
+        Instruction::Label(end),
+    ]
+}
+
+/// Converts the unsigned long `src` to a floating type. Values with the top bit set would
+/// convert as negative, so they are halved first, rounding to odd, and doubled afterwards.
+fn ulong_to_double(src: &Val, dst: &Val) -> Vec<Instruction> {
+    let halve = format!("{}.ulong_to_double.halve", aggregate_name(dst));
+    let end = format!("{}.ulong_to_double.end", aggregate_name(dst));
+    let src = Operand::from(src);
+    let (dst, dst_type) = dst.into_operand();
+    let (ax, dx) = (Reg::AX.width(Width::Eight), Reg::DX.width(Width::Eight));
+    vec![
+        Instruction::Cmp(
+            AsmType::Quadword,
+            Operand::Immediate(0, Width::Eight),
+            src.clone(),
+        ),
+        Instruction::JmpCC(CondCode::L, halve.clone()),
+        Instruction::Cvtsi2sd {
+            src_type: AsmType::Quadword,
+            dst_type,
+            src: src.clone(),
+            dst: dst.clone(),
+        },
+        Instruction::Jmp(end.clone()),
+        Instruction::Label(halve),
+        Instruction::Move {
+            asm_type: AsmType::Quadword,
+            source: src,
+            destination: ax.clone(),
+        },
+        Instruction::Move {
+            asm_type: AsmType::Quadword,
+            source: ax.clone(),
+            destination: dx.clone(),
+        },
+        Instruction::Unary(AsmType::Quadword, UnaryOperator::Shr, dx.clone()),
+        Instruction::Binary(
+            AsmType::Quadword,
+            BinaryOperator::And,
+            Operand::Immediate(1, Width::Eight),
+            ax.clone(),
+        ),
+        Instruction::Binary(AsmType::Quadword, BinaryOperator::Or, ax, dx.clone()),
+        Instruction::Cvtsi2sd {
+            src_type: AsmType::Quadword,
+            dst_type,
+            src: dx,
+            dst: dst.clone(),
+        },
+        Instruction::Binary(dst_type, BinaryOperator::Add, dst.clone(), dst),
+        Instruction::Label(end),
+    ]
+}
+
+/// Returns the size of stack that needs to be allocated. `aggregates` holds the types of
+/// temporaries that are structures, which need more room than their width, and the top
+/// `reserved` bytes of the frame are left alone.
+fn replace_pseudo(
+    instructions: &mut [Instruction],
+    symbols: &SymbolTable,
+    aggregates: &HashMap<String, Type>,
+    reserved: i32,
+) -> u32 {
+    let mut max = reserved.max(8);
     let mut mapping: HashMap<String, i32> = Default::default();
     let mut m = |op: &mut Operand| {
-        match op {
-            Operand::Immediate(_, _) | Operand::Register(_, _) | Operand::Data(_, _) => {}
-            Operand::Psuedo(x, width) => match symbols.get(x) {
-                None
-                | Some(Symbol {
-                    attributes: SymbolAttributes::Local,
-                    ..
-                }) => {
-                    let stack = *mapping
-                        .entry(x.clone())
-                        .or_insert_with(|| max + width.bytes());
-                    *op = Operand::Stack(stack, *width);
-                    max = max.max(stack);
-                }
-                _ => *op = Operand::Data(x.clone(), *width),
-            },
-            Operand::Stack(x, _) => max = max.max(*x),
+        let (name, offset, width) = match op {
+            Operand::Immediate(_, _)
+            | Operand::Register(_, _)
+            | Operand::Data(_, _, _)
+            | Operand::Memory(_, _, _)
+            | Operand::Indexed(_, _, _) => return,
+            Operand::Stack(x, _) => {
+                max = max.max(*x);
+                return;
+            }
+            Operand::Psuedo(x, width) => (x, 0, *width),
+            Operand::PsuedoMem(x, offset, width) => (x, *offset, *width),
         };
+        match symbols.get(name) {
+            None if let Some(ty) = aggregates.get(name.as_str()) => {
+                let stack = *mapping.entry(name.clone()).or_insert_with(|| {
+                    ((max as u64 + ty.size()).next_multiple_of(ty.alignment())) as i32
+                });
+                max = max.max(stack);
+                *op = Operand::Stack(stack - offset, width);
+            }
+            None => {
+                let stack = *mapping.entry(name.clone()).or_insert_with(|| {
+                    ((max + width.bytes()) as u32).next_multiple_of(width.bytes() as u32) as i32
+                });
+                max = max.max(stack);
+                *op = Operand::Stack(stack - offset, width);
+            }
+            Some(Symbol {
+                attributes: SymbolAttributes::Local,
+                ty,
+                ..
+            }) => {
+                let stack = *mapping.entry(name.clone()).or_insert_with(|| {
+                    ((max as u64 + ty.size()).next_multiple_of(ty.variable_alignment())) as i32
+                });
+                max = max.max(stack);
+                *op = Operand::Stack(stack - offset, width);
+            }
+            _ => *op = Operand::Data(name.clone(), offset, width),
+        }
     };
 
     for i in instructions {
@@ -731,6 +1626,10 @@ fn replace_pseudo(instructions: &mut [Instruction], symbols: &SymbolTable) -> u3
             | Instruction::Movesx {
                 source,
                 destination,
+            }
+            | Instruction::MovZeroExtend {
+                source,
+                destination,
             } => {
                 m(source);
                 m(destination);
@@ -740,7 +1639,9 @@ fn replace_pseudo(instructions: &mut [Instruction], symbols: &SymbolTable) -> u3
                 m(operand);
                 m(operand1);
             }
-            Instruction::Idiv(_, operand) | Instruction::Div(_, operand) => m(operand),
+            Instruction::Idiv(_, operand)
+            | Instruction::Div(_, operand)
+            | Instruction::IndirectCall(operand) => m(operand),
             Instruction::Cmp(_, operand, operand1) => {
                 m(operand);
                 m(operand1);
@@ -758,16 +1659,11 @@ fn replace_pseudo(instructions: &mut [Instruction], symbols: &SymbolTable) -> u3
             | Instruction::DeallocateStack(_)
             | Instruction::Call(_)
             | Instruction::Comment(_) => {}
-            Instruction::Cvtsi2sd {
-                src_type: _,
-                src,
-                dst,
-            }
-            | Instruction::Cvttsd2si {
-                dst_type: _,
-                src,
-                dst,
-            } => {
+            Instruction::Cvtsi2sd { src, dst, .. }
+            | Instruction::Cvttsd2si { src, dst, .. }
+            | Instruction::Cvtss2sd { src, dst }
+            | Instruction::Cvtsd2ss { src, dst }
+            | Instruction::Lea { src, dst } => {
                 m(src);
                 m(dst);
             }
@@ -776,96 +1672,140 @@ fn replace_pseudo(instructions: &mut [Instruction], symbols: &SymbolTable) -> u3
     (max as u32).next_multiple_of(16)
 }
 
+/// Lowers the body of a function, returning the size of its stack frame.
 fn lower_instructions(
     params: &[tacky::Val],
+    return_in_memory: bool,
+    variadic: bool,
     instructions: &[tacky::Instruction],
     symbols: &SymbolTable,
-) -> Vec<Instruction> {
-    let mut gen_reg_args = vec![];
-    let mut xmm_reg_args = vec![];
-    let mut stack_args = vec![];
-
-    for arg in params.iter() {
-        match arg.ty() {
-            Type::Function { .. } | Type::Int | Type::Long | Type::UInt | Type::ULong => {
-                if gen_reg_args.len() == REG_ARGS.len() {
-                    stack_args.push(arg);
-                } else {
-                    gen_reg_args.push(arg);
-                }
-            }
-            Type::Double => {
-                if xmm_reg_args.len() == XMM_REG_ARGS.len() {
-                    stack_args.push(arg);
-                } else {
-                    xmm_reg_args.push(arg);
-                }
-            }
+) -> (u32, Vec<Instruction>) {
+    let Args {
+        int: gen_reg_args,
+        sse: xmm_reg_args,
+        stack: stack_args,
+    } = Args::assign(params, return_in_memory);
+
+    let mut stack_params = vec![];
+    let mut eightbytes = 0;
+    for param in stack_args {
+        let offset = -16 - 8 * eightbytes as i32;
+        if param.ty().is_struct() {
+            stack_params.extend(copy_bytes(
+                param.ty().size(),
+                move |o, w| Operand::Stack(offset - o, w),
+                sub_object(param, 0),
+            ));
+        } else {
+            stack_params.push(Instruction::Move {
+                asm_type: param.ty().into(),
+                source: Operand::Stack(offset, param.ty().width()),
+                destination: param.into(),
+            });
         }
+        eightbytes += stack_eightbytes(param);
     }
 
     let mut v: Vec<Instruction> = empty()
+        .chain(return_in_memory.then(|| {
+            Instruction::mov(
+                (Reg::DI, Width::Eight),
+                Operand::Psuedo(RETURN_ADDRESS.into(), Width::Eight),
+            )
+        }))
         .chain(
             gen_reg_args
-                .into_iter()
-                .zip(REG_ARGS)
-                .map(|(var, reg)| Instruction::mov((reg, var.ty().width()), var)),
+                .iter()
+                .zip(Args::int_regs(return_in_memory))
+                .flat_map(|(arg, reg)| move_reg_arg(arg, *reg, true)),
         )
         .chain(
             xmm_reg_args
-                .into_iter()
+                .iter()
                 .zip(XMM_REG_ARGS)
-                .map(|(var, reg)| Instruction::mov((reg, var.ty().width()), var)),
-        )
-        .chain(
-            stack_args
-                .into_iter()
-                .enumerate()
-                .map(|(i, param)| Instruction::Move {
-                    asm_type: param.ty().into(),
-                    source: Operand::Stack(i as i32 * -8 - 16, param.ty().width()),
-                    destination: param.into(),
-                }),
+                .flat_map(|(arg, reg)| move_reg_arg(arg, reg, true)),
         )
+        .chain(stack_params)
         .chain(instructions.iter().flat_map(|i| {
-            let v: Vec<_> = i.into();
+            let v: Vec<_> = match i {
+                tacky::Instruction::VaStart(ap) => va_start(
+                    ap,
+                    8 * (gen_reg_args.len() + return_in_memory as usize),
+                    8 * REG_ARGS.len() + 16 * xmm_reg_args.len(),
+                    16 + 8 * eightbytes,
+                ),
+                i => i.into(),
+            };
             // v.insert(0, Instruction::Comment(format!("{i:?}")));
             v
         }))
         .collect();
 
-    let alloc_stack = Instruction::AllocateStack(replace_pseudo(&mut v, symbols));
-    v.insert(0, alloc_stack);
-    v.into_iter().flat_map(fixup_instruction).collect()
+    let stack_size = replace_pseudo(
+        &mut v,
+        symbols,
+        &aggregate_temporaries(instructions),
+        if variadic { REGISTER_SAVE_AREA } else { 0 },
+    );
+    (
+        stack_size,
+        v.into_iter().flat_map(fixup_instruction).collect(),
+    )
+}
+
+/// The types of the structure temporaries defined by `instructions`.
+fn aggregate_temporaries(instructions: &[tacky::Instruction]) -> HashMap<String, Type> {
+    instructions
+        .iter()
+        .filter_map(|i| match i {
+            tacky::Instruction::Copy { dst, .. }
+            | tacky::Instruction::Load { dst, .. }
+            | tacky::Instruction::CopyFromOffset { dst, .. }
+            | tacky::Instruction::VaArg { dst, .. }
+            | tacky::Instruction::Call(_, _, Some(dst)) => match dst {
+                Val::Var(name, ty @ Type::Struct(_)) => Some((name.clone(), ty.clone())),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
 }
 
 fn mktmp(asm_type: AsmType, is_dest: bool) -> Operand {
     match (asm_type, is_dest) {
+        (AsmType::Byte, true) => Reg::R10.width(Width::One),
+        (AsmType::Byte, false) => Reg::R11.width(Width::One),
+        (AsmType::Word, true) => Reg::R10.width(Width::Two),
+        (AsmType::Word, false) => Reg::R11.width(Width::Two),
         (AsmType::Longword, true) => Reg::R10.width(Width::Four),
         (AsmType::Longword, false) => Reg::R11.width(Width::Four),
         (AsmType::Quadword, true) => Reg::R10.width(Width::Eight),
         (AsmType::Quadword, false) => Reg::R11.width(Width::Eight),
+        (AsmType::Float, true) => Reg::XMM14.width(Width::Four),
+        (AsmType::Float, false) => Reg::XMM15.width(Width::Four),
         (AsmType::Double, true) => Reg::XMM14.width(Width::Eight),
         (AsmType::Double, false) => Reg::XMM15.width(Width::Eight),
+        (AsmType::ByteArray { .. }, _) => unreachable!("byte arrays never live in registers"),
     }
 }
 
 fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
     let vec = match instruction.clone() {
         Instruction::Move {
-            asm_type: AsmType::Double,
+            asm_type: AsmType::Float | AsmType::Double,
             source,
             destination,
         } if matches!(source, Operand::Immediate(_, _)) => {
-            let tmp = Reg::R10.width(Width::Eight);
+            let asm_type = source.width().into();
+            let tmp = Reg::R10.width(source.width());
             vec![
                 Instruction::Move {
-                    asm_type: AsmType::Quadword,
+                    asm_type,
                     source,
                     destination: tmp.clone(),
                 },
                 Instruction::Move {
-                    asm_type: AsmType::Quadword,
+                    asm_type,
                     source: tmp.clone(),
                     destination,
                 },
@@ -875,10 +1815,7 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
             asm_type,
             source,
             destination,
-        } if (matches!(source, Operand::Stack(_, _) | Operand::Data(_, _))
-            || source.outside_int_range())
-            && matches!(destination, Operand::Stack(_, _) | Operand::Data(_, _)) =>
-        {
+        } if (source.is_memory() || source.outside_int_range()) && destination.is_memory() => {
             let tmp = mktmp(asm_type, true);
             vec![
                 Instruction::Move {
@@ -896,14 +1833,12 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
         Instruction::Movesx {
             source,
             destination,
-        } if matches!(source, Operand::Immediate(_, _))
-            || matches!(destination, Operand::Stack(_, _) | Operand::Data(_, _)) =>
-        {
+        } if matches!(source, Operand::Immediate(_, _)) || destination.is_memory() => {
             let tmp = Reg::R11.width(source.width());
             let tmp2 = Reg::R10.width(destination.width());
             vec![
                 Instruction::Move {
-                    asm_type: AsmType::Longword,
+                    asm_type: source.width().into(),
                     source,
                     destination: tmp.clone(),
                 },
@@ -912,7 +1847,30 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
                     destination: tmp2.clone(),
                 },
                 Instruction::Move {
-                    asm_type: AsmType::Quadword,
+                    asm_type: destination.width().into(),
+                    source: tmp2,
+                    destination,
+                },
+            ]
+        }
+        Instruction::MovZeroExtend {
+            source,
+            destination,
+        } if matches!(source, Operand::Immediate(_, _)) || destination.is_memory() => {
+            let tmp = Reg::R11.width(source.width());
+            let tmp2 = Reg::R10.width(destination.width());
+            vec![
+                Instruction::Move {
+                    asm_type: source.width().into(),
+                    source,
+                    destination: tmp.clone(),
+                },
+                Instruction::MovZeroExtend {
+                    source: tmp,
+                    destination: tmp2.clone(),
+                },
+                Instruction::Move {
+                    asm_type: destination.width().into(),
                     source: tmp2,
                     destination,
                 },
@@ -925,8 +1883,8 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
                     | BinaryOperator::SignedRightShift
                     | BinaryOperator::LeftShift
                     | BinaryOperator::RightShift
-            ) && matches!(src, Operand::Stack(_, _) | Operand::Data(_, _))
-                && matches!(dst, Operand::Stack(_, _) | Operand::Data(_, _)) =>
+            ) && src.is_memory()
+                && dst.is_memory() =>
         {
             let tmp = mktmp(asm_type, false);
             vec![
@@ -956,9 +1914,8 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
                     | BinaryOperator::SignedRightShift
                     | BinaryOperator::LeftShift
                     | BinaryOperator::RightShift
-            ) && matches!(dst, Operand::Stack(_, _) | Operand::Data(_, _)))
-                || (matches!(asm_type, AsmType::Double)
-                    && !matches!(dst, Operand::Register(_, _))) =>
+            ) && dst.is_memory())
+                || (asm_type.is_floating() && !matches!(dst, Operand::Register(_, _))) =>
         {
             let tmp = mktmp(asm_type, false);
             vec![
@@ -976,8 +1933,8 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
             ]
         }
         Instruction::Binary(asm_type, op, src, dst)
-            if (matches!(src, Operand::Stack(_, _) | Operand::Data(_, _))
-                && matches!(dst, Operand::Stack(_, _) | Operand::Data(_, _))
+            if (src.is_memory()
+                && dst.is_memory()
                 && !matches!(
                     op,
                     BinaryOperator::SignedLeftShift
@@ -985,7 +1942,8 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
                         | BinaryOperator::LeftShift
                         | BinaryOperator::RightShift
                 ))
-                || src.outside_int_range() =>
+                || src.outside_int_range()
+                || (asm_type.is_floating() && matches!(src, Operand::Immediate(_, _))) =>
         {
             let tmp = mktmp(asm_type, true);
             vec![
@@ -1017,10 +1975,9 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
             ]
         }
         Instruction::Cmp(asm_type, lhs, rhs)
-            if (matches!(lhs, Operand::Stack(_, _) | Operand::Data(_, _))
-                && matches!(rhs, Operand::Stack(_, _) | Operand::Data(_, _)))
+            if (lhs.is_memory() && rhs.is_memory())
                 || lhs.outside_int_range()
-                || (asm_type == AsmType::Double && matches!(lhs, Operand::Immediate(_, _))) =>
+                || (asm_type.is_floating() && matches!(lhs, Operand::Immediate(_, _))) =>
         {
             let tmp = mktmp(asm_type, true);
             vec![
@@ -1035,7 +1992,7 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
         Instruction::Cmp(asm_type, lhs, rhs)
             if matches!(rhs, Operand::Immediate(_, _))
                 || rhs.outside_int_range()
-                || (asm_type == AsmType::Double && !matches!(rhs, Operand::Register(_, _))) =>
+                || (asm_type.is_floating() && !matches!(rhs, Operand::Register(_, _))) =>
         {
             let tmp = mktmp(asm_type, false);
             vec![
@@ -1051,11 +2008,7 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
             let tmp = Reg::AX.width(op.width());
             vec![
                 Instruction::Move {
-                    asm_type: match op.width() {
-                        Width::One => unreachable!(),
-                        Width::Four => AsmType::Longword,
-                        Width::Eight => AsmType::Quadword,
-                    },
+                    asm_type: op.width().into(),
                     source: op,
                     destination: tmp,
                 },
@@ -1082,12 +2035,37 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
                 Instruction::Div(asm_type, Reg::R10.width(width)),
             ]
         }
-        Instruction::Cvttsd2si { dst_type, src, dst }
-            if !matches!(dst, Operand::Register(_, _)) =>
-        {
+        Instruction::Cvttsd2si {
+            src_type,
+            dst_type,
+            src: src @ Operand::Immediate(_, _),
+            dst,
+        } => {
+            let tmp = mktmp(src_type, true);
+            vec![
+                Instruction::Move {
+                    asm_type: src_type,
+                    source: src,
+                    destination: tmp.clone(),
+                },
+                Instruction::Cvttsd2si {
+                    src_type,
+                    dst_type,
+                    src: tmp,
+                    dst,
+                },
+            ]
+        }
+        Instruction::Cvttsd2si {
+            src_type,
+            dst_type,
+            src,
+            dst,
+        } if !matches!(dst, Operand::Register(_, _)) => {
             let tmp = mktmp(dst_type, false);
             vec![
                 Instruction::Cvttsd2si {
+                    src_type,
                     dst_type,
                     src,
                     dst: tmp.clone(),
@@ -1100,10 +2078,12 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
             ]
         }
 
-        Instruction::Cvtsi2sd { src_type, src, dst }
-            if matches!(src, Operand::Immediate(_, _))
-                || !matches!(dst, Operand::Register(_, _)) =>
-        {
+        Instruction::Cvtsi2sd {
+            src_type,
+            dst_type,
+            src,
+            dst,
+        } if matches!(src, Operand::Immediate(_, _)) || !matches!(dst, Operand::Register(_, _)) => {
             vec![
                 Instruction::Move {
                     asm_type: src_type,
@@ -1112,17 +2092,69 @@ fn fixup_instruction(instruction: Instruction) -> Vec<Instruction> {
                 },
                 Instruction::Cvtsi2sd {
                     src_type,
+                    dst_type,
                     src: Reg::R10.width(src.width()),
                     dst: Reg::XMM15.width(dst.width()),
                 },
                 Instruction::Move {
-                    asm_type: src_type,
+                    asm_type: dst_type,
                     source: Reg::XMM15.width(dst.width()),
                     destination: dst,
                 },
             ]
         }
 
+        Instruction::Cvtss2sd { src, dst } | Instruction::Cvtsd2ss { src, dst }
+            if matches!(src, Operand::Immediate(_, _))
+                || !matches!(dst, Operand::Register(_, _)) =>
+        {
+            let widen = matches!(instruction, Instruction::Cvtss2sd { .. });
+            let (src_type, dst_type) = match widen {
+                true => (AsmType::Float, AsmType::Double),
+                false => (AsmType::Double, AsmType::Float),
+            };
+            let src_tmp = mktmp(src_type, true);
+            let dst_tmp = mktmp(dst_type, false);
+            let convert = match widen {
+                true => Instruction::Cvtss2sd {
+                    src: src_tmp.clone(),
+                    dst: dst_tmp.clone(),
+                },
+                false => Instruction::Cvtsd2ss {
+                    src: src_tmp.clone(),
+                    dst: dst_tmp.clone(),
+                },
+            };
+            vec![
+                Instruction::Move {
+                    asm_type: src_type,
+                    source: src,
+                    destination: src_tmp,
+                },
+                convert,
+                Instruction::Move {
+                    asm_type: dst_type,
+                    source: dst_tmp,
+                    destination: dst,
+                },
+            ]
+        }
+
+        Instruction::Lea { src, dst } if !matches!(dst, Operand::Register(_, _)) => {
+            let tmp = mktmp(AsmType::Quadword, false);
+            vec![
+                Instruction::Lea {
+                    src,
+                    dst: tmp.clone(),
+                },
+                Instruction::Move {
+                    asm_type: AsmType::Quadword,
+                    source: tmp,
+                    destination: dst,
+                },
+            ]
+        }
+
         i => return vec![i],
     };
 
@@ -1138,19 +2170,42 @@ pub fn generate_assembly(program: &tacky::Program, symbols: &SymbolTable) -> Pro
         .functions
         .iter()
         .map(|func| {
-            let instructions = lower_instructions(&func.params, &func.instructions, symbols);
+            let Type::Function { ret, variadic, .. } = &symbols[&func.identifier].ty else {
+                unreachable!("{} is not a function", func.identifier)
+            };
+            let (stack_size, instructions) = lower_instructions(
+                &func.params,
+                returned_in_memory(ret),
+                *variadic,
+                &func.instructions,
+                symbols,
+            );
             Function {
                 name: func.identifier.clone(),
                 global: func.global,
+                stack_size,
+                variadic: *variadic,
                 instructions,
             }
         })
         .collect();
     let mut static_variables: Vec<_> = program.static_variables.iter().map(|v| v.into()).collect();
     static_variables.sort_by(|lhs: &StaticVariable, rhs: &StaticVariable| lhs.name.cmp(&rhs.name));
+    let mut static_constants: Vec<_> = symbols
+        .iter()
+        .filter_map(|(name, symbol)| match &symbol.attributes {
+            SymbolAttributes::Constant(init) => Some(StaticConstant {
+                name: name.clone(),
+                init: init.clone(),
+                alignment: symbol.ty.variable_alignment() as i32,
+            }),
+            _ => None,
+        })
+        .collect();
+    static_constants.sort_by(|lhs, rhs| lhs.name.cmp(&rhs.name));
     Program {
         definitions,
         static_variables,
-        static_constants: vec![],
+        static_constants,
     }
 }
@@ -2,7 +2,7 @@ use std::fmt::{Debug, DebugStruct};
 
 use miette::SourceSpan;
 
-use crate::sema::Type;
+use crate::sema::{StructType, Type};
 
 trait FieldIf {
     fn field_if(&mut self, name: impl AsRef<str>, value: &dyn Debug, r#if: bool) -> &mut Self;
@@ -87,6 +87,63 @@ pub enum Expression {
         expr: Box<Expression>,
         span: SourceSpan,
     },
+    AddrOf {
+        expr: Box<Expression>,
+        span: SourceSpan,
+    },
+    Dereference {
+        expr: Box<Expression>,
+        span: SourceSpan,
+    },
+    Subscript {
+        array: Box<Expression>,
+        index: Box<Expression>,
+        span: SourceSpan,
+    },
+    /// A string literal, with escapes resolved and without the terminating null byte.
+    String {
+        value: Vec<u8>,
+        span: SourceSpan,
+    },
+    SizeOf {
+        expr: Box<Expression>,
+        span: SourceSpan,
+    },
+    SizeOfType {
+        ty: Type,
+        span: SourceSpan,
+    },
+    Dot {
+        expr: Box<Expression>,
+        member: String,
+        span: SourceSpan,
+    },
+    Arrow {
+        expr: Box<Expression>,
+        member: String,
+        span: SourceSpan,
+    },
+    /// `va_start(ap, last)`. Only `ap` is kept, since the variable arguments are found without
+    /// the last named parameter.
+    VaStart {
+        ap: Box<Expression>,
+        span: SourceSpan,
+    },
+    /// `va_arg(ap, ty)`, which takes the next variable argument as a `ty`.
+    VaArg {
+        ap: Box<Expression>,
+        ty: Type,
+        span: SourceSpan,
+    },
+    VaCopy {
+        dst: Box<Expression>,
+        src: Box<Expression>,
+        span: SourceSpan,
+    },
+    VaEnd {
+        ap: Box<Expression>,
+        span: SourceSpan,
+    },
 }
 
 impl Default for Expression {
@@ -109,7 +166,19 @@ impl Spanned for Expression {
             | Expression::CompoundAssignment { span, .. }
             | Expression::Ternary { span, .. }
             | Expression::FunctionCall { span, .. }
-            | Expression::Cast { span, .. } => *span,
+            | Expression::Cast { span, .. }
+            | Expression::AddrOf { span, .. }
+            | Expression::Dereference { span, .. }
+            | Expression::Subscript { span, .. }
+            | Expression::String { span, .. }
+            | Expression::SizeOf { span, .. }
+            | Expression::SizeOfType { span, .. }
+            | Expression::Dot { span, .. }
+            | Expression::Arrow { span, .. }
+            | Expression::VaStart { span, .. }
+            | Expression::VaArg { span, .. }
+            | Expression::VaCopy { span, .. }
+            | Expression::VaEnd { span, .. } => *span,
         }
     }
 }
@@ -176,6 +245,52 @@ impl Debug for Expression {
                 .field("to", to)
                 .field("expr", expr)
                 .finish(),
+            Self::AddrOf { expr, span: _ } => f.debug_tuple("AddrOf").field(expr).finish(),
+            Self::Dereference { expr, span: _ } => {
+                f.debug_tuple("Dereference").field(expr).finish()
+            }
+            Self::Subscript {
+                array,
+                index,
+                span: _,
+            } => f
+                .debug_struct("Subscript")
+                .field("array", array)
+                .field("index", index)
+                .finish(),
+            Self::String { value, span: _ } => write!(f, "String(\"{}\")", value.escape_ascii()),
+            Self::SizeOf { expr, span: _ } => f.debug_tuple("SizeOf").field(expr).finish(),
+            Self::SizeOfType { ty, span: _ } => f.debug_tuple("SizeOfType").field(ty).finish(),
+            Self::Dot {
+                expr,
+                member,
+                span: _,
+            } => f
+                .debug_struct("Dot")
+                .field("expr", expr)
+                .field("member", member)
+                .finish(),
+            Self::Arrow {
+                expr,
+                member,
+                span: _,
+            } => f
+                .debug_struct("Arrow")
+                .field("expr", expr)
+                .field("member", member)
+                .finish(),
+            Self::VaStart { ap, span: _ } => f.debug_tuple("VaStart").field(ap).finish(),
+            Self::VaArg { ap, ty, span: _ } => f
+                .debug_struct("VaArg")
+                .field("ap", ap)
+                .field("ty", ty)
+                .finish(),
+            Self::VaCopy { dst, src, span: _ } => f
+                .debug_struct("VaCopy")
+                .field("dst", dst)
+                .field("src", src)
+                .finish(),
+            Self::VaEnd { ap, span: _ } => f.debug_tuple("VaEnd").field(ap).finish(),
         }
     }
 }
@@ -186,7 +301,12 @@ pub enum Constant {
     Long(i64),
     UInt(u32),
     ULong(u64),
+    Short(i16),
+    UShort(u16),
+    Float(f32),
     Double(f64),
+    Char(i8),
+    UChar(u8),
 }
 
 impl Constant {
@@ -196,7 +316,21 @@ impl Constant {
             Constant::Long(v) => *v,
             Constant::UInt(v) => *v as i64,
             Constant::ULong(v) => *v as i64,
+            Constant::Short(v) => *v as i64,
+            Constant::UShort(v) => *v as i64,
+            Constant::Float(v) => *v as i64,
             Constant::Double(v) => *v as i64,
+            Constant::Char(v) => *v as i64,
+            Constant::UChar(v) => *v as i64,
+        }
+    }
+
+    pub fn as_double(&self) -> f64 {
+        match self {
+            Constant::ULong(v) => *v as f64,
+            Constant::Float(v) => *v as f64,
+            Constant::Double(v) => *v,
+            c => c.as_long() as f64,
         }
     }
 
@@ -206,7 +340,12 @@ impl Constant {
             Constant::Long(_) => Type::Long,
             Constant::UInt(_) => Type::UInt,
             Constant::ULong(_) => Type::ULong,
+            Constant::Short(_) => Type::Short,
+            Constant::UShort(_) => Type::UShort,
+            Constant::Float(_) => Type::Float,
             Constant::Double(_) => Type::Double,
+            Constant::Char(_) => Type::Char,
+            Constant::UChar(_) => Type::UChar,
         }
     }
 }
@@ -218,7 +357,12 @@ impl Debug for Constant {
             Self::Long(arg0) => write!(f, "{arg0}l"),
             Self::UInt(arg0) => write!(f, "{arg0}u"),
             Self::ULong(arg0) => write!(f, "{arg0}ul"),
+            Self::Short(arg0) => write!(f, "{arg0}s"),
+            Self::UShort(arg0) => write!(f, "{arg0}us"),
+            Self::Float(d) => write!(f, "{d:?}f"),
             Self::Double(d) => write!(f, "{d:?}"),
+            Self::Char(c) => write!(f, "{c}c"),
+            Self::UChar(c) => write!(f, "{c}uc"),
         }
     }
 }
@@ -254,11 +398,16 @@ pub enum BinaryOperator {
     NotEqual,
     And,
     Or,
+    /// Evaluates the left operand for its side effects, then yields the right one.
+    Comma,
 }
 
 #[derive()]
 pub enum Statement {
-    Return(Expression),
+    Return {
+        expr: Option<Expression>,
+        span: SourceSpan,
+    },
     Expression(Expression),
     If {
         cond: Expression,
@@ -294,7 +443,7 @@ pub enum Statement {
         label: Option<String>,
     },
     For {
-        init: ForInit,
+        init: Box<ForInit>,
         condition: Option<Expression>,
         post: Option<Expression>,
         body: Box<Statement>,
@@ -321,7 +470,14 @@ pub enum Statement {
 impl Debug for Statement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Return(arg0) => f.debug_tuple("Return").field(arg0).finish(),
+            Self::Return {
+                expr: Some(expr),
+                span: _,
+            } => f.debug_tuple("Return").field(expr).finish(),
+            Self::Return {
+                expr: None,
+                span: _,
+            } => write!(f, "Return"),
             Self::Expression(arg0) => f.debug_tuple("Expression").field(arg0).finish(),
             Self::If {
                 cond: arg0,
@@ -456,6 +612,9 @@ impl Spanned for ForInit {
 pub enum Declaration {
     Variable(VariableDeclaration),
     Function(FunctionDeclaration),
+    Struct(StructDeclaration),
+    Enum(EnumDeclaration),
+    Typedef(TypedefDeclaration),
 }
 
 impl Debug for Declaration {
@@ -463,6 +622,9 @@ impl Debug for Declaration {
         match self {
             Self::Variable(arg0) => arg0.fmt(f),
             Self::Function(arg0) => arg0.fmt(f),
+            Self::Struct(arg0) => arg0.fmt(f),
+            Self::Enum(arg0) => arg0.fmt(f),
+            Self::Typedef(arg0) => arg0.fmt(f),
         }
     }
 }
@@ -472,6 +634,9 @@ impl Spanned for Declaration {
         match self {
             Declaration::Variable(variable_declaration) => variable_declaration.span,
             Declaration::Function(function_declaration) => function_declaration.span,
+            Declaration::Struct(struct_declaration) => struct_declaration.span,
+            Declaration::Enum(enum_declaration) => enum_declaration.span,
+            Declaration::Typedef(typedef_declaration) => typedef_declaration.span,
         }
     }
 }
@@ -480,6 +645,7 @@ impl Spanned for Declaration {
 pub struct FunctionDeclaration {
     pub identifier: String,
     pub params: Vec<(Type, String, SourceSpan)>,
+    pub variadic: bool,
     pub ret: Type,
     pub body: Option<Block>,
     pub storage: Option<StorageClass>,
@@ -491,6 +657,7 @@ impl Debug for FunctionDeclaration {
         let Self {
             identifier,
             params,
+            variadic,
             ret,
             body,
             storage,
@@ -499,6 +666,7 @@ impl Debug for FunctionDeclaration {
         f.debug_struct("FunctionDeclaration")
             .field("identifier", identifier)
             .field("params", params)
+            .field_if("variadic", variadic, *variadic)
             .field("ret", ret)
             .field_if_set("body", body)
             .field_if_set("storage", storage)
@@ -506,11 +674,83 @@ impl Debug for FunctionDeclaration {
     }
 }
 
+/// A structure declaration, which defines the structure if it has a member list.
+#[derive()]
+pub struct StructDeclaration {
+    pub ty: StructType,
+    pub members: Option<Vec<(Type, String, SourceSpan)>>,
+    pub span: SourceSpan,
+}
+
+impl Debug for StructDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            ty,
+            members,
+            span: _,
+        } = self;
+        let name = if ty.is_union() {
+            "UnionDeclaration"
+        } else {
+            "StructDeclaration"
+        };
+        f.debug_struct(name)
+            .field("tag", &ty.tag())
+            .field_if_set("members", members)
+            .finish()
+    }
+}
+
+/// An enumeration, whose enumerators are `int` constants. Enumerators without a value are one
+/// more than the previous one.
+pub struct EnumDeclaration {
+    pub tag: Option<String>,
+    pub enumerators: Vec<(String, Option<Expression>, SourceSpan)>,
+    pub span: SourceSpan,
+}
+
+impl Debug for EnumDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            tag,
+            enumerators,
+            span: _,
+        } = self;
+        let enumerators: Vec<_> = enumerators
+            .iter()
+            .map(|(name, value, _)| (name, value))
+            .collect();
+        f.debug_struct("EnumDeclaration")
+            .field_if_set("tag", tag)
+            .field("enumerators", &enumerators)
+            .finish()
+    }
+}
+
+/// A typedef name. The parser substitutes `ty` wherever the name is used as a type specifier,
+/// so later passes only see it for completeness.
+#[derive()]
+pub struct TypedefDeclaration {
+    pub name: String,
+    pub ty: Type,
+    pub span: SourceSpan,
+}
+
+impl Debug for TypedefDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { name, ty, span: _ } = self;
+        f.debug_struct("TypedefDeclaration")
+            .field("name", name)
+            .field("ty", ty)
+            .finish()
+    }
+}
+
 #[derive()]
 pub struct VariableDeclaration {
     pub name: String,
     pub ty: Type,
-    pub init: Option<Expression>,
+    pub init: Option<Initializer>,
     pub storage: Option<StorageClass>,
     pub span: SourceSpan,
 }
@@ -533,6 +773,30 @@ impl Debug for VariableDeclaration {
     }
 }
 
+#[derive(Clone)]
+pub enum Initializer {
+    Single(Expression),
+    Compound(Vec<Initializer>, SourceSpan),
+}
+
+impl Debug for Initializer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Single(expr) => expr.fmt(f),
+            Self::Compound(inits, _) => f.debug_tuple("Compound").field(inits).finish(),
+        }
+    }
+}
+
+impl Spanned for Initializer {
+    fn span(&self) -> SourceSpan {
+        match self {
+            Initializer::Single(expr) => expr.span(),
+            Initializer::Compound(_, span) => *span,
+        }
+    }
+}
+
 #[derive()]
 pub enum BlockItem {
     Statement(Statement),
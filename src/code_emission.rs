@@ -1,78 +1,233 @@
-use std::io::{self, BufWriter, Write};
+use std::{
+    collections::HashSet,
+    io::{self, BufWriter, Write},
+};
 
-use crate::{assembly_gen::*, ast::Constant};
+use crate::{assembly_gen::*, ast::Constant, sema::StaticInit, target::Target};
 
-#[cfg(not(target_os = "macos"))]
-const SYMBOL_PREFIX: &'static str = "";
-#[cfg(target_os = "macos")]
-const SYMBOL_PREFIX: &str = "_";
-
-pub fn emit_asm(program: &Program, w: impl io::Write) -> io::Result<()> {
+pub fn emit_asm(program: &Program, target: Target, w: impl io::Write) -> io::Result<()> {
     let mut w = BufWriter::new(w);
     for sv in program.static_variables.iter() {
-        if matches!(
-            sv.value,
-            Constant::UInt(0)
-                | Constant::Int(0)
-                | Constant::Long(0)
-                | Constant::ULong(0)
-                | Constant::Double(0.0)
-        ) {
-            symbol(sv.global, &sv.name, "bss", &mut w)?;
-            writeln!(&mut w, "\t.zero {}\n", sv.alignment)?;
-        } else if let Constant::Double(d) = sv.value {
-            symbol(sv.global, &sv.name, "data", &mut w)?;
-            writeln!(&mut w, "\t.quad 0x{:x}", d.to_bits())?;
-        } else if sv.alignment == 4 {
-            symbol(sv.global, &sv.name, "data", &mut w)?;
-            writeln!(&mut w, "\t.long {}", sv.value.as_long())?;
-        } else if sv.alignment == 8 {
-            symbol(sv.global, &sv.name, "data", &mut w)?;
-            writeln!(&mut w, "\t.quad {}", sv.value.as_long())?;
+        let zeroed = !sv.read_only && sv.init.iter().all(is_zero);
+        let section = if sv.read_only {
+            target.read_only_section()
+        } else if zeroed {
+            "bss"
         } else {
-            unreachable!()
+            "data"
+        };
+        let symbol = Symbol::Object(sv.alignment);
+        self::symbol(sv.global, &sv.name, section, symbol, target, &mut w)?;
+        if zeroed {
+            let size: u64 = sv.init.iter().map(init_size).sum();
+            writeln!(&mut w, "\t.zero {size}")?;
+            end_symbol(&sv.name, target, &mut w)?;
+            writeln!(&mut w)?;
+            continue;
+        }
+        for init in sv.init.iter() {
+            static_init(init, target, &mut w)?;
         }
+        end_symbol(&sv.name, target, &mut w)?;
     }
+    for sc in program.static_constants.iter() {
+        let section = target.constant_section();
+        let symbol = Symbol::Object(sc.alignment);
+        self::symbol(false, &sc.name, section, symbol, target, &mut w)?;
+        static_init(&sc.init, target, &mut w)?;
+        end_symbol(&sc.name, target, &mut w)?;
+    }
+    // symbols defined elsewhere may be in a shared library, so that calls to them have to go
+    // through the procedure linkage table and their addresses come from the global offset table
+    let defined = program.defined_symbols();
     for definition in program.definitions.iter() {
-        function_definition(definition, &mut w)?;
+        function_definition(definition, &defined, target, &mut w)?;
+    }
+    if target == Target::Linux {
+        // without this note the linker assumes the program needs an executable stack
+        writeln!(w, "\t.section .note.GNU-stack,\"\",@progbits")?;
     }
     w.flush()
 }
 
-fn symbol(global: bool, name: &str, section: &str, mut w: impl io::Write) -> io::Result<()> {
-    if global {
-        w.write_all(b"\t.globl ")?;
+fn static_init(init: &StaticInit, target: Target, mut w: impl io::Write) -> io::Result<()> {
+    match init {
+        StaticInit::Zero(n) => writeln!(w, "\t.zero {n}"),
+        StaticInit::Scalar(Constant::Float(f)) => writeln!(w, "\t.long 0x{:x}", f.to_bits()),
+        StaticInit::Scalar(Constant::Double(d)) => writeln!(w, "\t.quad 0x{:x}", d.to_bits()),
+        StaticInit::Scalar(c @ (Constant::Char(_) | Constant::UChar(_))) => {
+            writeln!(w, "\t.byte {}", c.as_long())
+        }
+        StaticInit::Scalar(c @ (Constant::Short(_) | Constant::UShort(_))) => {
+            writeln!(w, "\t.short {}", c.as_long())
+        }
+        StaticInit::Scalar(c @ (Constant::Int(_) | Constant::UInt(_))) => {
+            writeln!(w, "\t.long {}", c.as_long())
+        }
+        StaticInit::Scalar(c @ (Constant::Long(_) | Constant::ULong(_))) => {
+            writeln!(w, "\t.quad {}", c.as_long())
+        }
+        StaticInit::String(bytes, null_terminated) => writeln!(
+            w,
+            "\t.{} \"{}\"",
+            if *null_terminated { "asciz" } else { "ascii" },
+            escape(bytes)
+        ),
+        StaticInit::Pointer(name, 0) => writeln!(w, "\t.quad {}{name}", target.symbol_prefix()),
+        StaticInit::Pointer(name, offset) => {
+            writeln!(w, "\t.quad {}{name}{offset:+}", target.symbol_prefix())
+        }
+    }
+}
+
+/// Escapes `bytes` for use in an assembler string directive.
+fn escape(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| match b {
+            b'"' | b'\\' => format!("\\{}", *b as char),
+            b' '..=b'~' => (*b as char).to_string(),
+            _ => format!("\\{b:03o}"),
+        })
+        .collect()
+}
+
+pub fn is_zero(init: &StaticInit) -> bool {
+    match init {
+        StaticInit::Zero(_) => true,
+        StaticInit::Scalar(Constant::Float(f)) => f.to_bits() == 0,
+        StaticInit::Scalar(Constant::Double(d)) => d.to_bits() == 0,
+        StaticInit::Scalar(c) => c.as_long() == 0,
+        StaticInit::String(..) | StaticInit::Pointer(..) => false,
+    }
+}
 
-        w.write_all(SYMBOL_PREFIX.as_bytes())?;
+fn init_size(init: &StaticInit) -> u64 {
+    match init {
+        StaticInit::Zero(n) => *n,
+        StaticInit::Scalar(c) => c.ty().size(),
+        StaticInit::String(bytes, null_terminated) => bytes.len() as u64 + *null_terminated as u64,
+        StaticInit::Pointer(..) => 8,
+    }
+}
 
-        w.write_all(name.as_bytes())?;
-        writeln!(w)?;
+/// What a symbol names.
+#[derive(Clone, Copy)]
+enum Symbol {
+    Function,
+    /// An object, with its alignment.
+    Object(i32),
+}
+
+fn symbol(
+    global: bool,
+    name: &str,
+    section: &str,
+    symbol: Symbol,
+    target: Target,
+    mut w: impl io::Write,
+) -> io::Result<()> {
+    let prefix = target.symbol_prefix();
+    if global {
+        writeln!(w, "\t.globl {prefix}{name}")?;
     }
 
     w.write_all(b"\t.")?;
     w.write_all(section.as_bytes())?;
     w.write_all(b"\n")?;
-    w.write_all(SYMBOL_PREFIX.as_bytes())?;
-    w.write_all(name.as_bytes())?;
-    w.write_all(b":\n")
+    if let Symbol::Object(alignment) = symbol {
+        writeln!(w, "\t.balign {alignment}")?;
+    }
+    if target == Target::Linux {
+        let ty = match symbol {
+            Symbol::Function => "function",
+            Symbol::Object(_) => "object",
+        };
+        writeln!(w, "\t.type {prefix}{name}, @{ty}")?;
+    }
+    writeln!(w, "{prefix}{name}:")
 }
 
-fn function_definition(function: &Function, mut w: impl io::Write) -> io::Result<()> {
-    symbol(function.global, &function.name, "text", &mut w)?;
+/// Records the size of the symbol that the last [`symbol`] started, for debuggers and the
+/// dynamic linker.
+fn end_symbol(name: &str, target: Target, mut w: impl io::Write) -> io::Result<()> {
+    if target == Target::Linux {
+        writeln!(w, "\t.size {name}, .-{name}")?;
+    }
+    Ok(())
+}
+
+fn function_definition(
+    function: &Function,
+    defined: &HashSet<&str>,
+    target: Target,
+    mut w: impl io::Write,
+) -> io::Result<()> {
+    let name = &function.name;
+    symbol(
+        function.global,
+        name,
+        "text",
+        Symbol::Function,
+        target,
+        &mut w,
+    )?;
 
     w.write_all(b"\tpushq %rbp\n")?;
     w.write_all(b"\tmovq %rsp, %rbp\n")?;
+    writeln!(w, "\tsubq ${}, %rsp", function.stack_size)?;
+    if function.variadic {
+        // spill the argument registers for va_arg, in the order of the register save area
+        for (i, reg) in REG_ARGS.iter().enumerate() {
+            let slot = Operand::Stack(REGISTER_SAVE_AREA - 8 * i as i32, Width::Eight);
+            let reg = Operand::Register(*reg, Width::Eight);
+            writeln!(
+                w,
+                "\tmovq {}, {}",
+                operand(&reg, target),
+                operand(&slot, target)
+            )?;
+        }
+        for (i, reg) in XMM_REG_ARGS.iter().enumerate() {
+            let offset = 8 * REG_ARGS.len() as i32 + 16 * i as i32;
+            let slot = Operand::Stack(REGISTER_SAVE_AREA - offset, Width::Eight);
+            let reg = Operand::Register(*reg, Width::Eight);
+            writeln!(
+                w,
+                "\tmovsd {}, {}",
+                operand(&reg, target),
+                operand(&slot, target)
+            )?;
+        }
+    }
 
     for inst in function.instructions.iter() {
         w.write_all(b"\t")?;
-        instruction(inst, &mut w)?;
+        instruction(inst, defined, target, &mut w)?;
     }
 
-    Ok(())
+    end_symbol(name, target, &mut w)
 }
 
-fn instruction(instruction: &Instruction, mut w: impl io::Write) -> io::Result<()> {
+fn instruction(
+    instruction: &Instruction,
+    defined: &HashSet<&str>,
+    target: Target,
+    mut w: impl io::Write,
+) -> io::Result<()> {
+    let local = target.local_label_prefix();
     match instruction {
+        // 32-bit moves between general purpose and vector registers are spelled movd
+        Instruction::Move {
+            asm_type: AsmType::Longword,
+            source,
+            destination,
+        } if is_xmm(source) || is_xmm(destination) => write!(
+            w,
+            "movd {}, {}",
+            operand(source, target),
+            operand(destination, target)
+        )?,
         Instruction::Move {
             asm_type,
             source,
@@ -81,46 +236,143 @@ fn instruction(instruction: &Instruction, mut w: impl io::Write) -> io::Result<(
             w,
             "mov{} {}, {}",
             asm_type,
-            operand(source),
-            operand(destination)
+            operand(source, target),
+            operand(destination, target)
         )?,
         Instruction::Movesx {
             source,
             destination,
-        } => write!(w, "movslq {}, {}", operand(source), operand(destination))?,
+        } => write!(
+            w,
+            "movs{}{} {}, {}",
+            AsmType::from(source.width()),
+            AsmType::from(destination.width()),
+            operand(source, target),
+            operand(destination, target)
+        )?,
+        Instruction::MovZeroExtend {
+            source,
+            destination,
+        } => write!(
+            w,
+            "movz{}{} {}, {}",
+            AsmType::from(source.width()),
+            AsmType::from(destination.width()),
+            operand(source, target),
+            operand(destination, target)
+        )?,
         Instruction::Ret => write!(w, "movq %rbp, %rsp\n\tpopq %rbp\n\tret")?,
         Instruction::Unary(ty, unary_operator, op) => match unary_operator {
-            UnaryOperator::Shr => write!(w, "shr{} {}", ty, operand(op)),
-            UnaryOperator::Neg => write!(w, "neg{} {}", ty, operand(op)),
-            UnaryOperator::Not => write!(w, "not{} {}", ty, operand(op)),
+            UnaryOperator::Shr => write!(w, "shr{} {}", ty, operand(op, target)),
+            UnaryOperator::Neg => write!(w, "neg{} {}", ty, operand(op, target)),
+            UnaryOperator::Not => write!(w, "not{} {}", ty, operand(op, target)),
         }?,
         Instruction::AllocateStack(offset) => write!(w, "subq ${offset}, %rsp")?,
         Instruction::Binary(ty, binary_operator, op, operand1) => match binary_operator {
-            BinaryOperator::Add => write!(w, "add{} {}, {}", ty, operand(op), operand(operand1)),
-            BinaryOperator::Sub => write!(w, "sub{} {}, {}", ty, operand(op), operand(operand1)),
-            BinaryOperator::Mult if ty == &AsmType::Double => {
-                write!(w, "mul{} {}, {}", ty, operand(op), operand(operand1))
+            BinaryOperator::Add => write!(
+                w,
+                "add{} {}, {}",
+                ty,
+                operand(op, target),
+                operand(operand1, target)
+            ),
+            BinaryOperator::Sub => write!(
+                w,
+                "sub{} {}, {}",
+                ty,
+                operand(op, target),
+                operand(operand1, target)
+            ),
+            BinaryOperator::Mult if ty.is_floating() => {
+                write!(
+                    w,
+                    "mul{} {}, {}",
+                    ty,
+                    operand(op, target),
+                    operand(operand1, target)
+                )
             }
-            BinaryOperator::Mult => write!(w, "imul{} {}, {}", ty, operand(op), operand(operand1)),
-            BinaryOperator::And => write!(w, "and{} {}, {}", ty, operand(op), operand(operand1)),
-            BinaryOperator::Or => write!(w, "or{} {}, {}", ty, operand(op), operand(operand1)),
+            BinaryOperator::Mult => write!(
+                w,
+                "imul{} {}, {}",
+                ty,
+                operand(op, target),
+                operand(operand1, target)
+            ),
+            BinaryOperator::And => write!(
+                w,
+                "and{} {}, {}",
+                ty,
+                operand(op, target),
+                operand(operand1, target)
+            ),
+            BinaryOperator::Or => write!(
+                w,
+                "or{} {}, {}",
+                ty,
+                operand(op, target),
+                operand(operand1, target)
+            ),
             BinaryOperator::Xor if *ty == AsmType::Double => {
-                write!(w, "xorpd {}, {}", operand(op), operand(operand1))
+                write!(
+                    w,
+                    "xorpd {}, {}",
+                    operand(op, target),
+                    operand(operand1, target)
+                )
             }
-            BinaryOperator::Xor => write!(w, "xor{} {}, {}", ty, operand(op), operand(operand1)),
+            BinaryOperator::Xor => write!(
+                w,
+                "xor{} {}, {}",
+                ty,
+                operand(op, target),
+                operand(operand1, target)
+            ),
             BinaryOperator::SignedLeftShift => {
-                write!(w, "sal{} {}, {}", ty, operand(op), operand(operand1))
+                write!(
+                    w,
+                    "sal{} {}, {}",
+                    ty,
+                    operand(op, target),
+                    operand(operand1, target)
+                )
             }
             BinaryOperator::SignedRightShift => {
-                write!(w, "sar{} {}, {}", ty, operand(op), operand(operand1))
+                write!(
+                    w,
+                    "sar{} {}, {}",
+                    ty,
+                    operand(op, target),
+                    operand(operand1, target)
+                )
             }
             BinaryOperator::LeftShift => {
-                write!(w, "shl{} {}, {}", ty, operand(op), operand(operand1))
+                write!(
+                    w,
+                    "shl{} {}, {}",
+                    ty,
+                    operand(op, target),
+                    operand(operand1, target)
+                )
             }
             BinaryOperator::RightShift => {
-                write!(w, "shr{} {}, {}", ty, operand(op), operand(operand1))
+                write!(
+                    w,
+                    "shr{} {}, {}",
+                    ty,
+                    operand(op, target),
+                    operand(operand1, target)
+                )
+            }
+            BinaryOperator::DivDouble => {
+                write!(
+                    w,
+                    "div{} {}, {}",
+                    ty,
+                    operand(op, target),
+                    operand(operand1, target)
+                )
             }
-            BinaryOperator::DivDouble => todo!(),
             BinaryOperator::Equals
             | BinaryOperator::NotEquals
             | BinaryOperator::LessThan
@@ -128,81 +380,175 @@ fn instruction(instruction: &Instruction, mut w: impl io::Write) -> io::Result<(
             | BinaryOperator::GreaterThan
             | BinaryOperator::GreaterThanOrEqual => unreachable!(),
         }?,
-        Instruction::Div(ty, op) => write!(w, "div{} {}", ty, operand(op))?,
-        Instruction::Idiv(ty, op) => write!(w, "idiv{} {}", ty, operand(op))?,
-        Instruction::Cdq(AsmType::Double) => unreachable!(),
+        Instruction::Div(ty, op) => write!(w, "div{} {}", ty, operand(op, target))?,
+        Instruction::Idiv(ty, op) => write!(w, "idiv{} {}", ty, operand(op, target))?,
+        Instruction::Cdq(
+            AsmType::Byte
+            | AsmType::Word
+            | AsmType::Float
+            | AsmType::Double
+            | AsmType::ByteArray { .. },
+        ) => {
+            unreachable!()
+        }
         Instruction::Cdq(AsmType::Longword) => write!(w, "cdq")?,
         Instruction::Cdq(AsmType::Quadword) => write!(w, "cqo")?,
-        Instruction::Cmp(AsmType::Double, lhs, rhs) => {
-            write!(w, "comisd {}, {}", operand(lhs), operand(rhs))?
-        }
-        Instruction::Cmp(ty, lhs, rhs) => {
-            write!(w, "cmp{} {}, {}", ty, operand(lhs), operand(rhs))?
-        }
-        Instruction::Jmp(label) => write!(w, "jmp L{label}")?,
-        Instruction::JmpCC(cond_code, label) => write!(w, "j{cond_code:?} L{label}")?,
-        Instruction::SetCC(cond_code, op) => write!(w, "set{cond_code:?} {}", operand(op))?,
-        Instruction::Label(label) => write!(w, "L{label}:")?,
+        Instruction::Cmp(ty @ (AsmType::Float | AsmType::Double), lhs, rhs) => write!(
+            w,
+            "comi{} {}, {}",
+            ty,
+            operand(lhs, target),
+            operand(rhs, target)
+        )?,
+        Instruction::Cmp(ty, lhs, rhs) => write!(
+            w,
+            "cmp{} {}, {}",
+            ty,
+            operand(lhs, target),
+            operand(rhs, target)
+        )?,
+        Instruction::Jmp(label) => write!(w, "jmp {local}{label}")?,
+        Instruction::JmpCC(cond_code, label) => write!(w, "j{cond_code:?} {local}{label}")?,
+        Instruction::SetCC(cond_code, op) => write!(w, "set{cond_code:?} {}", operand(op, target))?,
+        Instruction::Label(label) => write!(w, "{local}{label}:")?,
         Instruction::DeallocateStack(offset) => write!(w, "addq ${offset}, %rsp")?,
-        Instruction::Push(op) => write!(w, "pushq {}", operand(op))?,
-        Instruction::Call(func) => write!(w, "call _{func}")?,
+        Instruction::Push(op) => write!(w, "pushq {}", operand(op, target))?,
+        Instruction::Call(func) => {
+            write!(w, "call {}{func}", target.symbol_prefix())?;
+            if target == Target::Linux && !defined.contains(func.as_str()) {
+                write!(w, "@PLT")?;
+            }
+        }
+        Instruction::IndirectCall(callee) => write!(w, "call *{}", operand(callee, target))?,
         Instruction::Comment(comment) => write!(w, "# {comment}")?,
-        Instruction::Cvtsi2sd { src_type, src, dst } => {
-            write!(w, "cvtsi2sd{} {}, {}", src_type, operand(src), operand(dst))?
+        Instruction::Cvtsi2sd {
+            src_type,
+            dst_type,
+            src,
+            dst,
+        } => write!(
+            w,
+            "cvtsi2{}{} {}, {}",
+            dst_type,
+            src_type,
+            operand(src, target),
+            operand(dst, target)
+        )?,
+        Instruction::Lea {
+            src: Operand::Data(name, offset, _),
+            dst,
+        } if target == Target::Linux && !defined.contains(name.as_str()) => {
+            write!(w, "movq {name}@GOTPCREL(%rip), {}", operand(dst, target))?;
+            if *offset != 0 {
+                write!(w, "\n\taddq ${offset}, {}", operand(dst, target))?;
+            }
         }
-        Instruction::Cvttsd2si { dst_type, src, dst } => write!(
+        Instruction::Lea { src, dst } => {
+            write!(w, "leaq {}, {}", operand(src, target), operand(dst, target))?
+        }
+        Instruction::Cvttsd2si {
+            src_type,
+            dst_type,
+            src,
+            dst,
+        } => write!(
             w,
-            "cvttsd2si{} {}, {}",
+            "cvtt{}2si{} {}, {}",
+            src_type,
             dst_type,
-            operand(src),
-            operand(dst)
+            operand(src, target),
+            operand(dst, target)
+        )?,
+        Instruction::Cvtss2sd { src, dst } => write!(
+            w,
+            "cvtss2sd {}, {}",
+            operand(src, target),
+            operand(dst, target)
+        )?,
+        Instruction::Cvtsd2ss { src, dst } => write!(
+            w,
+            "cvtsd2ss {}, {}",
+            operand(src, target),
+            operand(dst, target)
         )?,
     }
     writeln!(w)?;
     Ok(())
 }
 
-fn operand(operand: &Operand) -> String {
+fn is_xmm(operand: &Operand) -> bool {
+    matches!(operand, Operand::Register(reg, _) if reg.is_xmm())
+}
+
+fn operand(operand: &Operand, target: Target) -> String {
     match operand {
         Operand::Immediate(val, _) => format!("${val}"),
         Operand::Register(name, width) => match (name, width) {
             (Reg::AX, Width::One) => "%al",
+            (Reg::AX, Width::Two) => "%ax",
             (Reg::AX, Width::Four) => "%eax",
             (Reg::AX, Width::Eight) => "%rax",
             (Reg::CX, Width::One) => "%cl",
+            (Reg::CX, Width::Two) => "%cx",
             (Reg::CX, Width::Four) => "%ecx",
             (Reg::CX, Width::Eight) => "%rcx",
             (Reg::DI, Width::One) => "%dil",
+            (Reg::DI, Width::Two) => "%di",
             (Reg::DI, Width::Four) => "%edi",
             (Reg::DI, Width::Eight) => "%rdi",
             (Reg::DX, Width::One) => "%dl",
+            (Reg::DX, Width::Two) => "%dx",
             (Reg::DX, Width::Four) => "%edx",
             (Reg::DX, Width::Eight) => "%rdx",
             (Reg::R10, Width::One) => "%r10b",
+            (Reg::R10, Width::Two) => "%r10w",
             (Reg::R10, Width::Four) => "%r10d",
             (Reg::R10, Width::Eight) => "%r10",
             (Reg::R11, Width::One) => "%r11b",
+            (Reg::R11, Width::Two) => "%r11w",
             (Reg::R11, Width::Four) => "%r11d",
             (Reg::R11, Width::Eight) => "%r11",
             (Reg::R8, Width::One) => "%r8b",
+            (Reg::R8, Width::Two) => "%r8w",
             (Reg::R8, Width::Four) => "%r8d",
             (Reg::R8, Width::Eight) => "%r8",
             (Reg::R9, Width::One) => "%r9b",
+            (Reg::R9, Width::Two) => "%r9w",
             (Reg::R9, Width::Four) => "%r9d",
             (Reg::R9, Width::Eight) => "%r9",
             (Reg::SI, Width::One) => "%sil",
+            (Reg::SI, Width::Two) => "%si",
             (Reg::SI, Width::Four) => "%esi",
             (Reg::SI, Width::Eight) => "%rsi",
+            (Reg::SP, _) => "%rsp",
             (Reg::XMM0, _) => "%xmm0",
             (Reg::XMM1, _) => "%xmm1",
             (Reg::XMM2, _) => "%xmm2",
+            (Reg::XMM3, _) => "%xmm3",
+            (Reg::XMM4, _) => "%xmm4",
+            (Reg::XMM5, _) => "%xmm5",
+            (Reg::XMM6, _) => "%xmm6",
+            (Reg::XMM7, _) => "%xmm7",
             (Reg::XMM14, _) => "%xmm14",
             (Reg::XMM15, _) => "%xmm15",
-            _ => todo!(),
         }
         .into(),
-        Operand::Psuedo(_, _) => unreachable!(),
+        Operand::Psuedo(_, _) | Operand::PsuedoMem(_, _, _) => unreachable!(),
         Operand::Stack(offset, _) => format!("{}(%rbp)", -offset),
-        Operand::Data(name, _) => format!("{SYMBOL_PREFIX}{name}(%rip)"),
+        Operand::Data(name, 0, _) => format!("{}{name}(%rip)", target.symbol_prefix()),
+        Operand::Data(name, offset, _) => {
+            format!("{}{name}+{offset}(%rip)", target.symbol_prefix())
+        }
+        Operand::Memory(reg, offset, _) => {
+            format!(
+                "{offset}({})",
+                self::operand(&Operand::Register(*reg, Width::Eight), target)
+            )
+        }
+        Operand::Indexed(base, index, scale) => format!(
+            "({}, {}, {scale})",
+            self::operand(&Operand::Register(*base, Width::Eight), target),
+            self::operand(&Operand::Register(*index, Width::Eight), target)
+        ),
     }
 }
@@ -0,0 +1,294 @@
+use std::io;
+
+/// An ELF64 relocatable object file for x86-64.
+#[derive(Debug, Default)]
+pub struct Object {
+    pub sections: Vec<Section>,
+    pub symbols: Vec<Symbol>,
+}
+
+#[derive(Debug)]
+pub struct Section {
+    pub name: &'static str,
+    pub kind: SectionKind,
+    /// The contents of the section, which for `.bss` only give its size.
+    pub data: Vec<u8>,
+    pub alignment: u64,
+    pub relocations: Vec<Relocation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionKind {
+    Text,
+    Data,
+    Bss,
+    ReadOnly,
+    /// A section that is not loaded, like `.note.GNU-stack`.
+    Note,
+}
+
+#[derive(Debug)]
+pub struct Symbol {
+    pub name: String,
+    /// The index of the defining section in [`Object::sections`], or `None` for a symbol
+    /// defined in another object.
+    pub section: Option<usize>,
+    pub value: u64,
+    pub size: u64,
+    pub global: bool,
+    pub kind: SymbolKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    NoType,
+    Object,
+    Function,
+    /// The start of a section, with an empty name.
+    Section,
+}
+
+#[derive(Debug)]
+pub struct Relocation {
+    pub offset: u64,
+    /// The index of the symbol in [`Object::symbols`].
+    pub symbol: usize,
+    pub kind: RelocationKind,
+    pub addend: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocationKind {
+    /// `R_X86_64_64`, the absolute address of the symbol.
+    Abs64,
+    /// `R_X86_64_PC32`, the address of the symbol relative to the relocated field.
+    Pc32,
+    /// `R_X86_64_PLT32`, like `Pc32` but through the procedure linkage table if need be.
+    Plt32,
+    /// `R_X86_64_REX_GOTPCRELX`, the global offset table entry of the symbol relative to the
+    /// relocated field, which is the operand of a `movq` that the linker may turn into a
+    /// `leaq` of the symbol itself.
+    RexGotPcRelX,
+}
+
+impl RelocationKind {
+    const fn number(self) -> u64 {
+        match self {
+            RelocationKind::Abs64 => 1,
+            RelocationKind::Pc32 => 2,
+            RelocationKind::Plt32 => 4,
+            RelocationKind::RexGotPcRelX => 42,
+        }
+    }
+}
+
+const HEADER_SIZE: u64 = 64;
+const SECTION_HEADER_SIZE: u64 = 64;
+const SYMBOL_SIZE: u64 = 24;
+const RELOCATION_SIZE: u64 = 24;
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+const SHT_NOBITS: u32 = 8;
+
+const SHF_WRITE: u64 = 0x1;
+const SHF_ALLOC: u64 = 0x2;
+const SHF_EXECINSTR: u64 = 0x4;
+const SHF_INFO_LINK: u64 = 0x40;
+
+struct SectionHeader {
+    name: u32,
+    kind: u32,
+    flags: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    alignment: u64,
+    entry_size: u64,
+}
+
+/// A string table, which starts with the empty string.
+struct Strings(Vec<u8>);
+
+impl Strings {
+    fn new() -> Self {
+        Strings(vec![0])
+    }
+
+    fn add(&mut self, s: &str) -> u32 {
+        if s.is_empty() {
+            return 0;
+        }
+        let offset = self.0.len() as u32;
+        self.0.extend_from_slice(s.as_bytes());
+        self.0.push(0);
+        offset
+    }
+}
+
+impl Object {
+    pub fn write(&self, mut w: impl io::Write) -> io::Result<()> {
+        // the file is laid out as the header, then the contents of every section, then the
+        // section header table
+        let mut body = vec![];
+        let mut headers = vec![];
+        let mut section_names = Strings::new();
+        let place = |body: &mut Vec<u8>, data: &[u8], alignment: u64| {
+            let offset = (HEADER_SIZE + body.len() as u64).next_multiple_of(alignment.max(1));
+            body.resize((offset - HEADER_SIZE) as usize, 0);
+            body.extend_from_slice(data);
+            offset
+        };
+
+        for section in &self.sections {
+            let (kind, flags) = match section.kind {
+                SectionKind::Text => (SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR),
+                SectionKind::Data => (SHT_PROGBITS, SHF_ALLOC | SHF_WRITE),
+                SectionKind::Bss => (SHT_NOBITS, SHF_ALLOC | SHF_WRITE),
+                SectionKind::ReadOnly => (SHT_PROGBITS, SHF_ALLOC),
+                SectionKind::Note => (SHT_PROGBITS, 0),
+            };
+            let data: &[u8] = match section.kind {
+                SectionKind::Bss => &[],
+                _ => &section.data,
+            };
+            headers.push(SectionHeader {
+                name: section_names.add(section.name),
+                kind,
+                flags,
+                offset: place(&mut body, data, section.alignment),
+                size: section.data.len() as u64,
+                link: 0,
+                info: 0,
+                alignment: section.alignment,
+                entry_size: 0,
+            });
+        }
+
+        // the symbol table lists the local symbols before the global ones
+        let mut order: Vec<usize> = (0..self.symbols.len()).collect();
+        order.sort_by_key(|&i| self.symbols[i].global);
+        let mut index = vec![0; self.symbols.len()];
+        for (position, &i) in order.iter().enumerate() {
+            index[i] = position as u32 + 1;
+        }
+        let symtab = (self.sections.len() + self.relocated().count() + 1) as u32;
+
+        for (i, section) in self.relocated() {
+            let mut data = vec![];
+            for relocation in &section.relocations {
+                let info = ((index[relocation.symbol] as u64) << 32) | relocation.kind.number();
+                data.extend_from_slice(&relocation.offset.to_le_bytes());
+                data.extend_from_slice(&info.to_le_bytes());
+                data.extend_from_slice(&relocation.addend.to_le_bytes());
+            }
+            headers.push(SectionHeader {
+                name: section_names.add(&format!(".rela{}", section.name)),
+                kind: SHT_RELA,
+                flags: SHF_INFO_LINK,
+                offset: place(&mut body, &data, 8),
+                size: data.len() as u64,
+                link: symtab,
+                info: i as u32 + 1,
+                alignment: 8,
+                entry_size: RELOCATION_SIZE,
+            });
+        }
+
+        let mut names = Strings::new();
+        let mut symbols = vec![0; SYMBOL_SIZE as usize];
+        for symbol in order.iter().map(|&i| &self.symbols[i]) {
+            let binding = if symbol.global { 1 } else { 0 };
+            let kind = match symbol.kind {
+                SymbolKind::NoType => 0,
+                SymbolKind::Object => 1,
+                SymbolKind::Function => 2,
+                SymbolKind::Section => 3,
+            };
+            let section = symbol.section.map_or(0, |i| i as u16 + 1);
+            symbols.extend_from_slice(&names.add(&symbol.name).to_le_bytes());
+            symbols.push((binding << 4) | kind);
+            symbols.push(0);
+            symbols.extend_from_slice(&section.to_le_bytes());
+            symbols.extend_from_slice(&symbol.value.to_le_bytes());
+            symbols.extend_from_slice(&symbol.size.to_le_bytes());
+        }
+        let first_global = order.iter().filter(|&&i| !self.symbols[i].global).count() + 1;
+        headers.push(SectionHeader {
+            name: section_names.add(".symtab"),
+            kind: SHT_SYMTAB,
+            flags: 0,
+            offset: place(&mut body, &symbols, 8),
+            size: symbols.len() as u64,
+            link: symtab + 1,
+            info: first_global as u32,
+            alignment: 8,
+            entry_size: SYMBOL_SIZE,
+        });
+        headers.push(SectionHeader {
+            name: section_names.add(".strtab"),
+            kind: SHT_STRTAB,
+            flags: 0,
+            offset: place(&mut body, &names.0, 1),
+            size: names.0.len() as u64,
+            link: 0,
+            info: 0,
+            alignment: 1,
+            entry_size: 0,
+        });
+        let shstrtab_name = section_names.add(".shstrtab");
+        headers.push(SectionHeader {
+            name: shstrtab_name,
+            kind: SHT_STRTAB,
+            flags: 0,
+            offset: place(&mut body, &section_names.0, 1),
+            size: section_names.0.len() as u64,
+            link: 0,
+            info: 0,
+            alignment: 1,
+            entry_size: 0,
+        });
+        let section_headers = place(&mut body, &[], 8);
+
+        w.write_all(b"\x7fELF")?;
+        // 64-bit, little endian, version 1, System V ABI
+        w.write_all(&[2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0])?;
+        w.write_all(&1u16.to_le_bytes())?; // ET_REL
+        w.write_all(&62u16.to_le_bytes())?; // EM_X86_64
+        w.write_all(&1u32.to_le_bytes())?;
+        w.write_all(&0u64.to_le_bytes())?; // entry point
+        w.write_all(&0u64.to_le_bytes())?; // program headers
+        w.write_all(&section_headers.to_le_bytes())?;
+        w.write_all(&0u32.to_le_bytes())?; // flags
+        w.write_all(&(HEADER_SIZE as u16).to_le_bytes())?;
+        w.write_all(&0u16.to_le_bytes())?; // program header size
+        w.write_all(&0u16.to_le_bytes())?; // program header count
+        w.write_all(&(SECTION_HEADER_SIZE as u16).to_le_bytes())?;
+        w.write_all(&(headers.len() as u16 + 1).to_le_bytes())?;
+        w.write_all(&(headers.len() as u16).to_le_bytes())?; // .shstrtab is the last section
+        w.write_all(&body)?;
+
+        w.write_all(&[0; SECTION_HEADER_SIZE as usize])?;
+        for header in headers {
+            w.write_all(&header.name.to_le_bytes())?;
+            w.write_all(&header.kind.to_le_bytes())?;
+            w.write_all(&header.flags.to_le_bytes())?;
+            w.write_all(&0u64.to_le_bytes())?; // address
+            w.write_all(&header.offset.to_le_bytes())?;
+            w.write_all(&header.size.to_le_bytes())?;
+            w.write_all(&header.link.to_le_bytes())?;
+            w.write_all(&header.info.to_le_bytes())?;
+            w.write_all(&header.alignment.to_le_bytes())?;
+            w.write_all(&header.entry_size.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// The sections with relocations, with their indices.
+    fn relocated(&self) -> impl Iterator<Item = (usize, &Section)> {
+        (self.sections.iter().enumerate()).filter(|(_, section)| !section.relocations.is_empty())
+    }
+}
@@ -0,0 +1,1557 @@
+//! Dumps of the intermediate stages of compilation, for `--emit`.
+//!
+//! The JSON form of every stage is one line holding an object with the schema version, the
+//! stage, the input file, and the stage's own fields. Every node is an object whose `kind` says
+//! what it is, and names are in `snake_case`. AST nodes carry a `span`, which gives the offset
+//! and length in the preprocessed translation unit along with the file, line and column that
+//! it comes from. The text form is an indented outline of the same fields, without the
+//! quotes and braces that keep JSON unambiguous. It is meant for people, and may change at
+//! any time.
+
+use std::fmt::{self, Display, Write};
+
+use miette::SourceSpan;
+
+use crate::{
+    assembly_gen::{self, AsmType, Operand},
+    ast::{
+        self, Block, BlockItem, Constant, Declaration, Expression, ForInit, Initializer, Spanned,
+        Statement, VariableDeclaration,
+    },
+    lexer::{SourceMap, Token},
+    sema::{StaticInit, SymbolAttributes, SymbolTable, Type},
+    tacky::{self, Callee, Val},
+};
+
+/// The version of the JSON schema, which changes whenever a field does.
+const SCHEMA: u64 = 1;
+
+/// A stage of compilation, in pipeline order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum Stage {
+    Tokens,
+    Ast,
+    TypedAst,
+    Tacky,
+    AsmIr,
+    Asm,
+}
+
+impl Stage {
+    pub fn name(self) -> &'static str {
+        match self {
+            Stage::Tokens => "tokens",
+            Stage::Ast => "ast",
+            Stage::TypedAst => "typed-ast",
+            Stage::Tacky => "tacky",
+            Stage::AsmIr => "asm-ir",
+            Stage::Asm => "asm",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Json,
+    Text,
+}
+
+/// A JSON value. Object keys are kept in the order they were given.
+pub enum Json {
+    Null,
+    Bool(bool),
+    /// A number, already written out.
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{b}"),
+            Json::Number(n) => f.write_str(n),
+            Json::String(s) => write_string(f, s),
+            Json::Array(values) => {
+                f.write_char('[')?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_char(']')
+            }
+            Json::Object(fields) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// How long the lines of the text form may get before a value is broken over several lines.
+const TEXT_WIDTH: usize = 100;
+
+impl Json {
+    /// Writes the value as an outline: one line per field of an object and per element of an
+    /// array, indented under their parent, unless the whole value fits on the rest of the
+    /// line. A node is written as its kind followed by its other fields, and a span as the
+    /// file, line and column it starts at.
+    fn outline(&self) -> String {
+        let mut out = String::new();
+        match self {
+            Json::Object(fields) => write_fields(fields, &mut out, 0),
+            value => value.write_broken(&mut out, 0),
+        }
+        out.push('\n');
+        out
+    }
+
+    fn write_outline(&self, out: &mut String, indent: usize) {
+        let inline = self.inline();
+        let column = out.len() - out.rfind('\n').map_or(0, |i| i + 1);
+        if column + inline.len() <= TEXT_WIDTH {
+            out.push_str(&inline);
+        } else {
+            self.write_broken(out, indent);
+        }
+    }
+
+    /// Writes the value over several lines, or on one if it cannot be broken up.
+    fn write_broken(&self, out: &mut String, indent: usize) {
+        match self {
+            Json::Array(values) if !values.is_empty() => {
+                for value in values {
+                    newline(out, indent);
+                    out.push_str("- ");
+                    value.write_outline(out, indent + 2);
+                }
+            }
+            Json::Object(fields) if !fields.is_empty() => write_fields(fields, out, indent),
+            value => out.push_str(&value.inline()),
+        }
+    }
+
+    /// The value written on a single line.
+    fn inline(&self) -> String {
+        match self {
+            Json::Null => "null".into(),
+            Json::Bool(b) => b.to_string(),
+            Json::Number(n) => n.clone(),
+            Json::String(s) if is_word(s) => s.clone(),
+            Json::String(_) => self.to_string(),
+            Json::Array(values) => {
+                let values: Vec<_> = values.iter().map(Json::inline).collect();
+                format!("[{}]", values.join(", "))
+            }
+            Json::Object(fields) => {
+                if let [
+                    ("offset", _),
+                    ("length", _),
+                    ("file", file),
+                    ("line", line),
+                    ("column", column),
+                ] = fields.as_slice()
+                {
+                    return format!("{}:{}:{}", file.inline(), line.inline(), column.inline());
+                }
+                let (kind, fields) = split_kind(fields);
+                let fields: Vec<_> = fields
+                    .iter()
+                    .map(|(key, value)| format!("{key}: {}", value.inline()))
+                    .collect();
+                match kind {
+                    Some(kind) if fields.is_empty() => kind.to_string(),
+                    Some(kind) => format!("{kind} {{ {} }}", fields.join(", ")),
+                    None if fields.is_empty() => "{}".into(),
+                    None => format!("{{ {} }}", fields.join(", ")),
+                }
+            }
+        }
+    }
+}
+
+/// Writes the fields of an object that does not fit on one line. The fields of a node go under
+/// its kind, and the first field of any other object goes where the object starts.
+fn write_fields(fields: &[(&'static str, Json)], out: &mut String, indent: usize) {
+    let (kind, fields) = split_kind(fields);
+    if let Some(kind) = kind {
+        out.push_str(kind);
+    }
+    for (i, (key, value)) in fields.iter().enumerate() {
+        if i > 0 || kind.is_some() {
+            newline(out, indent);
+        }
+        out.push_str(key);
+        out.push(':');
+        let inline = value.inline();
+        if indent + key.len() + 2 + inline.len() <= TEXT_WIDTH {
+            out.push(' ');
+            out.push_str(&inline);
+            continue;
+        }
+        // arrays and objects other than nodes start on a line of their own
+        match value {
+            Json::Array(values) if !values.is_empty() => {}
+            Json::Object(fields) if split_kind(fields).0.is_none() => newline(out, indent + 2),
+            _ => out.push(' '),
+        }
+        value.write_broken(out, indent + 2);
+    }
+}
+
+fn newline(out: &mut String, indent: usize) {
+    out.push('\n');
+    out.extend(std::iter::repeat_n(' ', indent));
+}
+
+/// Separates the kind of a node from its other fields.
+fn split_kind<'j>(
+    fields: &'j [(&'static str, Json)],
+) -> (Option<&'j str>, &'j [(&'static str, Json)]) {
+    match fields {
+        [("kind", Json::String(kind)), rest @ ..] => (Some(kind), rest),
+        fields => (None, fields),
+    }
+}
+
+/// Whether a string can go in the text form without quotes: names, kinds and file names can,
+/// while anything with spaces or punctuation that the outline uses itself is quoted.
+fn is_word(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '$' | '<' | '>' | '/' | '-'))
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<&String> for Json {
+    fn from(value: &String) -> Self {
+        Json::String(value.clone())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+macro_rules! json_number {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for Json {
+            fn from(value: $ty) -> Self {
+                Json::Number(value.to_string())
+            }
+        })*
+    };
+}
+
+json_number!(i8, u8, i16, u16, i32, u32, i64, u64, usize);
+
+/// Floating-point numbers that JSON cannot represent are written as the strings `"inf"`,
+/// `"-inf"` and `"nan"`.
+impl From<f64> for Json {
+    fn from(value: f64) -> Self {
+        match value {
+            v if v.is_nan() => "nan".into(),
+            v if v.is_infinite() => Json::from(if v > 0.0 { "inf" } else { "-inf" }),
+            // `Debug` keeps the sign of negative zero and a decimal point on whole numbers
+            v => Json::Number(format!("{v:?}")),
+        }
+    }
+}
+
+impl From<f32> for Json {
+    fn from(value: f32) -> Self {
+        match value {
+            v if v.is_finite() => Json::Number(format!("{v:?}")),
+            v => f64::from(v).into(),
+        }
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl<T: Into<Json>> FromIterator<T> for Json {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Json::Array(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+/// An object whose `kind` is `kind`.
+fn node(kind: &str, fields: impl IntoIterator<Item = (&'static str, Json)>) -> Json {
+    let mut object = vec![("kind", kind.into())];
+    object.extend(fields);
+    Json::Object(object)
+}
+
+// The names that the schema gives the variants of each enum. They are spelled out rather than
+// derived from `Debug`, so that renaming a variant does not change the schema.
+
+fn token_name(token: Token) -> &'static str {
+    use Token::*;
+    match token {
+        Identifier => "identifier",
+        Constant => "constant",
+        FloatingConstant => "floating_constant",
+        CharConstant => "char_constant",
+        StringLiteral => "string_literal",
+        LParen => "lparen",
+        RParen => "rparen",
+        LBrace => "lbrace",
+        RBrace => "rbrace",
+        LBracket => "lbracket",
+        RBracket => "rbracket",
+        Int => "int",
+        Long => "long",
+        Char => "char",
+        Void => "void",
+        Return => "return",
+        Semicolon => "semicolon",
+        Whitespace => "whitespace",
+        LineMarker => "line_marker",
+        Tilde => "tilde",
+        Hypen => "hypen",
+        Plus => "plus",
+        Asterisk => "asterisk",
+        FSlash => "fslash",
+        Percent => "percent",
+        Ampersand => "ampersand",
+        Pipe => "pipe",
+        Caret => "caret",
+        ShLeft => "sh_left",
+        ShRight => "sh_right",
+        Exclamation => "exclamation",
+        DoubleEquals => "double_equals",
+        DoubleAnd => "double_and",
+        DoublePipe => "double_pipe",
+        NotEqual => "not_equal",
+        Less => "less",
+        Greater => "greater",
+        LessEqual => "less_equal",
+        GreaterEqual => "greater_equal",
+        Equals => "equals",
+        PlusPlus => "plus_plus",
+        MinusMinus => "minus_minus",
+        PlusEquals => "plus_equals",
+        MinusEquals => "minus_equals",
+        TimesEquals => "times_equals",
+        DivEquals => "div_equals",
+        ModEquals => "mod_equals",
+        AndEquals => "and_equals",
+        OrEquals => "or_equals",
+        XorEquals => "xor_equals",
+        ShiftRightEquals => "shift_right_equals",
+        ShiftLeftEquals => "shift_left_equals",
+        If => "if",
+        Else => "else",
+        Question => "question",
+        Colon => "colon",
+        Goto => "goto",
+        For => "for",
+        While => "while",
+        Do => "do",
+        Break => "break",
+        Continue => "continue",
+        Switch => "switch",
+        Case => "case",
+        Default => "default",
+        Comma => "comma",
+        Static => "static",
+        Extern => "extern",
+        Signed => "signed",
+        Unsigned => "unsigned",
+        Short => "short",
+        Bool => "bool",
+        Float => "float",
+        Double => "double",
+        Sizeof => "sizeof",
+        Struct => "struct",
+        Union => "union",
+        Enum => "enum",
+        Typedef => "typedef",
+        Const => "const",
+        Volatile => "volatile",
+        Restrict => "restrict",
+        Dot => "dot",
+        Ellipsis => "ellipsis",
+        Arrow => "arrow",
+        VaStart => "va_start",
+        VaArg => "va_arg",
+        VaCopy => "va_copy",
+        VaEnd => "va_end",
+    }
+}
+
+fn storage_name(storage: ast::StorageClass) -> &'static str {
+    use ast::StorageClass::*;
+    match storage {
+        Static => "static",
+        Extern => "extern",
+    }
+}
+
+fn unary_name(op: &ast::UnaryOperator) -> &'static str {
+    use ast::UnaryOperator::*;
+    match op {
+        Minus => "minus",
+        Complement => "complement",
+        Not => "not",
+        PrefixIncrement => "prefix_increment",
+        PrefixDecrement => "prefix_decrement",
+        PostfixIncrement => "postfix_increment",
+        PostfixDecrement => "postfix_decrement",
+    }
+}
+
+fn binary_name(op: &ast::BinaryOperator) -> &'static str {
+    use ast::BinaryOperator::*;
+    match op {
+        Add => "add",
+        Subtract => "subtract",
+        Multiply => "multiply",
+        Divide => "divide",
+        Remainder => "remainder",
+        LeftShift => "left_shift",
+        RightShift => "right_shift",
+        BitwiseOr => "bitwise_or",
+        BitwiseAnd => "bitwise_and",
+        Xor => "xor",
+        Equals => "equals",
+        LessThan => "less_than",
+        LessThanOrEqual => "less_than_or_equal",
+        GreaterThan => "greater_than",
+        GreaterThanOrEqual => "greater_than_or_equal",
+        NotEqual => "not_equal",
+        And => "and",
+        Or => "or",
+        Comma => "comma",
+    }
+}
+
+fn tacky_unary_name(op: &tacky::UnaryOperator) -> &'static str {
+    use tacky::UnaryOperator::*;
+    match op {
+        Complement => "complement",
+        Negate => "negate",
+        Not => "not",
+    }
+}
+
+fn asm_unary_name(op: &assembly_gen::UnaryOperator) -> &'static str {
+    use assembly_gen::UnaryOperator::*;
+    match op {
+        Neg => "neg",
+        Not => "not",
+        Shr => "shr",
+    }
+}
+
+fn asm_binary_name(op: &assembly_gen::BinaryOperator) -> &'static str {
+    use assembly_gen::BinaryOperator::*;
+    match op {
+        Add => "add",
+        Sub => "sub",
+        Mult => "mult",
+        And => "and",
+        Or => "or",
+        Xor => "xor",
+        SignedLeftShift => "signed_left_shift",
+        SignedRightShift => "signed_right_shift",
+        LeftShift => "left_shift",
+        RightShift => "right_shift",
+        Equals => "equals",
+        NotEquals => "not_equals",
+        LessThan => "less_than",
+        LessThanOrEqual => "less_than_or_equal",
+        GreaterThan => "greater_than",
+        GreaterThanOrEqual => "greater_than_or_equal",
+        DivDouble => "div_double",
+    }
+}
+
+fn asm_type_name(ty: &AsmType) -> &'static str {
+    match ty {
+        AsmType::Byte => "byte",
+        AsmType::Word => "word",
+        AsmType::Longword => "longword",
+        AsmType::Quadword => "quadword",
+        AsmType::Float => "float",
+        AsmType::Double => "double",
+        AsmType::ByteArray { .. } => "byte_array",
+    }
+}
+
+fn reg_name(reg: &assembly_gen::Reg) -> &'static str {
+    use assembly_gen::Reg::*;
+    match reg {
+        AX => "ax",
+        DX => "dx",
+        CX => "cx",
+        DI => "di",
+        SI => "si",
+        R8 => "r8",
+        R9 => "r9",
+        R10 => "r10",
+        R11 => "r11",
+        SP => "sp",
+        XMM0 => "xmm0",
+        XMM1 => "xmm1",
+        XMM2 => "xmm2",
+        XMM3 => "xmm3",
+        XMM4 => "xmm4",
+        XMM5 => "xmm5",
+        XMM6 => "xmm6",
+        XMM7 => "xmm7",
+        XMM14 => "xmm14",
+        XMM15 => "xmm15",
+    }
+}
+
+fn cond_name(cc: &assembly_gen::CondCode) -> &'static str {
+    use assembly_gen::CondCode::*;
+    match cc {
+        E => "e",
+        NE => "ne",
+        G => "g",
+        GE => "ge",
+        L => "l",
+        LE => "le",
+        A => "a",
+        AE => "ae",
+        B => "b",
+        BE => "be",
+    }
+}
+
+/// Wraps the fields of a stage with the header every stage has, and writes it out in `format`.
+fn document(
+    stage: Stage,
+    file: &str,
+    fields: impl IntoIterator<Item = (&'static str, Json)>,
+    format: Format,
+) -> String {
+    let mut object = vec![
+        ("schema", SCHEMA.into()),
+        ("stage", stage.name().into()),
+        ("file", file.into()),
+    ];
+    object.extend(fields);
+    let document = Json::Object(object);
+    match format {
+        Format::Json => format!("{document}\n"),
+        Format::Text => document.outline(),
+    }
+}
+
+pub fn tokens(
+    tokens: &[(Token, SourceSpan)],
+    map: &SourceMap,
+    file: &str,
+    format: Format,
+) -> String {
+    let tokens = tokens.iter().map(|(token, span)| {
+        node(
+            token_name(*token),
+            [
+                ("text", map.text(*span).into()),
+                ("span", span_json(map, *span)),
+            ],
+        )
+    });
+    document(Stage::Tokens, file, [("tokens", tokens.collect())], format)
+}
+
+pub fn ast(program: &ast::Program, map: &SourceMap, file: &str, format: Format) -> String {
+    document(
+        Stage::Ast,
+        file,
+        [("program", Ast(map).program(program))],
+        format,
+    )
+}
+
+/// The AST after semantic analysis, with unique names, labels and implicit conversions, along
+/// with the type of every name.
+pub fn typed_ast(
+    program: &ast::Program,
+    symbols: &SymbolTable,
+    map: &SourceMap,
+    file: &str,
+    format: Format,
+) -> String {
+    let mut names: Vec<_> = symbols.keys().collect();
+    names.sort();
+    let symbols = names.into_iter().map(|name| {
+        let symbol = &symbols[name];
+        let (storage, global) = match &symbol.attributes {
+            SymbolAttributes::Function { global, .. } => ("function", *global),
+            SymbolAttributes::Static { global, .. } => ("static", *global),
+            SymbolAttributes::Constant(_) => ("constant", false),
+            SymbolAttributes::Local => ("local", false),
+        };
+        Json::Object(vec![
+            ("name", name.into()),
+            ("type", type_json(&symbol.ty)),
+            ("storage", storage.into()),
+            ("global", global.into()),
+        ])
+    });
+    document(
+        Stage::TypedAst,
+        file,
+        [
+            ("program", Ast(map).program(program)),
+            ("symbols", symbols.collect()),
+        ],
+        format,
+    )
+}
+
+pub fn tacky(program: &tacky::Program, file: &str, format: Format) -> String {
+    let static_variables = program.static_variables.iter().map(|sv| {
+        Json::Object(vec![
+            ("name", (&sv.identifier).into()),
+            ("global", sv.global.into()),
+            ("alignment", sv.alignment.into()),
+            ("read_only", sv.read_only.into()),
+            ("init", sv.init.iter().map(static_init).collect()),
+        ])
+    });
+    let functions = program.functions.iter().map(|function| {
+        Json::Object(vec![
+            ("name", (&function.identifier).into()),
+            ("global", function.global.into()),
+            ("params", function.params.iter().map(val).collect()),
+            (
+                "instructions",
+                function
+                    .instructions
+                    .iter()
+                    .map(tacky_instruction)
+                    .collect(),
+            ),
+        ])
+    });
+    document(
+        Stage::Tacky,
+        file,
+        [
+            ("static_variables", static_variables.collect()),
+            ("functions", functions.collect()),
+        ],
+        format,
+    )
+}
+
+pub fn asm_ir(program: &assembly_gen::Program, file: &str, format: Format) -> String {
+    let static_variables = program.static_variables.iter().map(|sv| {
+        Json::Object(vec![
+            ("name", (&sv.name).into()),
+            ("global", sv.global.into()),
+            ("alignment", sv.alignment.into()),
+            ("read_only", sv.read_only.into()),
+            ("init", sv.init.iter().map(static_init).collect()),
+        ])
+    });
+    let static_constants = program.static_constants.iter().map(|sc| {
+        Json::Object(vec![
+            ("name", (&sc.name).into()),
+            ("alignment", sc.alignment.into()),
+            ("init", static_init(&sc.init)),
+        ])
+    });
+    let functions = program.definitions.iter().map(|function| {
+        Json::Object(vec![
+            ("name", (&function.name).into()),
+            ("global", function.global.into()),
+            ("stack_size", function.stack_size.into()),
+            ("variadic", function.variadic.into()),
+            (
+                "instructions",
+                function.instructions.iter().map(asm_instruction).collect(),
+            ),
+        ])
+    });
+    document(
+        Stage::AsmIr,
+        file,
+        [
+            ("static_variables", static_variables.collect()),
+            ("static_constants", static_constants.collect()),
+            ("functions", functions.collect()),
+        ],
+        format,
+    )
+}
+
+/// The assembly, whose text form is the assembly itself.
+pub fn asm(assembly: &str, file: &str, format: Format) -> String {
+    match format {
+        Format::Json => document(Stage::Asm, file, [("text", assembly.into())], format),
+        Format::Text => assembly.to_string(),
+    }
+}
+
+fn span_json(map: &SourceMap, span: SourceSpan) -> Json {
+    let (file, line, column) = map.locate(span.offset());
+    Json::Object(vec![
+        ("offset", span.offset().into()),
+        ("length", span.len().into()),
+        ("file", file.into()),
+        ("line", line.into()),
+        ("column", column.into()),
+    ])
+}
+
+fn type_json(ty: &Type) -> Json {
+    let scalar = |kind| node(kind, []);
+    match ty {
+        Type::Function {
+            params,
+            ret,
+            variadic,
+        } => node(
+            "function",
+            [
+                ("params", params.iter().map(type_json).collect()),
+                ("ret", type_json(ret)),
+                ("variadic", (*variadic).into()),
+            ],
+        ),
+        Type::Int => scalar("int"),
+        Type::Long => scalar("long"),
+        Type::UInt => scalar("unsigned_int"),
+        Type::ULong => scalar("unsigned_long"),
+        Type::Short => scalar("short"),
+        Type::UShort => scalar("unsigned_short"),
+        Type::Char => scalar("char"),
+        Type::SChar => scalar("signed_char"),
+        Type::UChar => scalar("unsigned_char"),
+        Type::Bool => scalar("bool"),
+        Type::Float => scalar("float"),
+        Type::Double => scalar("double"),
+        Type::Void => scalar("void"),
+        Type::Pointer(referenced) => node("pointer", [("to", type_json(referenced))]),
+        Type::Array { element, size } => node(
+            "array",
+            [("element", type_json(element)), ("size", (*size).into())],
+        ),
+        Type::Struct(ty) => node(
+            if ty.is_union() { "union" } else { "struct" },
+            [("tag", ty.tag().into())],
+        ),
+        Type::Qualified(ty, qualifiers) => {
+            let names = [
+                (qualifiers.constant, "const"),
+                (qualifiers.volatile, "volatile"),
+                (qualifiers.restrict, "restrict"),
+            ];
+            let names = names.into_iter().filter(|(set, _)| *set).map(|(_, n)| n);
+            node(
+                "qualified",
+                [("qualifiers", names.collect()), ("type", type_json(ty))],
+            )
+        }
+    }
+}
+
+fn constant(constant: &Constant) -> Json {
+    let (ty, value): (_, Json) = match *constant {
+        Constant::Int(v) => ("int", v.into()),
+        Constant::Long(v) => ("long", v.into()),
+        Constant::UInt(v) => ("unsigned_int", v.into()),
+        Constant::ULong(v) => ("unsigned_long", v.into()),
+        Constant::Short(v) => ("short", v.into()),
+        Constant::UShort(v) => ("unsigned_short", v.into()),
+        Constant::Float(v) => ("float", v.into()),
+        Constant::Double(v) => ("double", v.into()),
+        Constant::Char(v) => ("char", v.into()),
+        Constant::UChar(v) => ("unsigned_char", v.into()),
+    };
+    node("constant", [("type", ty.into()), ("value", value)])
+}
+
+fn static_init(init: &StaticInit) -> Json {
+    match init {
+        StaticInit::Scalar(c) => constant(c),
+        StaticInit::Zero(bytes) => node("zero", [("bytes", (*bytes).into())]),
+        StaticInit::String(bytes, null_terminated) => node(
+            "string",
+            [
+                ("bytes", bytes.iter().copied().collect()),
+                ("null_terminated", (*null_terminated).into()),
+            ],
+        ),
+        StaticInit::Pointer(name, offset) => node(
+            "pointer",
+            [("name", name.into()), ("offset", (*offset).into())],
+        ),
+    }
+}
+
+/// Converts AST nodes, whose spans need the source map.
+struct Ast<'a>(&'a SourceMap);
+
+impl Ast<'_> {
+    fn span(&self, span: SourceSpan) -> (&'static str, Json) {
+        ("span", span_json(self.0, span))
+    }
+
+    fn program(&self, program: &ast::Program) -> Json {
+        program
+            .declarations
+            .iter()
+            .map(|d| self.declaration(d))
+            .collect()
+    }
+
+    fn members(&self, members: &[(Type, String, SourceSpan)]) -> Json {
+        members
+            .iter()
+            .map(|(ty, name, span)| {
+                Json::Object(vec![
+                    ("name", name.into()),
+                    ("type", type_json(ty)),
+                    self.span(*span),
+                ])
+            })
+            .collect()
+    }
+
+    fn declaration(&self, declaration: &Declaration) -> Json {
+        match declaration {
+            Declaration::Variable(decl) => self.variable(decl),
+            Declaration::Function(decl) => node(
+                "function_declaration",
+                [
+                    ("name", (&decl.identifier).into()),
+                    ("params", self.members(&decl.params)),
+                    ("variadic", decl.variadic.into()),
+                    ("ret", type_json(&decl.ret)),
+                    ("body", decl.body.as_ref().map(|b| self.block(b)).into()),
+                    ("storage", decl.storage.map(storage_name).into()),
+                    self.span(decl.span),
+                ],
+            ),
+            Declaration::Struct(decl) => node(
+                if decl.ty.is_union() {
+                    "union_declaration"
+                } else {
+                    "struct_declaration"
+                },
+                [
+                    ("tag", decl.ty.tag().into()),
+                    (
+                        "members",
+                        decl.members.as_ref().map(|m| self.members(m)).into(),
+                    ),
+                    self.span(decl.span),
+                ],
+            ),
+            Declaration::Enum(decl) => {
+                let enumerators = decl.enumerators.iter().map(|(name, value, span)| {
+                    Json::Object(vec![
+                        ("name", name.into()),
+                        ("value", value.as_ref().map(|v| self.expression(v)).into()),
+                        self.span(*span),
+                    ])
+                });
+                node(
+                    "enum_declaration",
+                    [
+                        ("tag", decl.tag.as_ref().into()),
+                        ("enumerators", enumerators.collect()),
+                        self.span(decl.span),
+                    ],
+                )
+            }
+            Declaration::Typedef(decl) => node(
+                "typedef_declaration",
+                [
+                    ("name", (&decl.name).into()),
+                    ("type", type_json(&decl.ty)),
+                    self.span(decl.span),
+                ],
+            ),
+        }
+    }
+
+    fn variable(&self, decl: &VariableDeclaration) -> Json {
+        node(
+            "variable_declaration",
+            [
+                ("name", (&decl.name).into()),
+                ("type", type_json(&decl.ty)),
+                (
+                    "init",
+                    decl.init.as_ref().map(|i| self.initializer(i)).into(),
+                ),
+                ("storage", decl.storage.map(storage_name).into()),
+                self.span(decl.span),
+            ],
+        )
+    }
+
+    fn initializer(&self, init: &Initializer) -> Json {
+        match init {
+            Initializer::Single(expr) => node("single", [("expr", self.expression(expr))]),
+            Initializer::Compound(inits, span) => node(
+                "compound",
+                [
+                    ("inits", inits.iter().map(|i| self.initializer(i)).collect()),
+                    self.span(*span),
+                ],
+            ),
+        }
+    }
+
+    fn block(&self, block: &Block) -> Json {
+        let items = block.items.iter().map(|item| match item {
+            BlockItem::Statement(statement) => self.statement(statement),
+            BlockItem::Declaration(declaration) => self.declaration(declaration),
+        });
+        node("block", [("items", items.collect()), self.span(block.span)])
+    }
+
+    fn optional(&self, expr: &Option<Expression>) -> Json {
+        expr.as_ref().map(|e| self.expression(e)).into()
+    }
+
+    fn statement(&self, statement: &Statement) -> Json {
+        let label =
+            |label: &Option<String>| -> (&'static str, Json) { ("label", label.as_ref().into()) };
+        match statement {
+            Statement::Return { expr, span } => {
+                node("return", [("expr", self.optional(expr)), self.span(*span)])
+            }
+            Statement::Expression(expr) => node("expression", [("expr", self.expression(expr))]),
+            Statement::If {
+                cond,
+                if_true,
+                if_false,
+            } => node(
+                "if",
+                [
+                    ("cond", self.expression(cond)),
+                    ("if_true", self.statement(if_true)),
+                    (
+                        "if_false",
+                        if_false.as_ref().map(|s| self.statement(s)).into(),
+                    ),
+                ],
+            ),
+            Statement::Labeled {
+                label,
+                statement,
+                span,
+            } => node(
+                "labeled",
+                [
+                    ("label", label.into()),
+                    ("statement", self.statement(statement)),
+                    self.span(*span),
+                ],
+            ),
+            Statement::Goto { label, span } => {
+                node("goto", [("label", label.into()), self.span(*span)])
+            }
+            Statement::Compound(block) => self.block(block),
+            Statement::Break { label: l, span } => node("break", [label(l), self.span(*span)]),
+            Statement::Continue { label: l, span } => {
+                node("continue", [label(l), self.span(*span)])
+            }
+            Statement::While {
+                expression,
+                statement,
+                label: l,
+            } => node(
+                "while",
+                [
+                    ("cond", self.expression(expression)),
+                    ("body", self.statement(statement)),
+                    label(l),
+                ],
+            ),
+            Statement::DoWhile {
+                statement,
+                expression,
+                label: l,
+            } => node(
+                "do_while",
+                [
+                    ("body", self.statement(statement)),
+                    ("cond", self.expression(expression)),
+                    label(l),
+                ],
+            ),
+            Statement::For {
+                init,
+                condition,
+                post,
+                body,
+                label: l,
+            } => {
+                let init = match init.as_ref() {
+                    ForInit::Decl(decl) => self.variable(decl),
+                    ForInit::Expr(expr) => self.optional(expr),
+                };
+                node(
+                    "for",
+                    [
+                        ("init", init),
+                        ("cond", self.optional(condition)),
+                        ("post", self.optional(post)),
+                        ("body", self.statement(body)),
+                        label(l),
+                    ],
+                )
+            }
+            Statement::Switch {
+                condition,
+                body,
+                label: l,
+            } => node(
+                "switch",
+                [
+                    ("cond", self.expression(condition)),
+                    ("body", self.statement(body)),
+                    label(l),
+                ],
+            ),
+            Statement::Case {
+                expression,
+                statement,
+                label: l,
+            } => node(
+                "case",
+                [
+                    ("value", self.expression(expression)),
+                    ("statement", self.statement(statement)),
+                    label(l),
+                ],
+            ),
+            Statement::Default {
+                statement,
+                label: l,
+                span,
+            } => node(
+                "default",
+                [
+                    ("statement", self.statement(statement)),
+                    label(l),
+                    self.span(*span),
+                ],
+            ),
+            Statement::Null => node("null", []),
+        }
+    }
+
+    fn expression(&self, expr: &Expression) -> Json {
+        let e = |expr: &Expression| self.expression(expr);
+        let (kind, fields): (_, Vec<(&'static str, Json)>) = match expr {
+            Expression::Constant { constant: c, .. } => ("constant", vec![("value", constant(c))]),
+            Expression::Unary { op, expr, .. } => (
+                "unary",
+                vec![("op", unary_name(op).into()), ("expr", e(expr))],
+            ),
+            Expression::Binary { op, lhs, rhs, .. } => (
+                "binary",
+                vec![
+                    ("op", binary_name(op).into()),
+                    ("lhs", e(lhs)),
+                    ("rhs", e(rhs)),
+                ],
+            ),
+            Expression::Var { name, .. } => ("var", vec![("name", name.into())]),
+            Expression::Assignment { lhs, rhs, .. } => {
+                ("assignment", vec![("lhs", e(lhs)), ("rhs", e(rhs))])
+            }
+            Expression::CompoundAssignment { op, lhs, rhs, .. } => (
+                "compound_assignment",
+                vec![
+                    ("op", binary_name(op).into()),
+                    ("lhs", e(lhs)),
+                    ("rhs", e(rhs)),
+                ],
+            ),
+            Expression::Ternary {
+                cond,
+                if_true,
+                if_false,
+                ..
+            } => (
+                "ternary",
+                vec![
+                    ("cond", e(cond)),
+                    ("if_true", e(if_true)),
+                    ("if_false", e(if_false)),
+                ],
+            ),
+            Expression::FunctionCall {
+                function, params, ..
+            } => (
+                "function_call",
+                vec![
+                    ("function", e(function)),
+                    ("args", params.iter().map(e).collect()),
+                ],
+            ),
+            Expression::Cast { to, expr, .. } => {
+                ("cast", vec![("to", type_json(to)), ("expr", e(expr))])
+            }
+            Expression::AddrOf { expr, .. } => ("addr_of", vec![("expr", e(expr))]),
+            Expression::Dereference { expr, .. } => ("dereference", vec![("expr", e(expr))]),
+            Expression::Subscript { array, index, .. } => {
+                ("subscript", vec![("array", e(array)), ("index", e(index))])
+            }
+            Expression::String { value, .. } => {
+                ("string", vec![("bytes", value.iter().copied().collect())])
+            }
+            Expression::SizeOf { expr, .. } => ("size_of", vec![("expr", e(expr))]),
+            Expression::SizeOfType { ty, .. } => ("size_of_type", vec![("type", type_json(ty))]),
+            Expression::Dot { expr, member, .. } => {
+                ("dot", vec![("expr", e(expr)), ("member", member.into())])
+            }
+            Expression::Arrow { expr, member, .. } => {
+                ("arrow", vec![("expr", e(expr)), ("member", member.into())])
+            }
+            Expression::VaStart { ap, .. } => ("va_start", vec![("ap", e(ap))]),
+            Expression::VaArg { ap, ty, .. } => {
+                ("va_arg", vec![("ap", e(ap)), ("type", type_json(ty))])
+            }
+            Expression::VaCopy { dst, src, .. } => {
+                ("va_copy", vec![("dst", e(dst)), ("src", e(src))])
+            }
+            Expression::VaEnd { ap, .. } => ("va_end", vec![("ap", e(ap))]),
+        };
+        node(kind, fields.into_iter().chain([self.span(expr.span())]))
+    }
+}
+
+fn val(val: &Val) -> Json {
+    match val {
+        Val::Constant(c) => constant(c),
+        Val::Var(name, ty) => node("var", [("name", name.into()), ("type", type_json(ty))]),
+    }
+}
+
+fn tacky_instruction(instruction: &tacky::Instruction) -> Json {
+    use tacky::Instruction::*;
+    let src_dst =
+        |kind: &str, src: &Val, dst: &Val| node(kind, [("src", val(src)), ("dst", val(dst))]);
+    match instruction {
+        Return(value) => node("return", [("value", value.as_ref().map(val).into())]),
+        Unary { op, src, dst } => node(
+            "unary",
+            [
+                ("op", tacky_unary_name(op).into()),
+                ("src", val(src)),
+                ("dst", val(dst)),
+            ],
+        ),
+        Binary { op, lhs, rhs, dst } => node(
+            "binary",
+            [
+                ("op", binary_name(op).into()),
+                ("lhs", val(lhs)),
+                ("rhs", val(rhs)),
+                ("dst", val(dst)),
+            ],
+        ),
+        Copy { src, dst } => src_dst("copy", src, dst),
+        Jump(target) => node("jump", [("target", target.into())]),
+        JumpIfZero(cond, target) => node(
+            "jump_if_zero",
+            [("cond", val(cond)), ("target", target.into())],
+        ),
+        JumpIfNotZero(cond, target) => node(
+            "jump_if_not_zero",
+            [("cond", val(cond)), ("target", target.into())],
+        ),
+        Label(name) => node("label", [("name", name.into())]),
+        Call(callee, args, dst) => {
+            let callee = match callee {
+                Callee::Function(name, ty) => {
+                    node("function", [("name", name.into()), ("type", type_json(ty))])
+                }
+                Callee::Pointer(ptr) => node("pointer", [("ptr", val(ptr))]),
+            };
+            node(
+                "call",
+                [
+                    ("callee", callee),
+                    ("args", args.iter().map(val).collect()),
+                    ("dst", dst.as_ref().map(val).into()),
+                ],
+            )
+        }
+        SignExtend { src, dst } => src_dst("sign_extend", src, dst),
+        Truncate { src, dst } => src_dst("truncate", src, dst),
+        ZeroExtend { src, dst } => src_dst("zero_extend", src, dst),
+        DoubleToInt { src, dst } => src_dst("double_to_int", src, dst),
+        DoubleToUInt { src, dst } => src_dst("double_to_uint", src, dst),
+        IntToDouble { src, dst } => src_dst("int_to_double", src, dst),
+        UIntToDouble { src, dst } => src_dst("uint_to_double", src, dst),
+        FloatToDouble { src, dst } => src_dst("float_to_double", src, dst),
+        DoubleToFloat { src, dst } => src_dst("double_to_float", src, dst),
+        GetAddress { src, dst } => src_dst("get_address", src, dst),
+        Load {
+            src_ptr,
+            dst,
+            volatile,
+        } => node(
+            "load",
+            [
+                ("src_ptr", val(src_ptr)),
+                ("dst", val(dst)),
+                ("volatile", (*volatile).into()),
+            ],
+        ),
+        Store {
+            src,
+            dst_ptr,
+            volatile,
+        } => node(
+            "store",
+            [
+                ("src", val(src)),
+                ("dst_ptr", val(dst_ptr)),
+                ("volatile", (*volatile).into()),
+            ],
+        ),
+        AddPtr {
+            ptr,
+            index,
+            scale,
+            dst,
+        } => node(
+            "add_ptr",
+            [
+                ("ptr", val(ptr)),
+                ("index", val(index)),
+                ("scale", (*scale).into()),
+                ("dst", val(dst)),
+            ],
+        ),
+        CopyToOffset {
+            src,
+            dst,
+            offset,
+            volatile,
+        } => node(
+            "copy_to_offset",
+            [
+                ("src", val(src)),
+                ("dst", dst.into()),
+                ("offset", (*offset).into()),
+                ("volatile", (*volatile).into()),
+            ],
+        ),
+        CopyFromOffset {
+            src,
+            offset,
+            dst,
+            volatile,
+        } => node(
+            "copy_from_offset",
+            [
+                ("src", src.into()),
+                ("offset", (*offset).into()),
+                ("dst", val(dst)),
+                ("volatile", (*volatile).into()),
+            ],
+        ),
+        VaStart(ap) => node("va_start", [("ap", val(ap))]),
+        VaArg { ap, dst } => node("va_arg", [("ap", val(ap)), ("dst", val(dst))]),
+    }
+}
+
+fn asm_type(ty: &AsmType) -> Json {
+    match ty {
+        AsmType::ByteArray { size, alignment } => node(
+            "byte_array",
+            [("size", (*size).into()), ("alignment", (*alignment).into())],
+        ),
+        ty => node(asm_type_name(ty), []),
+    }
+}
+
+fn operand(operand: &Operand) -> Json {
+    let width =
+        |width: &assembly_gen::Width| -> (&'static str, Json) { ("width", width.bytes().into()) };
+    match operand {
+        Operand::Immediate(value, w) => node("immediate", [("value", (*value).into()), width(w)]),
+        Operand::Register(reg, w) => node("register", [("reg", reg_name(reg).into()), width(w)]),
+        Operand::Psuedo(name, w) => node("pseudo", [("name", name.into()), width(w)]),
+        Operand::Stack(offset, w) => node("stack", [("offset", (*offset).into()), width(w)]),
+        Operand::Data(name, offset, w) => node(
+            "data",
+            [
+                ("name", name.into()),
+                ("offset", (*offset).into()),
+                width(w),
+            ],
+        ),
+        Operand::Memory(reg, offset, w) => node(
+            "memory",
+            [
+                ("base", reg_name(reg).into()),
+                ("offset", (*offset).into()),
+                width(w),
+            ],
+        ),
+        Operand::PsuedoMem(name, offset, w) => node(
+            "pseudo_mem",
+            [
+                ("name", name.into()),
+                ("offset", (*offset).into()),
+                width(w),
+            ],
+        ),
+        Operand::Indexed(base, index, scale) => node(
+            "indexed",
+            [
+                ("base", reg_name(base).into()),
+                ("index", reg_name(index).into()),
+                ("scale", (*scale).into()),
+            ],
+        ),
+    }
+}
+
+fn asm_instruction(instruction: &assembly_gen::Instruction) -> Json {
+    use assembly_gen::Instruction::*;
+    let src_dst = |kind: &str, src: &Operand, dst: &Operand| {
+        node(kind, [("src", operand(src)), ("dst", operand(dst))])
+    };
+    let typed = |kind: &str, ty: &AsmType, operands: &[&Operand]| {
+        node(
+            kind,
+            [
+                ("type", asm_type(ty)),
+                ("operands", operands.iter().map(|o| operand(o)).collect()),
+            ],
+        )
+    };
+    let convert = |kind: &str, src_type, dst_type, src, dst| {
+        node(
+            kind,
+            [
+                ("src_type", asm_type(src_type)),
+                ("dst_type", asm_type(dst_type)),
+                ("src", operand(src)),
+                ("dst", operand(dst)),
+            ],
+        )
+    };
+    match instruction {
+        Move {
+            asm_type: ty,
+            source,
+            destination,
+        } => node(
+            "mov",
+            [
+                ("type", asm_type(ty)),
+                ("src", operand(source)),
+                ("dst", operand(destination)),
+            ],
+        ),
+        Movesx {
+            source,
+            destination,
+        } => src_dst("movsx", source, destination),
+        MovZeroExtend {
+            source,
+            destination,
+        } => src_dst("mov_zero_extend", source, destination),
+        Unary(ty, op, dst) => node(
+            "unary",
+            [
+                ("op", asm_unary_name(op).into()),
+                ("type", asm_type(ty)),
+                ("dst", operand(dst)),
+            ],
+        ),
+        Binary(ty, op, src, dst) => node(
+            "binary",
+            [
+                ("op", asm_binary_name(op).into()),
+                ("type", asm_type(ty)),
+                ("src", operand(src)),
+                ("dst", operand(dst)),
+            ],
+        ),
+        Cmp(ty, lhs, rhs) => typed("cmp", ty, &[lhs, rhs]),
+        Idiv(ty, divisor) => typed("idiv", ty, &[divisor]),
+        Div(ty, divisor) => typed("div", ty, &[divisor]),
+        Cdq(ty) => typed("cdq", ty, &[]),
+        Jmp(target) => node("jmp", [("target", target.into())]),
+        JmpCC(cc, target) => node(
+            "jmp_cc",
+            [("cond", cond_name(cc).into()), ("target", target.into())],
+        ),
+        SetCC(cc, dst) => node(
+            "set_cc",
+            [("cond", cond_name(cc).into()), ("dst", operand(dst))],
+        ),
+        Label(name) => node("label", [("name", name.into())]),
+        AllocateStack(bytes) => node("allocate_stack", [("bytes", (*bytes).into())]),
+        DeallocateStack(bytes) => node("deallocate_stack", [("bytes", (*bytes).into())]),
+        Push(src) => node("push", [("src", operand(src))]),
+        Call(name) => node("call", [("name", name.into())]),
+        IndirectCall(target) => node("indirect_call", [("target", operand(target))]),
+        Comment(text) => node("comment", [("text", text.into())]),
+        Cvtsi2sd {
+            src_type,
+            dst_type,
+            src,
+            dst,
+        } => convert("cvtsi2sd", src_type, dst_type, src, dst),
+        Cvttsd2si {
+            src_type,
+            dst_type,
+            src,
+            dst,
+        } => convert("cvttsd2si", src_type, dst_type, src, dst),
+        Cvtss2sd { src, dst } => src_dst("cvtss2sd", src, dst),
+        Cvtsd2ss { src, dst } => src_dst("cvtsd2ss", src, dst),
+        Lea { src, dst } => src_dst("lea", src, dst),
+        Ret => node("ret", []),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Json, asm_binary_name, asm_type_name, asm_unary_name, binary_name, cond_name, node,
+        reg_name, storage_name, tacky_unary_name, token_name, unary_name,
+    };
+    use crate::{
+        assembly_gen::{self, AsmType, CondCode, Reg},
+        ast::{BinaryOperator, StorageClass, UnaryOperator},
+        lexer::Token,
+        tacky,
+    };
+
+    #[test]
+    fn test_json() {
+        let value = Json::Object(vec![
+            ("name", "a \"b\"\n".into()),
+            ("values", [1i32, -2].into_iter().collect()),
+            ("zero", (-0.0f64).into()),
+            ("nan", f64::NAN.into()),
+            ("none", None::<bool>.into()),
+        ]);
+        assert_eq!(
+            value.to_string(),
+            r#"{"name":"a \"b\"\n","values":[1,-2],"zero":-0.0,"nan":"nan","none":null}"#
+        );
+    }
+
+    #[test]
+    fn test_outline() {
+        let span = Json::Object(vec![
+            ("offset", 4u64.into()),
+            ("length", 1u64.into()),
+            ("file", "a.c".into()),
+            ("line", 1u64.into()),
+            ("column", 5u64.into()),
+        ]);
+        let long = "x".repeat(90);
+        let value = Json::Object(vec![
+            ("stage", "tokens".into()),
+            (
+                "tokens",
+                Json::Array(vec![
+                    node("plus", [("text", "+".into()), ("span", span)]),
+                    node("identifier", [("text", long.as_str().into())]),
+                ]),
+            ),
+            ("empty", Json::Array(vec![])),
+            ("text", "a b".into()),
+        ]);
+        assert_eq!(
+            value.outline(),
+            format!(
+                concat!(
+                    "stage: tokens\n",
+                    "tokens:\n",
+                    "  - plus {{ text: \"+\", span: a.c:1:5 }}\n",
+                    "  - identifier\n",
+                    "    text: {}\n",
+                    "empty: []\n",
+                    "text: \"a b\"\n",
+                ),
+                long
+            )
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(token_name(Token::LineMarker), "line_marker");
+        assert_eq!(token_name(Token::ShiftLeftEquals), "shift_left_equals");
+        assert_eq!(token_name(Token::VaStart), "va_start");
+        assert_eq!(storage_name(StorageClass::Extern), "extern");
+        assert_eq!(
+            unary_name(&UnaryOperator::PostfixIncrement),
+            "postfix_increment"
+        );
+        assert_eq!(
+            binary_name(&BinaryOperator::LessThanOrEqual),
+            "less_than_or_equal"
+        );
+        assert_eq!(tacky_unary_name(&tacky::UnaryOperator::Negate), "negate");
+        assert_eq!(asm_unary_name(&assembly_gen::UnaryOperator::Shr), "shr");
+        assert_eq!(
+            asm_binary_name(&assembly_gen::BinaryOperator::DivDouble),
+            "div_double"
+        );
+        assert_eq!(asm_type_name(&AsmType::Longword), "longword");
+        assert_eq!(reg_name(&Reg::XMM15), "xmm15");
+        assert_eq!(cond_name(&CondCode::NE), "ne");
+    }
+}
@@ -1,4 +1,6 @@
-use miette::{Diagnostic, NamedSource, Result, SourceSpan};
+use miette::{
+    Diagnostic, MietteError, MietteSpanContents, Result, SourceCode, SourceSpan, SpanContents,
+};
 
 use logos::Logos;
 
@@ -14,6 +16,11 @@ pub enum LexingError {
         #[label]
         span: SourceSpan,
     },
+    #[error("invalid digit in octal constant")]
+    InvalidOctalDigit {
+        #[label]
+        span: SourceSpan,
+    },
     #[default]
     #[error("Something went wrong")]
     Other,
@@ -42,23 +49,40 @@ fn ensure_no_trailing_word_chars<'src>(
     Ok(())
 }
 
+fn ensure_valid_integer<'src>(lex: &mut logos::Lexer<'src, Token>) -> Result<(), LexingError> {
+    let digits = lex.slice().trim_end_matches(['u', 'U', 'l', 'L']);
+    if let Some(offset) = digits
+        .strip_prefix('0')
+        .filter(|rest| !rest.starts_with(['x', 'X', 'b', 'B']))
+        .and_then(|rest| rest.find(['8', '9']))
+    {
+        let span = (lex.span().start + 1 + offset, 1).into();
+        return Err(LexingError::InvalidOctalDigit { span });
+    }
+    ensure_no_trailing_word_chars(lex)
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Logos, Copy)]
 #[logos(error(LexingError, LexingError::from_lexer))]
 pub enum Token {
     #[regex(r"[a-zA-Z_]\w*")]
     Identifier,
     #[regex(
-        r"\d+([uU][lL]|[lL][uU]|[uU]|[lL])?",
+        r"(0[xX][0-9a-fA-F]+|0[bB][01]+|0[0-9]*|[1-9][0-9]*)([uU](ll|LL|[lL])?|(ll|LL|[lL])[uU]?)?",
         priority = 5,
-        callback = ensure_no_trailing_word_chars
+        callback = ensure_valid_integer
     )]
     Constant,
     #[regex(
-        r"([0-9]*\.[0-9]+|[0-9]+\.?)[Ee][+-]?[0-9]+|[0-9]*\.[0-9]+|[0-9]+\.",
+        r"(([0-9]*\.[0-9]+|[0-9]+\.?)[Ee][+-]?[0-9]+|[0-9]*\.[0-9]+|[0-9]+\.|0[xX]([0-9a-fA-F]*\.[0-9a-fA-F]+|[0-9a-fA-F]+\.?)[pP][+-]?[0-9]+)[fF]?",
         priority = 6,
         callback = ensure_no_trailing_word_chars
     )]
     FloatingConstant,
+    #[regex(r#"'([^'\\\n]|\\(['"?\\abfnrtv]|[0-7]{1,3}|x[0-9a-fA-F]+))'"#)]
+    CharConstant,
+    #[regex(r#""([^"\\\n]|\\(['"?\\abfnrtv]|[0-7]{1,3}|x[0-9a-fA-F]+))*""#)]
+    StringLiteral,
     #[token("(")]
     LParen,
     #[token(")")]
@@ -67,10 +91,16 @@ pub enum Token {
     LBrace,
     #[token("}")]
     RBrace,
+    #[token("[")]
+    LBracket,
+    #[token("]")]
+    RBracket,
     #[token("int")]
     Int,
     #[token("long")]
     Long,
+    #[token("char")]
+    Char,
     #[token("void")]
     Void,
     #[regex(r"return")]
@@ -79,6 +109,9 @@ pub enum Token {
     Semicolon,
     #[regex(r"\s+")]
     Whitespace,
+    /// A line marker from the preprocessor, `# line "file" flags...`.
+    #[regex(r#"#[ \t]*[0-9]+[ \t]+"([^"\\\n]|\\.)*"([ \t]+[0-9]+)*"#)]
+    LineMarker,
     #[token("~")]
     Tilde,
     #[token("-")]
@@ -181,8 +214,45 @@ pub enum Token {
     Signed,
     #[token("unsigned")]
     Unsigned,
+    #[token("short")]
+    Short,
+    #[token("_Bool")]
+    Bool,
+    #[token("float")]
+    Float,
     #[token("double")]
     Double,
+    #[token("sizeof")]
+    Sizeof,
+    #[token("struct")]
+    Struct,
+    #[token("union")]
+    Union,
+    #[token("enum")]
+    Enum,
+    #[token("typedef")]
+    Typedef,
+    #[token("const")]
+    Const,
+    #[token("volatile")]
+    Volatile,
+    #[token("restrict")]
+    #[token("__restrict")]
+    Restrict,
+    #[token(".")]
+    Dot,
+    #[token("...")]
+    Ellipsis,
+    #[token("->")]
+    Arrow,
+    #[token("__builtin_va_start")]
+    VaStart,
+    #[token("__builtin_va_arg")]
+    VaArg,
+    #[token("__builtin_va_copy")]
+    VaCopy,
+    #[token("__builtin_va_end")]
+    VaEnd,
 }
 
 pub fn lex(source: impl AsRef<str>, filename: impl AsRef<str>) -> Result<Vec<(Token, SourceSpan)>> {
@@ -190,20 +260,180 @@ pub fn lex(source: impl AsRef<str>, filename: impl AsRef<str>) -> Result<Vec<(To
         .spanned()
         .map(|(token, span)| {
             token.map(|t| (t, span.into())).map_err(|e| {
-                miette::Report::from(e).with_source_code(NamedSource::new(
-                    filename.as_ref(),
-                    source.as_ref().to_string(),
-                ))
+                SourceMap::new(source.as_ref(), filename.as_ref()).attach(miette::Report::from(e))
             })
         })
-        .filter(|res| !matches!(res, Ok((Token::Whitespace, _))))
+        .filter(|res| !matches!(res, Ok((Token::Whitespace | Token::LineMarker, _))))
         .collect()
 }
 
+/// Maps positions in a preprocessed translation unit back to the files and lines they came
+/// from, following its line markers. Diagnostics with it as their source code name the
+/// original files and lines, while still showing the preprocessed text.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    source: String,
+    /// The stretches of the source between line markers, in order.
+    regions: Vec<Region>,
+}
+
+#[derive(Debug, Clone)]
+struct Region {
+    start: usize,
+    end: usize,
+    file: String,
+    /// The line of `file` that the first line of the region comes from.
+    line: usize,
+    /// The files and lines of the `#include`s that led to `file`, innermost first.
+    included_from: Vec<(String, usize)>,
+}
+
+impl SourceMap {
+    pub fn new(source: impl AsRef<str>, filename: impl AsRef<str>) -> Self {
+        let source = source.as_ref();
+        let mut regions = vec![Region {
+            start: 0,
+            end: source.len(),
+            file: filename.as_ref().to_string(),
+            line: 1,
+            included_from: vec![],
+        }];
+        let markers = Token::lexer(source)
+            .spanned()
+            .filter(|(token, _)| *token == Ok(Token::LineMarker));
+        for (_, span) in markers {
+            // a marker in the source itself may have a line number out of range, which leaves
+            // the lines after it numbered as they were
+            let Some((line, file, flags)) = parse_line_marker(&source[span.clone()]) else {
+                continue;
+            };
+            let current = regions.last_mut().unwrap();
+            current.end = span.start;
+            let mut included_from = current.included_from.clone();
+            if flags.contains(&1) {
+                let line = current.line + source[current.start..span.start].matches('\n').count();
+                included_from.insert(0, (current.file.clone(), line));
+            } else if flags.contains(&2) && !included_from.is_empty() {
+                included_from.remove(0);
+            }
+            regions.push(Region {
+                start: (span.end + 1).min(source.len()),
+                end: source.len(),
+                file,
+                line,
+                included_from,
+            });
+        }
+        Self {
+            source: source.to_string(),
+            regions,
+        }
+    }
+
+    fn region(&self, offset: usize) -> &Region {
+        let mut regions = self.regions.iter().rev();
+        regions
+            .find(|r| r.start <= offset)
+            .unwrap_or(&self.regions[0])
+    }
+
+    /// The text of the translation unit at `span`.
+    pub fn text(&self, span: SourceSpan) -> &str {
+        &self.source[span.offset()..span.offset() + span.len()]
+    }
+
+    /// The file, line and column, counting from one, that `offset` in the translation unit
+    /// comes from.
+    pub fn locate(&self, offset: usize) -> (&str, usize, usize) {
+        let region = self.region(offset);
+        let before = &self.source[region.start.min(offset)..offset];
+        let line = region.line + before.matches('\n').count();
+        let column = before.len() - before.rfind('\n').map_or(0, |newline| newline + 1) + 1;
+        (&region.file, line, column)
+    }
+
+    /// Gives `report` the translation unit as its source code, and says which `#include`s led
+    /// to the file of its first label.
+    pub fn attach(self, report: miette::Report) -> miette::Report {
+        let first = report.labels().and_then(|mut labels| labels.next());
+        let chain = first.map_or(vec![], |label| {
+            self.region(label.offset()).included_from.clone()
+        });
+        let report = report.with_source_code(self);
+        crate::preprocessor::included_from(
+            report,
+            chain.iter().map(|(file, line)| (file.as_str(), *line)),
+        )
+    }
+}
+
+/// Splits a line marker, `# line "file" flags...`, into its parts. Returns `None` if the line
+/// is not between 1 and 2147483647, as `#line` requires.
+fn parse_line_marker(marker: &str) -> Option<(usize, String, Vec<u8>)> {
+    let marker = marker[1..].trim_start();
+    let (line, rest) = marker.split_once([' ', '\t'])?;
+    let line = line.parse::<i32>().ok().filter(|line| *line > 0)?;
+    let rest = rest.trim_start().strip_prefix('"')?;
+    let mut file = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => file.extend(chars.next()),
+            '"' => break,
+            c => file.push(c),
+        }
+    }
+    let flags = chars.as_str().split_whitespace();
+    Some((
+        line as usize,
+        file,
+        flags.filter_map(|flag| flag.parse().ok()).collect(),
+    ))
+}
+
+impl SourceCode for SourceMap {
+    fn read_span<'a>(
+        &'a self,
+        span: &SourceSpan,
+        context_lines_before: usize,
+        context_lines_after: usize,
+    ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+        // context lines are taken from the region of the span alone, so that they are numbered
+        // consecutively and never show a line marker. A span across regions cannot be shown,
+        // which also keeps labels in different files from being merged into one snippet.
+        let region = self.region(span.offset());
+        if span.offset() + span.len() > region.end.max(region.start) {
+            return Err(MietteError::OutOfBounds);
+        }
+        let text = &self.source[region.start..region.end.max(region.start)];
+        let contents = text.read_span(
+            &(span.offset() - region.start, span.len()).into(),
+            context_lines_before,
+            context_lines_after,
+        )?;
+        Ok(Box::new(MietteSpanContents::new_named(
+            region.file.clone(),
+            contents.data(),
+            (
+                contents.span().offset() + region.start,
+                contents.span().len(),
+            )
+                .into(),
+            contents.line() + region.line - 1,
+            contents.column(),
+            contents.line_count(),
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::lexer::{Token::*, lex};
+    use miette::SourceCode;
+
+    use logos::Logos;
+
+    use crate::lexer::{LexingError, SourceMap, Token, Token::*, lex};
 
     #[test]
     fn test_lex_empty() {
@@ -244,13 +474,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lex_char_and_string() {
+        let tokens = lex(r#"char c = '\n'; "a\"b" 'x'"#, "example.c").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                (Char, (0, 4).into()),
+                (Identifier, (5, 1).into()),
+                (Equals, (7, 1).into()),
+                (CharConstant, (9, 4).into()),
+                (Semicolon, (13, 1).into()),
+                (StringLiteral, (15, 6).into()),
+                (CharConstant, (22, 3).into()),
+            ]
+        );
+        lex("'ab'", "example.c").expect_err("multi-character constants are not supported");
+        lex("\"abc", "example.c").expect_err("unterminated string literal");
+    }
+
     #[test]
     fn test_lex_invalid_ident() {
         lex("1foo$", "example.c").expect_err("1foo should fail to lex");
     }
 
+    #[test]
+    fn test_lex_number_bases() {
+        let tokens = lex("0x1Fu 017 0b1010 0 0x1.8p3 0X.8P-1f", "example.c").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                (Constant, (0, 5).into()),
+                (Constant, (6, 3).into()),
+                (Constant, (10, 6).into()),
+                (Constant, (17, 1).into()),
+                (FloatingConstant, (19, 7).into()),
+                (FloatingConstant, (27, 8).into()),
+            ]
+        );
+        lex("09", "example.c").expect_err("9 is not an octal digit");
+        // the whole constant is one token, so the digit is reported rather than a suffix
+        assert_eq!(
+            Token::lexer("0179u").next(),
+            Some(Err(LexingError::InvalidOctalDigit {
+                span: (3, 1).into()
+            }))
+        );
+        lex("0b12", "example.c").expect_err("2 is not a binary digit");
+        lex("0x1.8", "example.c").expect_err("hexadecimal floats need an exponent");
+    }
+
     #[test]
     fn test_lex_at() {
         lex("0@1", "example.c").expect_err("1foo should fail to lex");
     }
+
+    #[test]
+    fn test_source_map() {
+        let source = "# 1 \"a.c\"\nint a;\n# 1 \"b.h\" 1\nint b;\n# 3 \"a.c\" 2\nint c;\n";
+        let map = SourceMap::new(source, "a.c");
+        let lines = ["int a;", "int b;", "int c;"].map(|text| {
+            let span = (source.find(text).unwrap(), text.len()).into();
+            let contents = map.read_span(&span, 0, 0).unwrap();
+            (contents.name().unwrap().to_string(), contents.line() + 1)
+        });
+        assert_eq!(
+            lines,
+            [("a.c".into(), 1), ("b.h".into(), 1), ("a.c".into(), 3)]
+        );
+        assert_eq!(
+            map.region(source.find("int b").unwrap()).included_from,
+            [("a.c".into(), 2)]
+        );
+        assert_eq!(map.locate(source.find("c;").unwrap()), ("a.c", 3, 5));
+    }
+
+    #[test]
+    fn test_malformed_line_markers() {
+        // a return from an include that never happened, and lines that are out of range
+        let source =
+            "# 5 \"a.c\" 2\nint a;\n# 99999999999999999999999 \"b.c\"\n# 0 \"b.c\"\nint b;\n";
+        let map = SourceMap::new(source, "a.c");
+        assert_eq!(map.locate(source.find("a;").unwrap()), ("a.c", 5, 5));
+        assert!(
+            map.region(source.find("int a").unwrap())
+                .included_from
+                .is_empty()
+        );
+        assert_eq!(map.locate(source.find("b;").unwrap()), ("a.c", 8, 5));
+    }
 }
@@ -0,0 +1,21 @@
+#![feature(assert_matches)]
+
+//! A C compiler for x86-64. The [`Session`] API runs each stage of compilation on source
+//! text, so that the front end can be used without the `badcc` binary.
+
+pub mod assembler;
+pub mod assembly_gen;
+pub mod ast;
+pub mod code_emission;
+pub mod elf;
+pub mod emit;
+pub mod lexer;
+pub mod optimize;
+pub mod parser;
+pub mod preprocessor;
+pub mod sema;
+mod session;
+pub mod tacky;
+pub mod target;
+
+pub use session::{Compilation, Diagnostic, Location, Options, Session, Source};
@@ -1,36 +1,51 @@
-#![feature(assert_matches)]
-
 use camino::{Utf8Path, Utf8PathBuf};
-use fs_err::{File, create_dir_all, read_to_string};
-use miette::{Context, IntoDiagnostic, MietteHandlerOpts, NamedSource, Result, bail};
+use fs_err::File;
+use miette::{Context, IntoDiagnostic, Result, bail};
 use std::{
-    io::Write,
+    io::{self, BufWriter, Write},
     process::{Command, ExitCode},
 };
 
 use clap::Parser;
 
-use crate::{
-    assembly_gen::{Program, generate_assembly},
-    code_emission::emit_asm,
-    lexer::lex,
-    parser::parse,
-    sema::validate,
+use badcc::{
+    Options, Session, assembler,
+    assembly_gen::Program,
+    emit::{self, Format, Stage},
+    target::Target,
 };
 
-mod assembly_gen;
-mod ast;
-mod code_emission;
-mod lexer;
-mod parser;
-mod sema;
-mod tacky;
+/// The file name that stands for standard input as an input, and standard output as an output.
+const STDIO: &str = "-";
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Language {
+    C,
+    Assembler,
+}
 
 #[derive(clap::Parser)]
 struct Driver {
-    input: Utf8PathBuf,
-    #[clap(short = 'o')]
+    #[clap(
+        required = true,
+        help = "C source files, assembly files and objects to compile and link together, or - for standard input"
+    )]
+    inputs: Vec<Utf8PathBuf>,
+    #[clap(
+        short = 'o',
+        help = "Write the output to this file, or - for standard output with -S"
+    )]
     output: Option<Utf8PathBuf>,
+    #[clap(
+        short = 'x',
+        value_enum,
+        help = "Treat the inputs as this language instead of going by their extensions"
+    )]
+    language: Option<Language>,
+    #[clap(short = 'I', help = "Add a directory to search for headers")]
+    include_dirs: Vec<Utf8PathBuf>,
+    #[clap(long, value_enum, default_value_t = Target::host(), help = "Generate code for this platform")]
+    target: Target,
     #[clap(long)]
     lex: bool,
     #[clap(long)]
@@ -40,16 +55,48 @@ struct Driver {
     #[clap(long)]
     validate: bool,
 
-    #[clap(long, hide = true)]
-    test_outputs_dir: Option<Utf8PathBuf>,
-
     #[clap(long)]
     tacky: bool,
 
+    #[clap(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        help = "Write out these stages of compilation, stopping after the last"
+    )]
+    emit: Vec<Stage>,
+
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = Format::Json,
+        help = "The format of --emit output: versioned JSON for tools, or text for people that may change at any time"
+    )]
+    emit_format: Format,
+
     #[clap(short = 'c', help = "Only run preprocess, compile, and assemble steps")]
     skip_linking: bool,
 
-    #[clap(short = 'l', required = false)]
+    #[clap(
+        short = 'S',
+        help = "Only run preprocess and compile steps, writing assembly"
+    )]
+    skip_assembling: bool,
+
+    #[clap(
+        long,
+        help = "Assemble with the system assembler instead of the built-in one"
+    )]
+    no_integrated_as: bool,
+
+    #[clap(
+        short = 'O',
+        long,
+        help = "Evaluate operations on constants at compile time"
+    )]
+    fold_constants: bool,
+
+    #[clap(short = 'l', required = false, help = "Link with a library")]
     libraries: Vec<String>,
 
     #[clap(long, hide = true)]
@@ -64,107 +111,263 @@ struct Driver {
 
 impl Driver {
     fn run(&mut self) -> Result<()> {
-        if let Some(test_output_dir) = self.test_output_dir() {
-            let _ = std::fs::remove_dir_all(test_output_dir);
+        let single_output = if self.skip_assembling {
+            Some("-S")
+        } else if self.skip_linking {
+            Some("-c")
+        } else if !self.emit.is_empty() {
+            Some("--emit")
+        } else {
+            None
         };
+        if let Some(option) = single_output
+            && self.output.is_some()
+            && self.inputs.len() > 1
+        {
+            bail!("cannot specify -o with {option} and multiple input files");
+        }
+        let to_stdout = self
+            .output
+            .as_ref()
+            .is_some_and(|output| output.as_str() == STDIO);
+        if to_stdout && !self.skip_assembling && self.emit.is_empty() {
+            bail!("-o - is only supported with -S and --emit");
+        }
+        if self.output.is_some() && !to_stdout && self.emit.len() > 1 {
+            bail!("cannot specify -o with several --emit stages");
+        }
 
-        let pre = self.preprocess()?;
-        self.artifacts.push(pre.clone());
-
-        let src = read_to_string(&pre)
-            .into_diagnostic()
-            .with_context(|| format!("failed to read {pre}"))?;
+        let mut objects = vec![];
+        for input in self.inputs.clone() {
+            let object = match (self.language, input.extension()) {
+                (None, Some("o")) => Some(input),
+                (Some(Language::Assembler), _) | (None, Some("s")) if !self.stops_early() => {
+                    let object = self.object_path(&input);
+                    self.assemble(&input, &object)
+                        .context("Assembling failed")?;
+                    Some(object)
+                }
+                (Some(Language::Assembler), _) | (None, Some("s")) => None,
+                _ => self.compile(&input)?,
+            };
+            objects.extend(object);
+        }
 
-        let tokens = lex(&src, &pre)?;
-        self.write_test_output("tokens", || {
-            format!("{:#?}", tokens.iter().map(|(t, _)| t).collect::<Vec<_>>())
-        });
-        if self.lex {
+        if self.stops_early() || self.skip_linking {
             return Ok(());
         }
+        self.link(&objects).context("Linking failed")
+    }
 
-        let mut program = parse(&src, tokens, pre.as_str())?;
-        if self.parse {
-            return Ok(());
+    /// Whether an option asks to stop before anything is assembled.
+    fn stops_early(&self) -> bool {
+        self.lex
+            || self.parse
+            || self.validate
+            || self.tacky
+            || self.codegen
+            || self.skip_assembling
+            || !self.emit.is_empty()
+    }
+
+    /// The last stage that `--emit` asks for, after which compilation stops.
+    fn last_emitted(&self) -> Option<Stage> {
+        self.emit.iter().max().copied()
+    }
+
+    fn session(&self) -> Session {
+        Session::new(Options {
+            target: self.target,
+            include_dirs: self.include_dirs.clone(),
+            fold_constants: self.fold_constants,
+        })
+    }
+
+    /// Compiles the C source file `input` into an object, unless an option stops the
+    /// compilation earlier.
+    fn compile(&mut self, input: &Utf8Path) -> Result<Option<Utf8PathBuf>> {
+        let (name, text) = if input.as_str() == STDIO {
+            let text = io::read_to_string(io::stdin()).into_diagnostic();
+            (Utf8Path::new("<stdin>"), text)
+        } else {
+            (input, fs_err::read_to_string(input).into_diagnostic())
+        };
+        let session = self.session();
+        let source = session
+            .preprocess(name, text?)
+            .with_context(|| format!("failed to preprocess {name}"))?;
+        for warning in source.warnings() {
+            eprintln!("{warning:?}");
+        }
+
+        let tokens = session.lex(&source)?;
+        self.emit(input, Stage::Tokens, |format| {
+            emit::tokens(&tokens, &source.source_map(), name.as_str(), format)
+        })?;
+        if self.lex || self.last_emitted() == Some(Stage::Tokens) {
+            return Ok(None);
+        }
+
+        let mut program = session.parse(&source, tokens)?;
+        self.emit(input, Stage::Ast, |format| {
+            emit::ast(&program, &source.source_map(), name.as_str(), format)
+        })?;
+        if self.parse || self.last_emitted() == Some(Stage::Ast) {
+            return Ok(None);
         }
-        self.write_test_output("ast", || format!("{program:#?}"));
 
         if self.print_ast {
             println!("{program:#?}");
         }
 
-        let symbols = validate(&mut program)
-            .map_err(|e| miette::Report::from(e).with_source_code(NamedSource::new(&pre, src)))?;
-        self.write_test_output("sema_ast", || format!("{program:#?}"));
-        if self.validate {
-            return Ok(());
+        let symbols = session.validate(&source, &mut program)?;
+        self.emit(input, Stage::TypedAst, |format| {
+            let map = source.source_map();
+            emit::typed_ast(&program, &symbols, &map, name.as_str(), format)
+        })?;
+        if self.validate || self.last_emitted() == Some(Stage::TypedAst) {
+            return Ok(None);
         }
 
-        let tacky = tacky::lower(&program, &symbols);
-        self.write_test_output("tacky", || format!("{tacky:#?}"));
+        let mut tacky = session.lower(&program, &symbols);
+        session.optimize(&mut tacky);
+        self.emit(input, Stage::Tacky, |format| {
+            emit::tacky(&tacky, name.as_str(), format)
+        })?;
+        if self.tacky || self.last_emitted() == Some(Stage::Tacky) {
+            return Ok(None);
+        }
 
-        if self.tacky {
-            return Ok(());
+        let program = session.generate_assembly(&tacky, &symbols);
+        self.emit(input, Stage::AsmIr, |format| {
+            emit::asm_ir(&program, name.as_str(), format)
+        })?;
+        self.emit(input, Stage::Asm, |format| {
+            emit::asm(&session.emit_asm(&program), name.as_str(), format)
+        })?;
+        if self.last_emitted().is_some() {
+            return Ok(None);
+        }
+
+        if self.skip_assembling {
+            let assembly = self.assembly_path(input);
+            self.emit_asm(&program, &assembly)?;
+            return Ok(None);
         }
 
-        let program = generate_assembly(&tacky, &symbols);
-        self.write_test_output("assembly_ast", || format!("{program:#?}"));
+        let object = self.object_path(input);
 
-        let assembly = self.emit_asm(&program)?;
-        self.artifacts.push(assembly.clone());
+        // the built-in assembler only writes ELF objects
+        if self.codegen || self.no_integrated_as || self.target != Target::Linux {
+            let assembly = input.with_extension("s");
+            self.emit_asm(&program, &assembly)?;
+            self.artifacts.push(assembly.clone());
 
-        if self.codegen {
+            if self.codegen {
+                return Ok(None);
+            }
+
+            self.assemble(&assembly, &object)
+                .context("Assembling failed")?;
+            return Ok(Some(object));
+        }
+
+        self.write_object(&program, &object)?;
+        Ok(Some(object))
+    }
+
+    /// Writes the assembly for `program` to `path`, or to standard output if it is `-`.
+    fn emit_asm(&self, program: &Program, path: &Utf8Path) -> Result<()> {
+        let assembly = self.session().emit_asm(program);
+        write_output(path, assembly.as_bytes())
+    }
+
+    /// Writes `stage` of `input` if `--emit` asks for it, to the output if one was given, and
+    /// otherwise next to the source, or to standard output for standard input.
+    fn emit(
+        &self,
+        input: &Utf8Path,
+        stage: Stage,
+        dump: impl FnOnce(Format) -> String,
+    ) -> Result<()> {
+        if !self.emit.contains(&stage) {
             return Ok(());
         }
+        let path = match &self.output {
+            Some(output) => output.clone(),
+            None if input.as_str() == STDIO => STDIO.into(),
+            None => input.with_extension(match (stage, self.emit_format) {
+                (Stage::Asm, Format::Text) => "s".to_string(),
+                (stage, Format::Json) => format!("{}.json", stage.name()),
+                (stage, Format::Text) => format!("{}.txt", stage.name()),
+            }),
+        };
+        write_output(&path, dump(self.emit_format).as_bytes())
+    }
 
-        self.assemble(assembly).context("Assembling failed")?;
+    /// Where `-S` writes the assembly for `input`: the output if one was given, and otherwise
+    /// next to the source, or to standard output for standard input.
+    fn assembly_path(&self, input: &Utf8Path) -> Utf8PathBuf {
+        match &self.output {
+            Some(output) => output.clone(),
+            None if input.as_str() == STDIO => STDIO.into(),
+            None => input.with_extension("s"),
+        }
+    }
 
-        Ok(())
+    /// The object file for `input`, which is an artifact unless it is the requested output.
+    fn object_path(&mut self, input: &Utf8Path) -> Utf8PathBuf {
+        match &self.output {
+            Some(output) if self.skip_linking => output.clone(),
+            _ if self.skip_linking => input.with_extension("o"),
+            _ => {
+                let path = input.with_extension("o");
+                self.artifacts.push(path.clone());
+                path
+            }
+        }
     }
 
-    fn preprocess(&self) -> Result<Utf8PathBuf> {
-        let path = self.input.with_extension("i");
-        let result = Command::new("gcc")
-            .arg("-E")
-            .arg("-P")
-            .arg(&self.input)
-            .arg("-o")
-            .arg(&path)
-            .spawn()
-            .and_then(|mut c| c.wait())
+    fn write_object(&self, program: &Program, path: &Utf8Path) -> Result<()> {
+        let mut f = BufWriter::new(File::create(path).into_diagnostic()?);
+        assembler::assemble(program)
+            .write(&mut f)
             .into_diagnostic()?;
-        if result.success() {
-            Ok(path)
-        } else {
-            bail!("preprocessing {} failed", path)
-        }
+        f.flush().into_diagnostic()
     }
 
-    fn emit_asm(&self, program: &Program) -> Result<Utf8PathBuf> {
-        let path = self.input.with_extension("s");
-        let mut f = File::create(&path).into_diagnostic()?;
-        emit_asm(program, &f).into_diagnostic()?;
-        f.flush().into_diagnostic()?;
-        self.write_test_output("assembly.s", || read_to_string(&path).unwrap());
-        Ok(path)
+    fn assemble(&self, assembly: &Utf8Path, object: &Utf8Path) -> Result<()> {
+        let mut cmd = Command::new("gcc");
+        // -x lets gcc read assembly without a .s extension, including from standard input
+        cmd.args(["-c", "-x", "assembler"])
+            .arg(assembly)
+            .arg("-o")
+            .arg(object);
+        self.gcc(cmd)
     }
 
-    fn assemble(&self, assembly: impl AsRef<Utf8Path>) -> Result<()> {
+    /// Links `objects` with the requested libraries into an executable named after the
+    /// first input, or `a.out` for standard input, unless `-o` says otherwise.
+    fn link(&self, objects: &[Utf8PathBuf]) -> Result<()> {
         let mut cmd = Command::new("gcc");
+        cmd.args(objects);
+        cmd.args(self.libraries.iter().map(|library| format!("-l{library}")));
+        cmd.arg("-o").arg(
+            self.output
+                .clone()
+                .unwrap_or_else(|| match self.inputs[0].as_str() {
+                    STDIO => "a.out".into(),
+                    _ => self.inputs[0].with_extension(""),
+                }),
+        );
+        self.gcc(cmd)
+    }
 
-        if self.skip_linking {
-            cmd.arg("-c");
+    fn gcc(&self, mut cmd: Command) -> Result<()> {
+        if self.target == Target::Darwin {
+            cmd.arg("-arch").arg("x86_64");
         }
 
-        cmd.arg(assembly.as_ref())
-            .arg("-arch")
-            .arg("x86_64")
-            .arg("-o")
-            .arg(self.output.clone().unwrap_or_else(|| {
-                self.input
-                    .with_extension(if self.skip_linking { "o" } else { "" })
-            }));
-
         let status = cmd.spawn().into_diagnostic()?.wait().into_diagnostic()?;
 
         if status.success() {
@@ -173,30 +376,16 @@ impl Driver {
             bail!("{cmd:?}")
         }
     }
+}
 
-    fn test_output_dir(&self) -> Option<Utf8PathBuf> {
-        let test_outputs_dir = self.test_outputs_dir.as_ref()?;
-        let mut output_dir = test_outputs_dir.clone();
-        output_dir.extend(
-            self.input
-                .components()
-                .skip_while(|comp| comp.as_os_str() != "tests"),
-        );
-        Some(output_dir)
-    }
-
-    fn write_test_output<F, S: AsRef<str>>(&self, file: impl AsRef<Utf8Path>, contents: F)
-    where
-        F: FnOnce() -> S,
-    {
-        let Some(test_output_dir) = self.test_output_dir() else {
-            return;
-        };
-
-        let mut output_file = test_output_dir.clone();
-        output_file.push(file);
-        create_dir_all(output_file.parent().unwrap()).unwrap();
-        fs_err::write(output_file, contents().as_ref()).unwrap();
+/// Writes `contents` to `path`, or to standard output if it is `-`.
+fn write_output(path: &Utf8Path, contents: &[u8]) -> Result<()> {
+    if path.as_str() == STDIO {
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(contents).into_diagnostic()?;
+        stdout.flush().into_diagnostic()
+    } else {
+        fs_err::write(path, contents).into_diagnostic()
     }
 }
 
@@ -213,17 +402,9 @@ impl Drop for Driver {
 fn main() -> ExitCode {
     let mut driver = Driver::parse();
 
-    if driver.test_outputs_dir.is_some() {
-        miette::set_hook(Box::new(|_| {
-            Box::new(MietteHandlerOpts::new().width(10000).build())
-        }))
-        .unwrap();
-    }
-
     let status: u8 = match driver.run() {
         Ok(_) => 0,
         Err(err) => {
-            driver.write_test_output("error.txt", || format!("{err:?}"));
             eprintln!("{err:?}");
             2
         }
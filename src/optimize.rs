@@ -0,0 +1,289 @@
+use crate::{
+    ast::{self, BinaryOperator, Constant},
+    sema::Type,
+    tacky::{Instruction, Program, UnaryOperator, Val},
+};
+
+/// Replaces each instruction whose operands are all constants with a copy of its result, and
+/// each jump on a constant condition with an unconditional jump or nothing. Operations whose
+/// result is undefined, such as a division by zero, are left for the program to perform.
+/// Instructions that access volatile objects are never folded or removed.
+pub fn fold_constants(program: &mut Program) {
+    for function in program.functions.iter_mut() {
+        let instructions = std::mem::take(&mut function.instructions);
+        function.instructions = instructions.into_iter().filter_map(fold).collect();
+    }
+}
+
+fn fold(instruction: Instruction) -> Option<Instruction> {
+    use Instruction::*;
+    if instruction.is_volatile() {
+        return Some(instruction);
+    }
+    let folded = match &instruction {
+        Unary {
+            op,
+            src: Val::Constant(src),
+            dst,
+        } => Some((unary(op, *src), dst.clone())),
+        Binary {
+            op,
+            lhs: Val::Constant(lhs),
+            rhs: Val::Constant(rhs),
+            dst,
+        } => binary(*op, *lhs, *rhs).map(|result| (result, dst.clone())),
+        SignExtend {
+            src: Val::Constant(src),
+            dst,
+        }
+        | Truncate {
+            src: Val::Constant(src),
+            dst,
+        }
+        | ZeroExtend {
+            src: Val::Constant(src),
+            dst,
+        }
+        | IntToDouble {
+            src: Val::Constant(src),
+            dst,
+        }
+        | UIntToDouble {
+            src: Val::Constant(src),
+            dst,
+        }
+        | FloatToDouble {
+            src: Val::Constant(src),
+            dst,
+        }
+        | DoubleToFloat {
+            src: Val::Constant(src),
+            dst,
+        } => {
+            let mut result = *src;
+            result.cast(&dst.ty());
+            Some((result, dst.clone()))
+        }
+        DoubleToInt {
+            src: Val::Constant(src),
+            dst,
+        }
+        | DoubleToUInt {
+            src: Val::Constant(src),
+            dst,
+        } => truncate(*src, &dst.ty()).map(|result| (result, dst.clone())),
+        JumpIfZero(Val::Constant(cond), target) => {
+            return cond.is_zero().then(|| Jump(target.clone()));
+        }
+        JumpIfNotZero(Val::Constant(cond), target) => {
+            return (!cond.is_zero()).then(|| Jump(target.clone()));
+        }
+        _ => None,
+    };
+    match folded {
+        Some((result, dst)) => Some(Copy {
+            src: Val::Constant(result),
+            dst,
+        }),
+        None => Some(instruction),
+    }
+}
+
+fn unary(op: &UnaryOperator, src: Constant) -> Constant {
+    src.unary(match op {
+        UnaryOperator::Complement => ast::UnaryOperator::Complement,
+        UnaryOperator::Negate => ast::UnaryOperator::Minus,
+        UnaryOperator::Not => ast::UnaryOperator::Not,
+    })
+}
+
+fn binary(op: BinaryOperator, lhs: Constant, rhs: Constant) -> Option<Constant> {
+    let ty = lhs.ty();
+    if ty.signed() && matches!(op, BinaryOperator::Divide | BinaryOperator::Remainder) {
+        // the most negative value is its own negation, and dividing it by -1 overflows
+        let minimum = !lhs.is_zero() && lhs.unary(ast::UnaryOperator::Minus) == lhs;
+        if minimum && rhs.as_long() == -1 {
+            return None;
+        }
+    }
+    if matches!(op, BinaryOperator::LeftShift | BinaryOperator::RightShift) {
+        let bits = ty.size() as i64 * 8;
+        if !(0..bits).contains(&rhs.as_long()) {
+            return None;
+        }
+    }
+    lhs.binary(op, rhs)
+}
+
+/// Converts a floating constant to the integer type `ty`, unless its integer part is out of
+/// the range of `ty`, which is undefined.
+fn truncate(src: Constant, ty: &Type) -> Option<Constant> {
+    let bits = ty.size() as i32 * 8;
+    let (min, end) = if ty.signed() {
+        (-(2f64.powi(bits - 1)), 2f64.powi(bits - 1))
+    } else {
+        (0.0, 2f64.powi(bits))
+    };
+    if !(min..end).contains(&src.as_double().trunc()) {
+        return None;
+    }
+    let mut result = src;
+    result.cast(ty);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{BinaryOperator, Constant},
+        sema::Type,
+        tacky::{Instruction, UnaryOperator, Val},
+    };
+
+    use super::fold;
+
+    fn var(ty: Type) -> Val {
+        Val::Var("tmp".into(), ty)
+    }
+
+    fn folded(instruction: Instruction) -> Option<Constant> {
+        match fold(instruction) {
+            Some(Instruction::Copy {
+                src: Val::Constant(constant),
+                ..
+            }) => Some(constant),
+            _ => None,
+        }
+    }
+
+    fn binary(op: BinaryOperator, lhs: Constant, rhs: Constant) -> Option<Constant> {
+        folded(Instruction::Binary {
+            op,
+            lhs: Val::Constant(lhs),
+            rhs: Val::Constant(rhs),
+            dst: var(lhs.ty()),
+        })
+    }
+
+    #[test]
+    fn test_integers() {
+        use Constant::*;
+        assert_eq!(binary(BinaryOperator::Add, Int(1), Int(1)), Some(Int(2)));
+        assert_eq!(
+            binary(BinaryOperator::Subtract, UInt(0), UInt(1)),
+            Some(UInt(u32::MAX))
+        );
+        assert_eq!(
+            binary(BinaryOperator::Multiply, ULong(u64::MAX), ULong(2)),
+            Some(ULong(u64::MAX - 1))
+        );
+        assert_eq!(
+            binary(BinaryOperator::RightShift, UInt(u32::MAX), Int(31)),
+            Some(UInt(1))
+        );
+        assert_eq!(
+            binary(BinaryOperator::LessThan, UInt(1), UInt(u32::MAX)),
+            Some(Int(1))
+        );
+        assert_eq!(binary(BinaryOperator::Divide, Int(1), Int(0)), None);
+        assert_eq!(binary(BinaryOperator::Remainder, Long(1), Long(0)), None);
+        assert_eq!(binary(BinaryOperator::Divide, Int(i32::MIN), Int(-1)), None);
+        assert_eq!(binary(BinaryOperator::LeftShift, Int(1), Int(32)), None);
+
+        let negate = Instruction::Unary {
+            op: UnaryOperator::Negate,
+            src: Val::Constant(UInt(1)),
+            dst: var(Type::UInt),
+        };
+        assert_eq!(folded(negate), Some(UInt(u32::MAX)));
+        let truncate = Instruction::Truncate {
+            src: Val::Constant(Long(0x1_0000_0002)),
+            dst: var(Type::Int),
+        };
+        assert_eq!(folded(truncate), Some(Int(2)));
+    }
+
+    #[test]
+    fn test_doubles() {
+        use Constant::*;
+        let negative_zero = binary(BinaryOperator::Multiply, Double(-1.0), Double(0.0));
+        assert!(matches!(negative_zero, Some(Double(d)) if d == 0.0 && d.is_sign_negative()));
+        let nan = binary(BinaryOperator::Divide, Double(0.0), Double(0.0));
+        assert!(matches!(nan, Some(Double(d)) if d.is_nan()));
+        assert_eq!(
+            binary(BinaryOperator::NotEqual, Double(f64::NAN), Double(f64::NAN)),
+            Some(Int(1))
+        );
+
+        let negate = Instruction::Unary {
+            op: UnaryOperator::Negate,
+            src: Val::Constant(Double(0.0)),
+            dst: var(Type::Double),
+        };
+        assert!(matches!(folded(negate), Some(Double(d)) if d.is_sign_negative()));
+        let to_int = |d| {
+            folded(Instruction::DoubleToInt {
+                src: Val::Constant(Double(d)),
+                dst: var(Type::Int),
+            })
+        };
+        assert_eq!(to_int(-2.9), Some(Int(-2)));
+        assert_eq!(to_int(3e9), None);
+        assert_eq!(to_int(f64::NAN), None);
+        let to_double = Instruction::UIntToDouble {
+            src: Val::Constant(ULong(u64::MAX)),
+            dst: var(Type::Double),
+        };
+        assert_eq!(folded(to_double), Some(Double(u64::MAX as f64)));
+    }
+
+    #[test]
+    fn test_jumps() {
+        let jump = |instruction| match fold(instruction) {
+            Some(Instruction::Jump(target)) => Some(target),
+            None => None,
+            instruction => panic!("{instruction:?} is not a jump"),
+        };
+        let zero = Val::Constant(Constant::Double(-0.0));
+        let one = Val::Constant(Constant::Long(1));
+        assert_eq!(
+            jump(Instruction::JumpIfZero(zero.clone(), "a".into())),
+            Some("a".into())
+        );
+        assert_eq!(jump(Instruction::JumpIfNotZero(zero, "a".into())), None);
+        assert_eq!(jump(Instruction::JumpIfZero(one, "a".into())), None);
+    }
+
+    #[test]
+    fn test_volatile_accesses() {
+        let ptr = Val::Var("ptr".into(), Type::Pointer(Type::Int.into()));
+        let load = fold(Instruction::Load {
+            src_ptr: ptr.clone(),
+            dst: var(Type::Int),
+            volatile: true,
+        });
+        assert!(matches!(
+            load,
+            Some(Instruction::Load { volatile: true, .. })
+        ));
+        let store = fold(Instruction::Store {
+            src: Val::Constant(Constant::Int(1)),
+            dst_ptr: ptr,
+            volatile: true,
+        });
+        assert!(matches!(
+            store,
+            Some(Instruction::Store { volatile: true, .. })
+        ));
+        let copy = fold(Instruction::CopyToOffset {
+            src: Val::Constant(Constant::Int(1)),
+            dst: "x".into(),
+            offset: 0,
+            volatile: true,
+        });
+        assert!(matches!(
+            copy,
+            Some(Instruction::CopyToOffset { volatile: true, .. })
+        ));
+    }
+}
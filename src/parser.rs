@@ -1,11 +1,16 @@
 use std::{
     borrow::Borrow,
+    collections::HashMap,
     num::{ParseFloatError, ParseIntError},
 };
 
-use miette::{NamedSource, SourceSpan};
+use miette::SourceSpan;
 
-use crate::{ast::*, lexer::Token, sema::Type};
+use crate::{
+    ast::*,
+    lexer::{SourceMap, Token},
+    sema::{Qualifiers, StructType, Type},
+};
 
 impl Borrow<dyn miette::Diagnostic + 'static> for Box<ParserError> {
     fn borrow(&self) -> &(dyn miette::Diagnostic + 'static) {
@@ -65,6 +70,73 @@ enum ParserError {
         #[label]
         span: SourceSpan,
     },
+
+    #[error("only pointer types can be restrict-qualified")]
+    RestrictNonPointer {
+        #[label("specifiers")]
+        span: SourceSpan,
+    },
+
+    #[error("cannot apply additional type derivations to a function type")]
+    DerivedFunctionType {
+        #[label("here")]
+        span: SourceSpan,
+    },
+
+    #[error("functions cannot return arrays or functions")]
+    InvalidReturnType {
+        #[label("here")]
+        span: SourceSpan,
+    },
+
+    #[error("escape sequence out of range")]
+    EscapeOutOfRange {
+        #[label("here")]
+        span: SourceSpan,
+    },
+
+    #[error("array size must be a positive integer constant")]
+    InvalidArraySize {
+        #[label("here")]
+        span: SourceSpan,
+    },
+
+    #[error("a function cannot be declared in a for loop header")]
+    ForInitFunctionDeclaration {
+        #[label("declaration")]
+        span: SourceSpan,
+    },
+
+    #[error("a structure, union or enumeration cannot be declared in a for loop header")]
+    ForInitTagDeclaration {
+        #[label("declaration")]
+        span: SourceSpan,
+    },
+
+    #[error("a typedef cannot be declared in a for loop header")]
+    ForInitTypedefDeclaration {
+        #[label("declaration")]
+        span: SourceSpan,
+    },
+
+    #[error("structure members cannot have function type")]
+    FunctionMember {
+        #[label("here")]
+        span: SourceSpan,
+    },
+
+    #[error("conflicting declarations of {name}")]
+    ConflictingDeclaration {
+        name: String,
+        #[label("here")]
+        span: SourceSpan,
+    },
+
+    #[error("a function definition cannot take its type from a typedef")]
+    TypedefFunctionDefinition {
+        #[label("here")]
+        span: SourceSpan,
+    },
 }
 
 type Result<T> = std::result::Result<T, ParserError>;
@@ -76,6 +148,9 @@ fn spanning(start: SourceSpan, end: SourceSpan) -> SourceSpan {
 struct Lexer<'i> {
     source: &'i str,
     tokens: Vec<(Token, SourceSpan)>,
+    /// The ordinary identifiers declared in each enclosing scope, mapped to the type they name
+    /// if they are typedef names.
+    typedefs: Vec<HashMap<String, Option<Type>>>,
 }
 
 impl Lexer<'_> {
@@ -137,10 +212,51 @@ impl Lexer<'_> {
     }
 
     fn peek_decl_specifier(&self) -> bool {
-        use Token::*;
-        self.peek_token().is_some_and(|(t, _)| {
-            matches!(t, Int | Long | Signed | Unsigned | Double | Static | Extern)
-        })
+        // a typedef name followed by a colon is a statement label
+        self.peek_token()
+            .is_some_and(|t| self.starts_declaration(t))
+            && !(self.peek_kind(Token::Identifier)
+                && self.peek_n(2).is_some_and(|(t, _)| t == Token::Colon))
+    }
+
+    /// Whether `token` can begin a declaration or type name: either a specifier keyword or a
+    /// typedef name that is in scope.
+    fn starts_declaration(&self, (token, span): (Token, SourceSpan)) -> bool {
+        is_decl_specifier(token)
+            || (token == Token::Identifier && self.typedef(self.str_at(span)).is_some())
+    }
+
+    fn push_scope(&mut self) {
+        self.typedefs.push(HashMap::new());
+    }
+
+    fn pop_scope(&mut self) {
+        self.typedefs.pop();
+    }
+
+    /// The type named by `name`, if it is a typedef name not hidden by an ordinary identifier.
+    fn typedef(&self, name: &str) -> Option<&Type> {
+        self.typedefs
+            .iter()
+            .rev()
+            .find_map(|s| s.get(name))
+            .and_then(Option::as_ref)
+    }
+
+    /// Declares `name` in the current scope, as a typedef name for `ty` if it is set and as an
+    /// ordinary identifier otherwise.
+    fn declare(&mut self, name: &str, ty: Option<Type>, span: SourceSpan) -> Result<()> {
+        let scope = self.typedefs.last_mut().unwrap();
+        match scope.get(name) {
+            Some(previous) if *previous != ty => Err(ParserError::ConflictingDeclaration {
+                name: name.into(),
+                span,
+            }),
+            _ => {
+                scope.insert(name.into(), ty);
+                Ok(())
+            }
+        }
     }
 
     fn str_at(&self, span: SourceSpan) -> &str {
@@ -157,6 +273,11 @@ pub fn parse(
     let mut lexer = Lexer {
         source: source.as_ref(),
         tokens,
+        // builtin typedef names live outside the file scope, so declarations there can hide them
+        typedefs: vec![
+            HashMap::from([("__builtin_va_list".into(), Some(Type::va_list()))]),
+            HashMap::new(),
+        ],
     };
     parse_program(&mut lexer)
         .and_then(|program| {
@@ -165,10 +286,7 @@ pub fn parse(
             }
             Ok(program)
         })
-        .map_err(|e| {
-            miette::Report::from(e)
-                .with_source_code(NamedSource::new(filename, source.as_ref().to_string()))
-        })
+        .map_err(|e| SourceMap::new(source.as_ref(), filename).attach(miette::Report::from(e)))
 }
 
 fn parse_program(lexer: &mut Lexer) -> Result<Program> {
@@ -181,11 +299,13 @@ fn parse_program(lexer: &mut Lexer) -> Result<Program> {
 
 fn parse_block(lexer: &mut Lexer) -> Result<Block> {
     let (_, start) = lexer.expect(Token::LBrace)?;
+    lexer.push_scope();
     let mut items = vec![];
     while !matches!(lexer.peek_token(), Some((Token::RBrace, _))) {
         items.push(parse_block_item(lexer)?);
     }
     let (_, end) = lexer.expect(Token::RBrace)?;
+    lexer.pop_scope();
     let span = spanning(start, end);
     Ok(Block { items, span })
 }
@@ -198,22 +318,88 @@ fn parse_block_item(lexer: &mut Lexer) -> Result<BlockItem> {
     }
 }
 
-fn parse_decl_specifiers(lexer: &mut Lexer) -> Result<(Option<StorageClass>, Type, SourceSpan)> {
+const fn is_decl_specifier(token: Token) -> bool {
+    use Token::*;
+    matches!(
+        token,
+        Int | Long
+            | Short
+            | Char
+            | Bool
+            | Float
+            | Double
+            | Void
+            | Struct
+            | Union
+            | Enum
+            | Signed
+            | Unsigned
+            | Static
+            | Extern
+            | Typedef
+            | Const
+            | Volatile
+            | Restrict
+    )
+}
+
+/// The specifiers at the start of a declaration.
+struct Specifiers {
+    storage: Option<StorageClass>,
+    /// Whether the declaration introduces typedef names rather than objects or functions.
+    typedef: bool,
+    ty: Type,
+    span: SourceSpan,
+}
+
+fn parse_decl_specifiers(lexer: &mut Lexer) -> Result<Specifiers> {
     use Token::*;
 
     let start = lexer.mark();
     let mut end = start;
     let mut type_tokens = vec![];
-    while let Some((token, span)) = lexer.tokens.pop_if(|(token, _)| {
-        matches!(
-            token,
-            Int | Long | Double | Signed | Unsigned | Static | Extern
-        )
-    }) {
-        end = span;
-        type_tokens.push(token);
+    let mut tag = None;
+    let mut named = None;
+    while let Some((token, span)) = lexer.peek_token() {
+        if is_decl_specifier(token) {
+            lexer.next_token();
+            end = span;
+            if matches!(token, Struct | Union | Enum) {
+                let (name, span) = lexer.expect_identifier()?;
+                end = span;
+                tag = Some(name);
+            }
+            type_tokens.push(token);
+        } else if token == Identifier
+            // an identifier after another type specifier is the declarator, even if it is
+            // also a typedef name
+            && named.is_none()
+            && type_tokens
+                .iter()
+                .all(|t| matches!(t, Static | Extern | Typedef | Const | Volatile | Restrict))
+            && let Some(ty) = lexer.typedef(lexer.str_at(span))
+        {
+            named = Some(ty.clone());
+            lexer.next_token();
+            end = span;
+        } else {
+            break;
+        }
     }
 
+    // qualifiers may be repeated, and apply to whatever type the other specifiers name
+    let mut qualifiers = Qualifiers::default();
+    type_tokens.retain(|token| {
+        let qualifier = match token {
+            Const => &mut qualifiers.constant,
+            Volatile => &mut qualifiers.volatile,
+            Restrict => &mut qualifiers.restrict,
+            _ => return true,
+        };
+        *qualifier = true;
+        false
+    });
+
     let mut remove = |token: Token| {
         if let Some(idx) = type_tokens.iter().position(|t| *t == token) {
             type_tokens.remove(idx);
@@ -225,16 +411,40 @@ fn parse_decl_specifiers(lexer: &mut Lexer) -> Result<(Option<StorageClass>, Typ
 
     let int = remove(Int);
     let long = remove(Long);
+    // `long long` has the same representation as `long`, so it is treated as a synonym
+    if long {
+        remove(Long);
+    }
+    let short = remove(Short);
+    let char = remove(Char);
+    let bool = remove(Bool);
     let signed = remove(Signed);
     let unsigned = remove(Unsigned);
+    let float = remove(Float);
     let double = remove(Double);
+    let void = remove(Void);
+    let r#struct = remove(Struct);
+    let union = remove(Union);
+    let r#enum = remove(Enum);
+    let tagged = r#struct || union || r#enum;
 
     let r#static = remove(Static);
     let r#extern = remove(Extern);
+    let typedef = remove(Typedef);
 
+    let floating = float || double;
+    let sized = long || short || char;
+    let specified = int || sized || signed || unsigned || bool || floating || void || tagged;
     if !type_tokens.is_empty()
-        || (!int && !long && !signed && !unsigned && !double)
-        || (double && (long || int || signed || unsigned))
+        || specified == named.is_some()
+        || (float && double)
+        || (floating && (sized || int || signed || unsigned))
+        || (bool && (floating || sized || int || signed || unsigned))
+        || ((void || tagged) && (floating || bool || sized || int || signed || unsigned))
+        || (void && tagged)
+        || (r#struct as u8 + union as u8 + r#enum as u8 > 1)
+        || (char && (long || int))
+        || (short && (long || char))
         || (signed && unsigned)
     {
         return Err(ParserError::DeclSingleType {
@@ -242,109 +452,552 @@ fn parse_decl_specifiers(lexer: &mut Lexer) -> Result<(Option<StorageClass>, Typ
         });
     }
 
-    let ty = match (!long, !unsigned) {
+    let ty = named.unwrap_or_else(|| match (!long, !unsigned) {
+        _ if float => Type::Float,
         _ if double => Type::Double,
+        _ if bool => Type::Bool,
+        _ if void => Type::Void,
+        _ if r#struct => Type::Struct(StructType::new(tag.unwrap())),
+        _ if union => Type::Struct(StructType::new_union(tag.unwrap())),
+        // enumerations are compatible with int
+        _ if r#enum => Type::Int,
+        _ if char && signed => Type::SChar,
+        _ if char && unsigned => Type::UChar,
+        _ if char => Type::Char,
+        _ if short && unsigned => Type::UShort,
+        _ if short => Type::Short,
         (true, true) => Type::Int,
         (true, false) => Type::UInt,
         (false, true) => Type::Long,
         (false, false) => Type::ULong,
-    };
+    });
+    if qualifiers.restrict && !ty.is_pointer() {
+        return Err(ParserError::RestrictNonPointer {
+            span: spanning(start, end),
+        });
+    }
+    let ty = ty.qualified(qualifiers);
 
+    if r#static as u8 + r#extern as u8 + typedef as u8 > 1 {
+        return Err(ParserError::MultipleDeclSpecifiers {
+            span: spanning(start, end),
+        });
+    }
     let storage = match (r#static, r#extern) {
-        (true, true) => {
-            return Err(ParserError::MultipleDeclSpecifiers {
-                span: spanning(start, end),
-            });
-        }
-        (true, false) => Some(StorageClass::Static),
-        (false, true) => Some(StorageClass::Extern),
-        (false, false) => None,
+        (true, _) => Some(StorageClass::Static),
+        (_, true) => Some(StorageClass::Extern),
+        _ => None,
     };
 
-    Ok((storage, ty, spanning(start, end)))
+    Ok(Specifiers {
+        storage,
+        typedef,
+        ty,
+        span: spanning(start, end),
+    })
 }
 
-fn parse_variable_declaration(lexer: &mut Lexer) -> Result<VariableDeclaration> {
-    let start = lexer.mark();
-    let (storage, ty, _) = parse_decl_specifiers(lexer)?;
-    let (name, _) = lexer.expect_identifier()?;
+enum Declarator {
+    Identifier(String, SourceSpan),
+    /// The position of the identifier omitted from an abstract declarator.
+    Abstract(SourceSpan),
+    Pointer(Box<Declarator>, Qualifiers),
+    Array(Box<Declarator>, u64),
+    Function(Vec<(Type, Declarator)>, bool, Box<Declarator>),
+}
+
+fn parse_declarator(lexer: &mut Lexer) -> Result<Declarator> {
+    parse_any_declarator(lexer, false)
+}
+
+/// Parses a declarator, which may omit its identifier if `abstract` is set, as it can in type
+/// names and parameter declarations.
+fn parse_any_declarator(lexer: &mut Lexer, r#abstract: bool) -> Result<Declarator> {
+    if lexer.expect(Token::Asterisk).is_ok() {
+        let mut qualifiers = Qualifiers::default();
+        while let Some((token, _)) = lexer.peek_token() {
+            match token {
+                Token::Const => qualifiers.constant = true,
+                Token::Volatile => qualifiers.volatile = true,
+                Token::Restrict => qualifiers.restrict = true,
+                _ => break,
+            }
+            lexer.next_token();
+        }
+        return Ok(Declarator::Pointer(
+            parse_any_declarator(lexer, r#abstract)?.into(),
+            qualifiers,
+        ));
+    }
 
-    let init = if lexer.expect(Token::Equals).is_ok() {
-        Some(parse_expression(lexer)?)
+    // where the identifier may be omitted, a parenthesis can also begin a parameter list
+    let nested = lexer.peek_kind(Token::LParen)
+        && !(r#abstract
+            && lexer
+                .peek_n(2)
+                .is_some_and(|t| t.0 == Token::RParen || lexer.starts_declaration(t)));
+    let simple = if nested {
+        lexer.next_token();
+        let inner = parse_any_declarator(lexer, r#abstract)?;
+        lexer.expect(Token::RParen)?;
+        inner
+    } else if r#abstract && !lexer.peek_kind(Token::Identifier) {
+        Declarator::Abstract(lexer.mark())
     } else {
-        None
+        let (name, span) = lexer.expect_identifier()?;
+        Declarator::Identifier(name, span)
     };
 
-    let (_, end) = lexer.expect(Token::Semicolon)?;
-    let span = spanning(start, end);
-
-    Ok(VariableDeclaration {
-        name: name.to_string(),
-        ty,
-        init,
-        storage,
-        span,
-    })
+    if lexer.peek_kind(Token::LParen) {
+        let (params, variadic) = parse_param_list(lexer)?;
+        Ok(Declarator::Function(params, variadic, simple.into()))
+    } else {
+        let mut declarator = simple;
+        while lexer.peek_kind(Token::LBracket) {
+            declarator = Declarator::Array(declarator.into(), parse_array_size(lexer)?);
+        }
+        Ok(declarator)
+    }
 }
 
-fn parse_type(lexer: &mut Lexer) -> Result<Type> {
-    let (storage, ty, span) = parse_decl_specifiers(lexer)?;
-    if storage.is_some() {
-        return Err(ParserError::TypeStorageClass { span });
+/// Parses an array dimension. An omitted dimension (`[]`) is represented by a size of zero,
+/// which can never be declared explicitly.
+fn parse_array_size(lexer: &mut Lexer) -> Result<u64> {
+    lexer.expect(Token::LBracket)?;
+    if lexer.expect(Token::RBracket).is_ok() {
+        return Ok(0);
     }
-    Ok(ty)
-}
 
-fn parse_declaration(lexer: &mut Lexer) -> Result<Declaration> {
-    let start = lexer.mark();
-    for n in 2.. {
-        match lexer.peek_n(n) {
-            Some((Token::Semicolon | Token::Equals, _)) => {
-                return parse_variable_declaration(lexer).map(Declaration::Variable);
-            }
-            Some((Token::LParen, _)) | None => break,
-            _ => {}
+    let size = match parse_assignment_expression(lexer)? {
+        Expression::Constant { constant, .. }
+            if !constant.ty().is_floating() && constant.as_long() > 0 =>
+        {
+            constant.as_long() as u64
         }
-    }
+        expr => return Err(ParserError::InvalidArraySize { span: expr.span() }),
+    };
 
-    let (storage, ret, _) = parse_decl_specifiers(lexer)?;
-    let (identifier, _) = lexer.expect_identifier()?;
+    lexer.expect(Token::RBracket)?;
+    Ok(size)
+}
 
+/// Parses a parameter list, and whether it ends with `...` to accept further arguments.
+fn parse_param_list(lexer: &mut Lexer) -> Result<(Vec<(Type, Declarator)>, bool)> {
     lexer.expect(Token::LParen)?;
 
     let mut params = vec![];
-    if !(lexer.expect(Token::Void).is_ok() || lexer.peek_kind(Token::RParen)) {
-        let ty = parse_type(lexer)?;
-        let (id, span) = lexer.expect_identifier()?;
-        params.push((ty, id, span));
-        while lexer.expect(Token::Comma).is_ok() {
-            let ty = parse_type(lexer)?;
-            let (id, span) = lexer.expect_identifier()?;
-            params.push((ty, id, span));
+    let mut variadic = false;
+    if lexer.peek_kind(Token::Void) && lexer.peek_n(2).is_some_and(|(t, _)| t == Token::RParen) {
+        lexer.next_token();
+    } else if !lexer.peek_kind(Token::RParen) {
+        loop {
+            let ty = parse_type_specifiers(lexer)?;
+            params.push((ty, parse_any_declarator(lexer, true)?));
+            if lexer.expect(Token::Comma).is_err() {
+                break;
+            }
+            // the variable part must follow at least one named parameter
+            if lexer.expect(Token::Ellipsis).is_ok() {
+                variadic = true;
+                break;
+            }
         }
     }
 
     lexer.expect(Token::RParen)?;
+    Ok((params, variadic))
+}
+
+type ProcessedDeclarator = (String, SourceSpan, Type, Vec<(String, SourceSpan)>);
+
+/// Applies `declarator` to `base`, giving the declared name (empty for an abstract declarator),
+/// its type and, for a function declarator, the names of its parameters.
+fn process_declarator(declarator: Declarator, base: Type) -> Result<ProcessedDeclarator> {
+    match declarator {
+        Declarator::Identifier(name, span) => Ok((name, span, base, vec![])),
+        Declarator::Abstract(span) => Ok((String::new(), span, base, vec![])),
+        Declarator::Pointer(inner, qualifiers) => {
+            process_declarator(*inner, Type::Pointer(base.into()).qualified(qualifiers))
+        }
+        Declarator::Array(inner, size) => {
+            if matches!(base, Type::Function { .. }) {
+                return Err(ParserError::DerivedFunctionType {
+                    span: declarator_span(&inner),
+                });
+            }
+            process_declarator(
+                *inner,
+                Type::Array {
+                    element: base.into(),
+                    size,
+                },
+            )
+        }
+        Declarator::Function(params, variadic, inner) => {
+            if matches!(base, Type::Function { .. } | Type::Array { .. }) {
+                return Err(ParserError::InvalidReturnType {
+                    span: declarator_span(&inner),
+                });
+            }
+
+            let mut param_types = vec![];
+            let mut param_names = vec![];
+            for (i, (ty, param)) in params.into_iter().enumerate() {
+                let (mut param_name, param_span, param_ty, _) = process_declarator(param, ty)?;
+                // unnamed parameters get a name that cannot clash with any identifier
+                if param_name.is_empty() {
+                    param_name = i.to_string();
+                }
+                // array and function parameters are adjusted to pointers
+                let param_ty = match param_ty {
+                    Type::Array { element, .. } => Type::Pointer(element),
+                    ty @ Type::Function { .. } => Type::Pointer(ty.into()),
+                    ty => ty,
+                };
+                param_types.push(param_ty);
+                param_names.push((param_name, param_span));
+            }
+
+            let ty = Type::Function {
+                params: param_types,
+                ret: base.into(),
+                variadic,
+            };
+            match *inner {
+                Declarator::Identifier(name, span) => Ok((name, span, ty, param_names)),
+                Declarator::Abstract(span) => Ok((String::new(), span, ty, param_names)),
+                // the parameters belong to a function type nested in the declared type
+                inner => process_declarator(inner, ty),
+            }
+        }
+    }
+}
+
+fn declarator_span(declarator: &Declarator) -> SourceSpan {
+    match declarator {
+        Declarator::Identifier(_, span) | Declarator::Abstract(span) => *span,
+        Declarator::Pointer(inner, _)
+        | Declarator::Array(inner, _)
+        | Declarator::Function(_, _, inner) => declarator_span(inner),
+    }
+}
+
+fn parse_type_specifiers(lexer: &mut Lexer) -> Result<Type> {
+    let specifiers = parse_decl_specifiers(lexer)?;
+    if specifiers.storage.is_some() || specifiers.typedef {
+        return Err(ParserError::TypeStorageClass {
+            span: specifiers.span,
+        });
+    }
+    Ok(specifiers.ty)
+}
+
+fn parse_type(lexer: &mut Lexer) -> Result<Type> {
+    let base = parse_type_specifiers(lexer)?;
+    let declarator = parse_any_declarator(lexer, true)?;
+    let (name, span, ty, _) = process_declarator(declarator, base)?;
+    if !name.is_empty() {
+        return Err(ParserError::Expected {
+            options: vec![Token::RParen],
+            kind: Token::Identifier,
+            span,
+        });
+    }
+    Ok(ty)
+}
+
+fn parse_declaration(lexer: &mut Lexer) -> Result<Declaration> {
+    if (lexer.peek_kind(Token::Struct) || lexer.peek_kind(Token::Union))
+        && lexer
+            .peek_n(3)
+            .is_some_and(|(t, _)| matches!(t, Token::LBrace | Token::Semicolon))
+    {
+        return parse_struct_declaration(lexer).map(Declaration::Struct);
+    }
+    if lexer.peek_kind(Token::Enum)
+        && (lexer.peek_n(2).is_some_and(|(t, _)| t == Token::LBrace)
+            || lexer.peek_n(3).is_some_and(|(t, _)| t == Token::LBrace))
+    {
+        return parse_enum_declaration(lexer).map(Declaration::Enum);
+    }
+
+    let start = lexer.mark();
+    let Specifiers {
+        storage,
+        typedef,
+        ty: base,
+        ..
+    } = parse_decl_specifiers(lexer)?;
+    let declarator = parse_declarator(lexer)?;
+    let (identifier, name_span, ty, names) = process_declarator(declarator, base)?;
+
+    if typedef {
+        let (_, end) = lexer.expect(Token::Semicolon)?;
+        lexer.declare(&identifier, Some(ty.clone()), name_span)?;
+        return Ok(Declaration::Typedef(TypedefDeclaration {
+            name: identifier,
+            ty,
+            span: spanning(start, end),
+        }));
+    }
+    lexer.declare(&identifier, None, name_span)?;
+
+    let Type::Function {
+        params,
+        ret,
+        variadic,
+    } = ty
+    else {
+        let init = if lexer.expect(Token::Equals).is_ok() {
+            Some(parse_initializer(lexer)?)
+        } else {
+            None
+        };
+
+        let (_, end) = lexer.expect(Token::Semicolon)?;
+        return Ok(Declaration::Variable(VariableDeclaration {
+            name: identifier,
+            ty,
+            init,
+            storage,
+            span: spanning(start, end),
+        }));
+    };
+
+    // a function declared with a typedef for its type has no parameter names of its own
+    let typedef_type = names.len() != params.len();
+    let names = match typedef_type {
+        true => (0..params.len())
+            .map(|i| (i.to_string(), name_span))
+            .collect(),
+        false => names,
+    };
 
     let (body, end) = if lexer.peek_kind(Token::LBrace) {
+        if typedef_type {
+            return Err(ParserError::TypedefFunctionDefinition { span: name_span });
+        }
+        // parameters are in scope throughout the body
+        lexer.push_scope();
+        for (name, span) in &names {
+            lexer.declare(name, None, *span)?;
+        }
         let block = parse_block(lexer)?;
+        lexer.pop_scope();
         let span = block.span;
         (Some(block), span)
     } else {
         let (_, span) = lexer.expect(Token::Semicolon)?;
         (None, span)
     };
+    let params = params
+        .into_iter()
+        .zip(names)
+        .map(|(ty, (name, span))| (ty, name, span))
+        .collect();
+
     let span = spanning(start, end);
     Ok(Declaration::Function(FunctionDeclaration {
         identifier,
         params,
-        ret,
+        variadic,
+        ret: *ret,
         body,
         storage,
         span,
     }))
 }
 
+fn parse_struct_declaration(lexer: &mut Lexer) -> Result<StructDeclaration> {
+    let (kind, start) = lexer
+        .expect(Token::Struct)
+        .or_else(|_| lexer.expect(Token::Union))?;
+    let (tag, _) = lexer.expect_identifier()?;
+
+    let members = if lexer.expect(Token::LBrace).is_ok() {
+        let mut members = vec![parse_member(lexer)?];
+        while lexer.expect(Token::RBrace).is_err() {
+            members.push(parse_member(lexer)?);
+        }
+        Some(members)
+    } else {
+        None
+    };
+
+    let (_, end) = lexer.expect(Token::Semicolon)?;
+    Ok(StructDeclaration {
+        ty: match kind {
+            Token::Union => StructType::new_union(tag),
+            _ => StructType::new(tag),
+        },
+        members,
+        span: spanning(start, end),
+    })
+}
+
+fn parse_enum_declaration(lexer: &mut Lexer) -> Result<EnumDeclaration> {
+    let (_, start) = lexer.expect(Token::Enum)?;
+    let tag = lexer.expect_identifier().ok().map(|(tag, _)| tag);
+    lexer.expect(Token::LBrace)?;
+
+    let mut enumerators = vec![];
+    loop {
+        let (name, span) = lexer.expect_identifier()?;
+        let value = match lexer.expect(Token::Equals) {
+            Ok(_) => Some(parse_assignment_expression(lexer)?),
+            Err(_) => None,
+        };
+        lexer.declare(&name, None, span)?;
+        enumerators.push((name, value, span));
+        if lexer.expect(Token::Comma).is_err() || lexer.peek_kind(Token::RBrace) {
+            break;
+        }
+    }
+    lexer.expect(Token::RBrace)?;
+
+    let (_, end) = lexer.expect(Token::Semicolon)?;
+    Ok(EnumDeclaration {
+        tag,
+        enumerators,
+        span: spanning(start, end),
+    })
+}
+
+fn parse_member(lexer: &mut Lexer) -> Result<(Type, String, SourceSpan)> {
+    let base = parse_type_specifiers(lexer)?;
+    let declarator = parse_declarator(lexer)?;
+    let (name, span, ty, _) = process_declarator(declarator, base)?;
+    if matches!(ty, Type::Function { .. }) {
+        return Err(ParserError::FunctionMember { span });
+    }
+    lexer.expect(Token::Semicolon)?;
+    Ok((ty, name, span))
+}
+
+fn parse_initializer(lexer: &mut Lexer) -> Result<Initializer> {
+    let Ok((_, start)) = lexer.expect(Token::LBrace) else {
+        return parse_assignment_expression(lexer).map(Initializer::Single);
+    };
+
+    let mut initializers = vec![parse_initializer(lexer)?];
+    while lexer.expect(Token::Comma).is_ok() {
+        if lexer.peek_kind(Token::RBrace) {
+            break;
+        }
+        initializers.push(parse_initializer(lexer)?);
+    }
+
+    let (_, end) = lexer.expect(Token::RBrace)?;
+    Ok(Initializer::Compound(initializers, spanning(start, end)))
+}
+
+/// Converts a hexadecimal floating constant such as `0x1.8p3`, without its suffix, to the
+/// nearest value with `precision` significant bits, or fewer below `2^min_exp` where the value
+/// becomes subnormal. Ties round to even, as with decimal constants.
+fn hex_float(s: &str, precision: i64, min_exp: i64) -> f64 {
+    let (digits, exponent) = s[2..].split_once(['p', 'P']).unwrap();
+    // exponents this large overflow or underflow anyway
+    let mut exponent = exponent
+        .parse::<i64>()
+        .unwrap_or(if exponent.starts_with('-') {
+            -1 << 20
+        } else {
+            1 << 20
+        })
+        .clamp(-1 << 20, 1 << 20);
+
+    // keep the leading 64 bits, remembering whether any of the rest are set
+    let mut bits = 0u64;
+    let mut sticky = false;
+    let mut fraction = false;
+    for c in digits.chars() {
+        if c == '.' {
+            fraction = true;
+            continue;
+        }
+        let digit = c.to_digit(16).unwrap() as u64;
+        if bits >> 60 == 0 {
+            bits = bits << 4 | digit;
+            exponent -= 4 * fraction as i64;
+        } else {
+            sticky |= digit != 0;
+            exponent += 4 * !fraction as i64;
+        }
+    }
+    if bits == 0 {
+        return 0.0;
+    }
+
+    // the value is `bits * 2^exponent`, with the top bit of `bits` set
+    let shift = bits.leading_zeros();
+    bits <<= shift;
+    exponent -= shift as i64;
+    let top = exponent + 63;
+    let kept = precision - (min_exp - top).max(0);
+    let dropped = (64 - kept).clamp(0, 127) as u32;
+
+    let wide = bits as u128;
+    let mut mantissa = wide >> dropped;
+    let rest = wide & ((1 << dropped) - 1);
+    let half = 1 << (dropped - 1);
+    if rest > half || rest == half && (sticky || mantissa & 1 == 1) {
+        mantissa += 1;
+    }
+
+    // the mantissa fits in `precision` bits, so scaling it by powers of two is exact until the
+    // result overflows
+    let mut value = mantissa as f64;
+    let mut exponent = exponent + dropped as i64;
+    while exponent != 0 {
+        let step = exponent.clamp(-1000, 1000);
+        value *= 2f64.powi(step as i32);
+        exponent -= step;
+    }
+    value
+}
+
+/// Resolves the escape sequences in the body of a character constant or string literal. The
+/// lexer has already checked that every escape sequence is well formed.
+fn unescape(s: &str, span: SourceSpan) -> Result<Vec<u8>> {
+    let mut bytes = s.bytes().peekable();
+    let mut value = vec![];
+    while let Some(b) = bytes.next() {
+        if b != b'\\' {
+            value.push(b);
+            continue;
+        }
+        let escaped = match bytes.next().unwrap() {
+            b'a' => 0x07,
+            b'b' => 0x08,
+            b'f' => 0x0c,
+            b'n' => b'\n',
+            b'r' => b'\r',
+            b't' => b'\t',
+            b'v' => 0x0b,
+            b'x' => {
+                let mut n: u32 = 0;
+                while let Some(d) = bytes.next_if(u8::is_ascii_hexdigit) {
+                    n = n.saturating_mul(16) + (d as char).to_digit(16).unwrap();
+                }
+                u8::try_from(n).map_err(|_| ParserError::EscapeOutOfRange { span })?
+            }
+            d @ b'0'..=b'7' => {
+                let mut n = (d - b'0') as u32;
+                for _ in 0..2 {
+                    match bytes.next_if(|d| (b'0'..=b'7').contains(d)) {
+                        Some(d) => n = n * 8 + (d - b'0') as u32,
+                        None => break,
+                    }
+                }
+                u8::try_from(n).map_err(|_| ParserError::EscapeOutOfRange { span })?
+            }
+            c => c,
+        };
+        value.push(escaped);
+    }
+    Ok(value)
+}
+
 fn parse_optional_expression(lexer: &mut Lexer, end: Token) -> Result<Option<Expression>> {
     if lexer.expect(end).is_ok() {
         return Ok(None);
@@ -357,7 +1010,19 @@ fn parse_optional_expression(lexer: &mut Lexer, end: Token) -> Result<Option<Exp
 
 fn parse_for_init(lexer: &mut Lexer) -> Result<ForInit> {
     if lexer.peek_decl_specifier() {
-        parse_variable_declaration(lexer).map(ForInit::Decl)
+        match parse_declaration(lexer)? {
+            Declaration::Variable(decl) => Ok(ForInit::Decl(decl)),
+            Declaration::Function(decl) => {
+                Err(ParserError::ForInitFunctionDeclaration { span: decl.span })
+            }
+            Declaration::Struct(StructDeclaration { span, .. })
+            | Declaration::Enum(EnumDeclaration { span, .. }) => {
+                Err(ParserError::ForInitTagDeclaration { span })
+            }
+            Declaration::Typedef(TypedefDeclaration { span, .. }) => {
+                Err(ParserError::ForInitTypedefDeclaration { span })
+            }
+        }
     } else {
         parse_optional_expression(lexer, Token::Semicolon).map(ForInit::Expr)
     }
@@ -366,13 +1031,19 @@ fn parse_for_init(lexer: &mut Lexer) -> Result<ForInit> {
 fn parse_statement(lexer: &mut Lexer) -> Result<Statement> {
     match lexer.peek_token() {
         Some((token, _span)) => match token {
-            Token::Return => lexer
-                .expect(Token::Return)
-                .and_then(|_| Ok(Statement::Return(parse_expression(lexer)?)))
-                .and_then(|s| {
-                    lexer.expect(Token::Semicolon)?;
-                    Ok(s)
-                }),
+            Token::Return => {
+                let (_, start) = lexer.expect(Token::Return)?;
+                let expr = if lexer.peek_kind(Token::Semicolon) {
+                    None
+                } else {
+                    Some(parse_expression(lexer)?)
+                };
+                let (_, end) = lexer.expect(Token::Semicolon)?;
+                Ok(Statement::Return {
+                    expr,
+                    span: spanning(start, end),
+                })
+            }
             Token::Semicolon => lexer.expect(Token::Semicolon).map(|_| Statement::Null),
             Token::If => {
                 lexer.expect(Token::If)?;
@@ -489,12 +1160,15 @@ fn parse_statement(lexer: &mut Lexer) -> Result<Statement> {
             Token::For => {
                 lexer.next_token();
                 lexer.expect(Token::LParen)?;
+                // a declaration in the header is scoped to the loop
+                lexer.push_scope();
                 let init = parse_for_init(lexer)?;
                 let condition = parse_optional_expression(lexer, Token::Semicolon)?;
                 let post = parse_optional_expression(lexer, Token::RParen)?;
                 let body = parse_statement(lexer)?;
+                lexer.pop_scope();
                 Ok(Statement::For {
-                    init,
+                    init: Box::new(init),
                     condition,
                     post,
                     body: body.into(),
@@ -521,6 +1195,17 @@ fn parse_expression(lexer: &mut Lexer) -> Result<Expression> {
     .map(|(e, _)| e)
 }
 
+/// Parses an expression that cannot contain a top-level comma operator, for positions where a
+/// comma separates list items, such as function arguments and initializers.
+fn parse_assignment_expression(lexer: &mut Lexer) -> Result<Expression> {
+    parse_expression_bp(
+        lexer,
+        1,
+        lexer.peek_token().map_or((0, 0).into(), |(_, span)| span),
+    )
+    .map(|(e, _)| e)
+}
+
 fn parse_expression_bp(
     lexer: &mut Lexer,
     min_bp: u8,
@@ -543,54 +1228,99 @@ fn parse_expression_bp(
             let mut long = strip('l');
             let mut unsigned = strip('u');
             long |= strip('l');
+            // `long long` is represented as `long`
+            if long {
+                strip('l');
+            }
             unsigned |= strip('u');
 
-            if unsigned {
-                if long {
-                    s.parse().map(Constant::ULong)
-                } else {
-                    s.parse()
-                        .map(Constant::UInt)
-                        .or_else(|_| s.parse().map(Constant::ULong))
-                }
-            } else if long {
-                s.parse().map(Constant::Long)
+            let prefixed = |lower, upper| s.strip_prefix(lower).or_else(|| s.strip_prefix(upper));
+            let (digits, radix) = if let Some(hex) = prefixed("0x", "0X") {
+                (hex, 16)
+            } else if let Some(binary) = prefixed("0b", "0B") {
+                (binary, 2)
+            } else if let Some(octal) = s.strip_prefix('0').filter(|s| !s.is_empty()) {
+                (octal, 8)
             } else {
-                s.parse()
-                    .map(Constant::Int)
-                    .or_else(|_| s.parse().map(Constant::Long))
-            }
-            .map(|constant| Expression::Constant { constant, span })
-            .map_err(|error| ParserError::ConstantOutOfRange { error, span })
-        }
+                (s, 10)
+            };
+            // unlike decimal constants, other bases can have unsigned types without a suffix
+            let decimal = radix == 10;
+            u64::from_str_radix(digits, radix)
+                .and_then(|value| match value {
+                    _ if !long && !unsigned && value <= i32::MAX as u64 => {
+                        Ok(Constant::Int(value as i32))
+                    }
+                    _ if !long && (unsigned || !decimal) && value <= u32::MAX as u64 => {
+                        Ok(Constant::UInt(value as u32))
+                    }
+                    _ if !unsigned && value <= i64::MAX as u64 => Ok(Constant::Long(value as i64)),
+                    _ if unsigned || !decimal => Ok(Constant::ULong(value)),
+                    // too large for long, which gives the error
+                    _ => i64::from_str_radix(digits, radix).map(Constant::Long),
+                })
+                .map(|constant| Expression::Constant { constant, span })
+                .map_err(|error| ParserError::ConstantOutOfRange { error, span })
+        }
         Token::FloatingConstant => {
             let s = lexer.str_at(span);
-            s.parse()
-                .map(Constant::Double)
-                .map(|constant| Expression::Constant { constant, span })
-                .map_err(|error| ParserError::DoubleOutOfRange { error, span })
+            let hex = s.starts_with("0x") || s.starts_with("0X");
+            match s.strip_suffix(['f', 'F']) {
+                Some(s) if hex => Ok(Constant::Float(hex_float(s, 24, -126) as f32)),
+                None if hex => Ok(Constant::Double(hex_float(s, 53, -1022))),
+                Some(s) => s.parse().map(Constant::Float),
+                None => s.parse().map(Constant::Double),
+            }
+            .map(|constant| Expression::Constant { constant, span })
+            .map_err(|error| ParserError::DoubleOutOfRange { error, span })
+        }
+        Token::CharConstant => {
+            let s = lexer.str_at(span);
+            let value = unescape(&s[1..s.len() - 1], span)?;
+            Ok(Expression::Constant {
+                constant: Constant::Int(value[0] as i8 as i32),
+                span,
+            })
+        }
+        Token::StringLiteral => {
+            // adjacent string literals are concatenated
+            let mut value = vec![];
+            let mut end = span;
+            let mut next = Some((token, span));
+            while let Some((_, span)) = next {
+                let s = lexer.str_at(span);
+                value.extend(unescape(&s[1..s.len() - 1], span)?);
+                end = span;
+                next = lexer.tokens.pop_if(|(t, _)| *t == Token::StringLiteral);
+            }
+            Ok(Expression::String {
+                value,
+                span: spanning(span, end),
+            })
         }
         Token::Identifier => Ok(Expression::Var {
             name: lexer.str_at(span).into(),
             span,
         }),
 
-        Token::LParen => {
-            if let Ok(to) = parse_type(lexer) {
-                lexer.expect(Token::RParen)?;
-                let (exp, span) = parse_expression_bp(lexer, 65, span)?;
-                Ok(Expression::Cast {
-                    to,
-                    expr: exp.into(),
-                    span,
-                })
-            } else {
-                parse_expression_bp(lexer, 0, span).and_then(|(e, _)| {
-                    lexer.expect(Token::RParen)?;
-                    Ok(e)
-                })
-            }
+        Token::LParen
+            if lexer
+                .peek_token()
+                .is_some_and(|t| lexer.starts_declaration(t)) =>
+        {
+            let to = parse_type(lexer)?;
+            lexer.expect(Token::RParen)?;
+            let (exp, span) = parse_expression_bp(lexer, 60, span)?;
+            Ok(Expression::Cast {
+                to,
+                expr: exp.into(),
+                span,
+            })
         }
+        Token::LParen => parse_expression_bp(lexer, 0, span).and_then(|(e, _)| {
+            lexer.expect(Token::RParen)?;
+            Ok(e)
+        }),
 
         // Prefix operators
         Token::Hypen if min_bp <= 60 => {
@@ -628,11 +1358,90 @@ fn parse_expression_bp(
                 span,
             })
         }
+        Token::Ampersand if min_bp <= 60 => {
+            parse_expression_bp(lexer, 60, span).map(|(e, span)| Expression::AddrOf {
+                expr: Box::new(e),
+                span,
+            })
+        }
+        Token::Asterisk if min_bp <= 60 => {
+            parse_expression_bp(lexer, 60, span).map(|(e, span)| Expression::Dereference {
+                expr: Box::new(e),
+                span,
+            })
+        }
+        Token::Sizeof
+            if min_bp <= 60
+                && lexer.peek_kind(Token::LParen)
+                && lexer.peek_n(2).is_some_and(|t| lexer.starts_declaration(t)) =>
+        {
+            lexer.expect(Token::LParen)?;
+            let ty = parse_type(lexer)?;
+            let (_, end) = lexer.expect(Token::RParen)?;
+            Ok(Expression::SizeOfType {
+                ty,
+                span: spanning(span, end),
+            })
+        }
+        Token::Sizeof if min_bp <= 60 => {
+            parse_expression_bp(lexer, 60, span).map(|(e, span)| Expression::SizeOf {
+                expr: Box::new(e),
+                span,
+            })
+        }
+
+        // stdarg builtins
+        Token::VaStart => {
+            lexer.expect(Token::LParen)?;
+            let ap = parse_assignment_expression(lexer)?;
+            lexer.expect(Token::Comma)?;
+            parse_assignment_expression(lexer)?;
+            let (_, end) = lexer.expect(Token::RParen)?;
+            Ok(Expression::VaStart {
+                ap: ap.into(),
+                span: spanning(span, end),
+            })
+        }
+        Token::VaArg => {
+            lexer.expect(Token::LParen)?;
+            let ap = parse_assignment_expression(lexer)?;
+            lexer.expect(Token::Comma)?;
+            let ty = parse_type(lexer)?;
+            let (_, end) = lexer.expect(Token::RParen)?;
+            Ok(Expression::VaArg {
+                ap: ap.into(),
+                ty,
+                span: spanning(span, end),
+            })
+        }
+        Token::VaCopy => {
+            lexer.expect(Token::LParen)?;
+            let dst = parse_assignment_expression(lexer)?;
+            lexer.expect(Token::Comma)?;
+            let src = parse_assignment_expression(lexer)?;
+            let (_, end) = lexer.expect(Token::RParen)?;
+            Ok(Expression::VaCopy {
+                dst: dst.into(),
+                src: src.into(),
+                span: spanning(span, end),
+            })
+        }
+        Token::VaEnd => {
+            lexer.expect(Token::LParen)?;
+            let ap = parse_assignment_expression(lexer)?;
+            let (_, end) = lexer.expect(Token::RParen)?;
+            Ok(Expression::VaEnd {
+                ap: ap.into(),
+                span: spanning(span, end),
+            })
+        }
 
         kind => {
             return Err(ParserError::Expected {
                 options: vec![
                     Token::Constant,
+                    Token::CharConstant,
+                    Token::StringLiteral,
                     Token::Identifier,
                     Token::LParen,
                     Token::Hypen,
@@ -640,6 +1449,9 @@ fn parse_expression_bp(
                     Token::Exclamation,
                     Token::PlusPlus,
                     Token::MinusMinus,
+                    Token::Ampersand,
+                    Token::Asterisk,
+                    Token::Sizeof,
                 ],
                 kind,
                 span,
@@ -648,7 +1460,7 @@ fn parse_expression_bp(
     }?;
 
     loop {
-        let Some((next, next_span)) = lexer.peek_token() else {
+        let Some((next, _)) = lexer.peek_token() else {
             break;
         };
         let (op, r_bp) = match next {
@@ -732,6 +1544,7 @@ fn parse_expression_bp(
                 continue;
             }
             // Binary
+            Token::Comma => (BinaryOperator::Comma, 0),
             Token::DoublePipe => (BinaryOperator::Or, 5),
             Token::DoubleAnd => (BinaryOperator::And, 10),
             Token::Pipe => (BinaryOperator::BitwiseOr, 25),
@@ -750,23 +1563,40 @@ fn parse_expression_bp(
             Token::Asterisk => (BinaryOperator::Multiply, 50),
             Token::FSlash => (BinaryOperator::Divide, 50),
             Token::Percent => (BinaryOperator::Remainder, 50),
+            // Member access
+            Token::Dot | Token::Arrow if min_bp <= 65 => {
+                lexer.next_token();
+                let (member, end) = lexer.expect_identifier()?;
+                let expr = Box::new(lhs);
+                let span = spanning(span, end);
+                lhs = if next == Token::Dot {
+                    Expression::Dot { expr, member, span }
+                } else {
+                    Expression::Arrow { expr, member, span }
+                };
+                continue;
+            }
             // Indexing / Call
+            Token::LBracket if min_bp <= 65 => {
+                lexer.next_token();
+                let index = parse_expression(lexer)?;
+                let (_, end) = lexer.expect(Token::RBracket)?;
+                lhs = Expression::Subscript {
+                    array: lhs.into(),
+                    index: index.into(),
+                    span: spanning(span, end),
+                };
+                continue;
+            }
             Token::LParen => {
-                if !matches!(lhs, Expression::Var { .. }) {
-                    return Err(ParserError::Expected {
-                        options: vec![Token::Identifier],
-                        kind: Token::Identifier,
-                        span: next_span,
-                    });
-                }
                 lhs = {
                     lexer.expect(Token::LParen)?;
                     let mut params = vec![];
                     if lexer.peek_kind(Token::RParen) {
                     } else {
-                        params.push(parse_expression(lexer)?);
+                        params.push(parse_assignment_expression(lexer)?);
                         while lexer.expect(Token::Comma).is_ok() {
-                            params.push(parse_expression(lexer)?);
+                            params.push(parse_assignment_expression(lexer)?);
                         }
                     }
                     let (_, end) = lexer.expect(Token::RParen)?;
@@ -864,4 +1694,1015 @@ mod tests {
         "#);
         Ok(())
     }
+
+    #[test]
+    fn test_pointer_declarators() -> miette::Result<()> {
+        let src = "int *f(long **p); int main(void) { int x; int *y = &x; *y = (int *)0 == y; }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [
+                        (
+                            Pointer(
+                                Pointer(
+                                    Long,
+                                ),
+                            ),
+                            "p",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    14,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    ret: Pointer(
+                        Int,
+                    ),
+                },
+                FunctionDeclaration {
+                    identifier: "main",
+                    params: [],
+                    ret: Int,
+                    body: [
+                        VariableDeclaration {
+                            name: "x",
+                            ty: Int,
+                        },
+                        VariableDeclaration {
+                            name: "y",
+                            ty: Pointer(
+                                Int,
+                            ),
+                            init: AddrOf(
+                                Var("x"),
+                            ),
+                        },
+                        Expression(
+                            Assignment {
+                                lhs: Dereference(
+                                    Var("y"),
+                                ),
+                                rhs: Binary {
+                                    op: Equals,
+                                    lhs: Cast {
+                                        to: Pointer(
+                                            Int,
+                                        ),
+                                        expr: Constant(0),
+                                    },
+                                    rhs: Var("y"),
+                                },
+                            },
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_declarators() -> miette::Result<()> {
+        let src = "int a[2][3] = {{1, 2}, {3}}; int f(int x[4]) { return a[1][0] + x[2]; }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                VariableDeclaration {
+                    name: "a",
+                    ty: Array {
+                        element: Array {
+                            element: Int,
+                            size: 3,
+                        },
+                        size: 2,
+                    },
+                    init: Compound(
+                        [
+                            Compound(
+                                [
+                                    Constant(1),
+                                    Constant(2),
+                                ],
+                            ),
+                            Compound(
+                                [
+                                    Constant(3),
+                                ],
+                            ),
+                        ],
+                    ),
+                },
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [
+                        (
+                            Pointer(
+                                Int,
+                            ),
+                            "x",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    39,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    ret: Int,
+                    body: [
+                        Return(
+                            Binary {
+                                op: Add,
+                                lhs: Subscript {
+                                    array: Subscript {
+                                        array: Var("a"),
+                                        index: Constant(1),
+                                    },
+                                    index: Constant(0),
+                                },
+                                rhs: Subscript {
+                                    array: Var("x"),
+                                    index: Constant(2),
+                                },
+                            },
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_and_string_literals() -> miette::Result<()> {
+        let src =
+            r#"unsigned char c = 'a'; char s[] = "a\tb" "\x41\101"; int f(void) { return '\n'; }"#;
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                VariableDeclaration {
+                    name: "c",
+                    ty: UChar,
+                    init: Constant(97),
+                },
+                VariableDeclaration {
+                    name: "s",
+                    ty: Array {
+                        element: Char,
+                        size: 0,
+                    },
+                    init: String("a\tbAA"),
+                },
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [],
+                    ret: Int,
+                    body: [
+                        Return(
+                            Constant(10),
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sizeof_and_void() -> miette::Result<()> {
+        let src = "void f(void *p) { (void) sizeof p; return; } long g(void) { return sizeof(int *[2]) + sizeof 1; }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [
+                        (
+                            Pointer(
+                                Void,
+                            ),
+                            "p",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    13,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    ret: Void,
+                    body: [
+                        Expression(
+                            Cast {
+                                to: Void,
+                                expr: SizeOf(
+                                    Var("p"),
+                                ),
+                            },
+                        ),
+                        Return,
+                    ],
+                },
+                FunctionDeclaration {
+                    identifier: "g",
+                    params: [],
+                    ret: Long,
+                    body: [
+                        Return(
+                            Binary {
+                                op: Add,
+                                lhs: SizeOfType(
+                                    Array {
+                                        element: Pointer(
+                                            Int,
+                                        ),
+                                        size: 2,
+                                    },
+                                ),
+                                rhs: SizeOf(
+                                    Constant(1),
+                                ),
+                            },
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_declarations() -> miette::Result<()> {
+        let src = "struct s; struct s { int a; struct s *next; }; int f(struct s *p) { struct s x; return p->next->a + x.a; }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                StructDeclaration {
+                    tag: "s",
+                },
+                StructDeclaration {
+                    tag: "s",
+                    members: [
+                        (
+                            Int,
+                            "a",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    25,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                        (
+                            Pointer(
+                                Struct(
+                                    "s",
+                                ),
+                            ),
+                            "next",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    38,
+                                ),
+                                length: 4,
+                            },
+                        ),
+                    ],
+                },
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [
+                        (
+                            Pointer(
+                                Struct(
+                                    "s",
+                                ),
+                            ),
+                            "p",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    63,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    ret: Int,
+                    body: [
+                        VariableDeclaration {
+                            name: "x",
+                            ty: Struct(
+                                "s",
+                            ),
+                        },
+                        Return(
+                            Binary {
+                                op: Add,
+                                lhs: Arrow {
+                                    expr: Arrow {
+                                        expr: Var("p"),
+                                        member: "next",
+                                    },
+                                    member: "a",
+                                },
+                                rhs: Dot {
+                                    expr: Var("x"),
+                                    member: "a",
+                                },
+                            },
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_and_enum_declarations() -> miette::Result<()> {
+        let src = "union u { char c; long l; }; enum e { A, B = -2, }; int main(void) { union u x; return B; }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                UnionDeclaration {
+                    tag: "u",
+                    members: [
+                        (
+                            Char,
+                            "c",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    15,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                        (
+                            Long,
+                            "l",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    23,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                },
+                EnumDeclaration {
+                    tag: "e",
+                    enumerators: [
+                        (
+                            "A",
+                            None,
+                        ),
+                        (
+                            "B",
+                            Some(
+                                Unary {
+                                    op: Minus,
+                                    expr: Constant(2),
+                                },
+                            ),
+                        ),
+                    ],
+                },
+                FunctionDeclaration {
+                    identifier: "main",
+                    params: [],
+                    ret: Int,
+                    body: [
+                        VariableDeclaration {
+                            name: "x",
+                            ty: Struct(
+                                union "u",
+                            ),
+                        },
+                        Return(
+                            Var("B"),
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_typedef_names() -> miette::Result<()> {
+        let src = "typedef long T; int f(T x) { T y = (T)x; { int T = 1; return (T) + y; } }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                TypedefDeclaration {
+                    name: "T",
+                    ty: Long,
+                },
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [
+                        (
+                            Long,
+                            "x",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    24,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    ret: Int,
+                    body: [
+                        VariableDeclaration {
+                            name: "y",
+                            ty: Long,
+                            init: Cast {
+                                to: Long,
+                                expr: Var("x"),
+                            },
+                        },
+                        Compound(
+                            [
+                                VariableDeclaration {
+                                    name: "T",
+                                    ty: Int,
+                                    init: Constant(1),
+                                },
+                                Return(
+                                    Binary {
+                                        op: Add,
+                                        lhs: Var("T"),
+                                        rhs: Var("y"),
+                                    },
+                                ),
+                            ],
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_function_pointers() -> miette::Result<()> {
+        let src = "int (*fp)(int, char *); int apply(int (*f)(int), int g(void)) { return f(g()) + (*fp)(1, (char *)0) + (int)sizeof(long (*)(void)); }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                VariableDeclaration {
+                    name: "fp",
+                    ty: Pointer(
+                        Function {
+                            params: [
+                                Int,
+                                Pointer(
+                                    Char,
+                                ),
+                            ],
+                            ret: Int,
+                            variadic: false,
+                        },
+                    ),
+                },
+                FunctionDeclaration {
+                    identifier: "apply",
+                    params: [
+                        (
+                            Pointer(
+                                Function {
+                                    params: [
+                                        Int,
+                                    ],
+                                    ret: Int,
+                                    variadic: false,
+                                },
+                            ),
+                            "f",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    40,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                        (
+                            Pointer(
+                                Function {
+                                    params: [],
+                                    ret: Int,
+                                    variadic: false,
+                                },
+                            ),
+                            "g",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    53,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    ret: Int,
+                    body: [
+                        Return(
+                            Binary {
+                                op: Add,
+                                lhs: Binary {
+                                    op: Add,
+                                    lhs: FunctionCall {
+                                        function: Var("f"),
+                                        params: [
+                                            FunctionCall {
+                                                function: Var("g"),
+                                            },
+                                        ],
+                                    },
+                                    rhs: FunctionCall {
+                                        function: Dereference(
+                                            Var("fp"),
+                                        ),
+                                        params: [
+                                            Constant(1),
+                                            Cast {
+                                                to: Pointer(
+                                                    Char,
+                                                ),
+                                                expr: Constant(0),
+                                            },
+                                        ],
+                                    },
+                                },
+                                rhs: Cast {
+                                    to: Int,
+                                    expr: SizeOfType(
+                                        Pointer(
+                                            Function {
+                                                params: [],
+                                                ret: Long,
+                                                variadic: false,
+                                            },
+                                        ),
+                                    ),
+                                },
+                            },
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_variadic_prototypes() -> miette::Result<()> {
+        let src = "int printf(char *format, ...); int (*log)(int level, char *, ...);";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                FunctionDeclaration {
+                    identifier: "printf",
+                    params: [
+                        (
+                            Pointer(
+                                Char,
+                            ),
+                            "format",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    17,
+                                ),
+                                length: 6,
+                            },
+                        ),
+                    ],
+                    variadic: true,
+                    ret: Int,
+                },
+                VariableDeclaration {
+                    name: "log",
+                    ty: Pointer(
+                        Function {
+                            params: [
+                                Int,
+                                Pointer(
+                                    Char,
+                                ),
+                            ],
+                            ret: Int,
+                            variadic: true,
+                        },
+                    ),
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stdarg_builtins() -> miette::Result<()> {
+        let src = "double f(int n, ...) { __builtin_va_list ap; __builtin_va_list copy; __builtin_va_start(ap, n); __builtin_va_copy(copy, ap); double d = __builtin_va_arg(ap, double); __builtin_va_end(ap); return d; }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [
+                        (
+                            Int,
+                            "n",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    13,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    variadic: true,
+                    ret: Double,
+                    body: [
+                        VariableDeclaration {
+                            name: "ap",
+                            ty: Array {
+                                element: Struct(
+                                    "__va_list_tag",
+                                ),
+                                size: 1,
+                            },
+                        },
+                        VariableDeclaration {
+                            name: "copy",
+                            ty: Array {
+                                element: Struct(
+                                    "__va_list_tag",
+                                ),
+                                size: 1,
+                            },
+                        },
+                        Expression(
+                            VaStart(
+                                Var("ap"),
+                            ),
+                        ),
+                        Expression(
+                            VaCopy {
+                                dst: Var("copy"),
+                                src: Var("ap"),
+                            },
+                        ),
+                        VariableDeclaration {
+                            name: "d",
+                            ty: Double,
+                            init: VaArg {
+                                ap: Var("ap"),
+                                ty: Double,
+                            },
+                        },
+                        Expression(
+                            VaEnd(
+                                Var("ap"),
+                            ),
+                        ),
+                        Return(
+                            Var("d"),
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stdarg_names_are_identifiers() -> miette::Result<()> {
+        let src = "int va_start(int va_list) { int va_arg = va_list; return va_arg; }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                FunctionDeclaration {
+                    identifier: "va_start",
+                    params: [
+                        (
+                            Int,
+                            "va_list",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    17,
+                                ),
+                                length: 7,
+                            },
+                        ),
+                    ],
+                    ret: Int,
+                    body: [
+                        VariableDeclaration {
+                            name: "va_arg",
+                            ty: Int,
+                            init: Var("va_list"),
+                        },
+                        Return(
+                            Var("va_arg"),
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_float_declarations() -> miette::Result<()> {
+        let src = "float f(float x) { double d = x * 2.5f; return d + 1.0F; }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [
+                        (
+                            Float,
+                            "x",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    14,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    ret: Float,
+                    body: [
+                        VariableDeclaration {
+                            name: "d",
+                            ty: Double,
+                            init: Binary {
+                                op: Multiply,
+                                lhs: Var("x"),
+                                rhs: Constant(2.5f),
+                            },
+                        },
+                        Return(
+                            Binary {
+                                op: Add,
+                                lhs: Var("d"),
+                                rhs: Constant(1.0f),
+                            },
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_integer_types() -> miette::Result<()> {
+        let src = "_Bool f(unsigned short s, long long l) { short int t = s; return t + 1ull; }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [
+                        (
+                            UShort,
+                            "s",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    23,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                        (
+                            Long,
+                            "l",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    36,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    ret: Bool,
+                    body: [
+                        VariableDeclaration {
+                            name: "t",
+                            ty: Short,
+                            init: Var("s"),
+                        },
+                        Return(
+                            Binary {
+                                op: Add,
+                                lhs: Var("t"),
+                                rhs: Constant(1ul),
+                            },
+                        ),
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_qualifiers() -> miette::Result<()> {
+        let src = "const volatile int x; char *const restrict p; const char *f(int *volatile q);";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                VariableDeclaration {
+                    name: "x",
+                    ty: Qualified(
+                        Int,
+                        const volatile,
+                    ),
+                },
+                VariableDeclaration {
+                    name: "p",
+                    ty: Qualified(
+                        Pointer(
+                            Char,
+                        ),
+                        const restrict,
+                    ),
+                },
+                FunctionDeclaration {
+                    identifier: "f",
+                    params: [
+                        (
+                            Qualified(
+                                Pointer(
+                                    Int,
+                                ),
+                                volatile,
+                            ),
+                            "q",
+                            SourceSpan {
+                                offset: SourceOffset(
+                                    74,
+                                ),
+                                length: 1,
+                            },
+                        ),
+                    ],
+                    ret: Pointer(
+                        Qualified(
+                            Char,
+                            const,
+                        ),
+                    ),
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_number_bases() -> miette::Result<()> {
+        let src = "long x[] = {0x1F, 017, 0b1010, 0xFFFFFFFF, 4294967295, 0x8000000000000000, 0x10L, 0x1.8p3, 0x.4p0f};";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                VariableDeclaration {
+                    name: "x",
+                    ty: Array {
+                        element: Long,
+                        size: 0,
+                    },
+                    init: Compound(
+                        [
+                            Constant(31),
+                            Constant(15),
+                            Constant(10),
+                            Constant(4294967295u),
+                            Constant(4294967295l),
+                            Constant(9223372036854775808ul),
+                            Constant(16l),
+                            Constant(12.0),
+                            Constant(0.25f),
+                        ],
+                    ),
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_comma_operator() -> miette::Result<()> {
+        let src = "int main(void) { for (i = 0, j = 1; i < j; i++, j--) f((a, b), c); }";
+        let tokens = lex(src, "example.c")?;
+        let program = parse(src, tokens, "example.c")?;
+        insta::assert_debug_snapshot!(program, @r#"
+        Program {
+            declarations: [
+                FunctionDeclaration {
+                    identifier: "main",
+                    params: [],
+                    ret: Int,
+                    body: [
+                        For {
+                            init: Some(
+                                Binary {
+                                    op: Comma,
+                                    lhs: Assignment {
+                                        lhs: Var("i"),
+                                        rhs: Constant(0),
+                                    },
+                                    rhs: Assignment {
+                                        lhs: Var("j"),
+                                        rhs: Constant(1),
+                                    },
+                                },
+                            ),
+                            condition: Binary {
+                                op: LessThan,
+                                lhs: Var("i"),
+                                rhs: Var("j"),
+                            },
+                            post: Binary {
+                                op: Comma,
+                                lhs: Unary {
+                                    op: PostfixIncrement,
+                                    expr: Var("i"),
+                                },
+                                rhs: Unary {
+                                    op: PostfixDecrement,
+                                    expr: Var("j"),
+                                },
+                            },
+                            body: Expression(
+                                FunctionCall {
+                                    function: Var("f"),
+                                    params: [
+                                        Binary {
+                                            op: Comma,
+                                            lhs: Var("a"),
+                                            rhs: Var("b"),
+                                        },
+                                        Var("c"),
+                                    ],
+                                },
+                            ),
+                        },
+                    ],
+                },
+            ],
+        }
+        "#);
+        Ok(())
+    }
 }
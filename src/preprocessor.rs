@@ -0,0 +1,1731 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap, HashSet, VecDeque},
+    rc::Rc,
+};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use miette::{Diagnostic, IntoDiagnostic, NamedSource, Result, SourceSpan};
+
+#[derive(Debug, Diagnostic, thiserror::Error)]
+enum PreprocessorError {
+    #[error("unterminated comment")]
+    UnterminatedComment {
+        #[label("comment starts here")]
+        span: SourceSpan,
+    },
+    #[error("invalid preprocessing directive #{name}")]
+    UnknownDirective {
+        name: String,
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("macro names must be identifiers")]
+    MacroName {
+        #[label("expected an identifier")]
+        span: SourceSpan,
+    },
+    #[error("invalid macro parameter list")]
+    MacroParameters {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("'#' is not followed by a macro parameter")]
+    StringifyNonParameter {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("'##' cannot appear at either end of a macro expansion")]
+    PasteAtEdge {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("pasting \"{lhs}\" and \"{rhs}\" does not give a valid preprocessing token")]
+    InvalidPaste {
+        lhs: String,
+        rhs: String,
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("unterminated argument list invoking macro \"{name}\"")]
+    UnterminatedArguments {
+        name: String,
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("macro \"{name}\" requires {expected} arguments, but {actual} given")]
+    ArgumentCount {
+        name: String,
+        expected: usize,
+        actual: usize,
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("#include expects \"FILENAME\" or <FILENAME>")]
+    IncludeSyntax {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("{name}: no such file or directory")]
+    IncludeNotFound {
+        name: String,
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("#include nested too deeply")]
+    IncludeDepth {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("#{directive} without #if")]
+    UnmatchedConditional {
+        directive: String,
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("#{directive} after #else")]
+    AfterElse {
+        directive: String,
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("unterminated conditional directive")]
+    UnterminatedConditional {
+        #[label("this #if has no #endif")]
+        span: SourceSpan,
+    },
+    #[error("invalid expression in #if")]
+    InvalidExpression {
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("division by zero in #if")]
+    DivisionByZero {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("floating constant in preprocessor expression")]
+    FloatingConstant {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("#error {message}")]
+    Error {
+        message: String,
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("#warning {message}")]
+    #[diagnostic(severity(Warning))]
+    Warning {
+        message: String,
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("#line expects a line number from 1 to 2147483647 and an optional file name")]
+    LineSyntax {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("#{directive} expects a string literal")]
+    IdentSyntax {
+        directive: String,
+        #[label]
+        span: SourceSpan,
+    },
+}
+
+/// The headers that come with the compiler rather than the C library.
+const BUILTIN_HEADERS: [(&str, &str); 3] = [
+    (
+        "stddef.h",
+        "#pragma once
+typedef unsigned long size_t;
+typedef long ptrdiff_t;
+typedef int wchar_t;
+#define NULL ((void *)0)
+#define offsetof(type, member) ((size_t)&((type *)0)->member)
+",
+    ),
+    (
+        "stdarg.h",
+        "#pragma once
+typedef __builtin_va_list __gnuc_va_list;
+typedef __builtin_va_list va_list;
+#define va_start(ap, last) __builtin_va_start(ap, last)
+#define va_arg(ap, type) __builtin_va_arg(ap, type)
+#define va_copy(dst, src) __builtin_va_copy(dst, src)
+#define va_end(ap) __builtin_va_end(ap)
+",
+    ),
+    (
+        "stdbool.h",
+        "#pragma once
+#define bool _Bool
+#define true 1
+#define false 0
+#define __bool_true_false_are_defined 1
+",
+    ),
+];
+
+/// How deeply `#include` may nest before we assume it recurses forever.
+const MAX_INCLUDE_DEPTH: usize = 200;
+
+const PREDEFINED_MACROS: [(&str, &str); 5] = [
+    ("__STDC__", "1"),
+    ("__STDC_HOSTED__", "1"),
+    ("__STDC_VERSION__", "201710L"),
+    ("__x86_64__", "1"),
+    ("__LP64__", "1"),
+];
+
+/// Multi-character punctuators, longest first so that the first match is the longest one.
+const PUNCTUATORS: [&str; 23] = [
+    "...", "<<=", ">>=", "->", "++", "--", "<<", ">>", "<=", ">=", "==", "!=", "&&", "||", "*=",
+    "/=", "%=", "+=", "-=", "&=", "^=", "|=", "##",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Identifier,
+    Number,
+    Character,
+    String,
+    Punctuator,
+    /// A stray character, or a quote without its closing quote, left for the lexer to reject.
+    Other,
+    /// Stands in for an empty macro argument next to `##`.
+    Placemarker,
+}
+
+#[derive(Debug, Clone)]
+struct PpToken {
+    kind: Kind,
+    text: String,
+    span: SourceSpan,
+    line: usize,
+    /// The byte offset of the token from the start of its line, which keeps the indentation
+    /// of the source in the output.
+    column: usize,
+    /// Whether the token is the first on its line, which lets a `#` start a directive.
+    bol: bool,
+    /// Whether whitespace precedes the token.
+    space: bool,
+    /// The macros whose expansion produced this token, which must not expand it again.
+    hideset: BTreeSet<String>,
+}
+
+impl PpToken {
+    fn is(&self, punctuator: &str) -> bool {
+        self.kind == Kind::Punctuator && self.text == punctuator
+    }
+
+    fn is_identifier(&self, name: &str) -> bool {
+        self.kind == Kind::Identifier && self.text == name
+    }
+}
+
+/// Splits a file into preprocessing tokens, dropping comments and line splices.
+fn tokenize(text: &str) -> Result<Vec<PpToken>, PreprocessorError> {
+    let bytes = text.as_bytes();
+    let mut tokens = vec![];
+    let (mut i, mut line, mut bol, mut space) = (0, 1, true, false);
+    let mut line_start = 0;
+    while i < bytes.len() {
+        let rest = &text[i..];
+        let c = bytes[i];
+        if c == b'\n' {
+            (i, line, bol, space) = (i + 1, line + 1, true, false);
+            line_start = i;
+            continue;
+        }
+        if c.is_ascii_whitespace() {
+            (i, space) = (i + 1, true);
+            continue;
+        }
+        if rest.starts_with("\\\n") || rest.starts_with("\\\r\n") {
+            i += if bytes[i + 1] == b'\n' { 2 } else { 3 };
+            line += 1;
+            line_start = i;
+            continue;
+        }
+        if rest.starts_with("//") {
+            // a line comment ends at the first newline that is not spliced
+            while i < bytes.len() && bytes[i] != b'\n' {
+                if bytes[i] == b'\\' && bytes.get(i + 1) == Some(&b'\n') {
+                    line += 1;
+                    i += 1;
+                }
+                i += 1;
+            }
+            space = true;
+            continue;
+        }
+        if let Some(comment) = rest.strip_prefix("/*") {
+            let Some(end) = comment.find("*/") else {
+                return Err(PreprocessorError::UnterminatedComment {
+                    span: (i, 2).into(),
+                });
+            };
+            line += comment[..end].matches('\n').count();
+            if let Some(newline) = comment[..end].rfind('\n') {
+                line_start = i + 2 + newline + 1;
+            }
+            (i, space) = (i + end + 4, true);
+            continue;
+        }
+
+        let start = i;
+        // the encoding prefix of a wide or unicode literal
+        let prefix = ["u8", "L", "u", "U"]
+            .into_iter()
+            .find(|p| rest.starts_with(p) && matches!(bytes.get(i + p.len()), Some(b'"' | b'\'')))
+            .map_or(0, str::len);
+        let kind = if let quote @ (b'"' | b'\'') = bytes[i + prefix] {
+            i += prefix + 1;
+            let mut terminated = false;
+            while i < bytes.len() && bytes[i] != b'\n' {
+                match bytes[i] {
+                    b'\\' => {
+                        if bytes.get(i + 1) == Some(&b'\n') {
+                            line += 1;
+                        }
+                        i += 2;
+                    }
+                    c if c == quote => {
+                        i += 1;
+                        terminated = true;
+                        break;
+                    }
+                    _ => i += 1,
+                }
+            }
+            i = i.min(bytes.len());
+            match (terminated, quote) {
+                (true, b'"') => Kind::String,
+                (true, _) => Kind::Character,
+                (false, _) => Kind::Other,
+            }
+        } else if c.is_ascii_alphabetic() || c == b'_' || c == b'$' {
+            i += rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+                .unwrap_or(rest.len());
+            Kind::Identifier
+        } else if c.is_ascii_digit()
+            || c == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+        {
+            i += 1;
+            while let Some(&c) = bytes.get(i) {
+                match c {
+                    b'e' | b'E' | b'p' | b'P' if matches!(bytes.get(i + 1), Some(b'+' | b'-')) => {
+                        i += 2
+                    }
+                    c if c.is_ascii_alphanumeric() || c == b'_' || c == b'.' => i += 1,
+                    _ => break,
+                }
+            }
+            Kind::Number
+        } else if let Some(punctuator) = PUNCTUATORS.iter().find(|p| rest.starts_with(**p)) {
+            i += punctuator.len();
+            Kind::Punctuator
+        } else {
+            let c = rest.chars().next().unwrap();
+            i += c.len_utf8();
+            if c.is_ascii_punctuation() {
+                Kind::Punctuator
+            } else {
+                Kind::Other
+            }
+        };
+        tokens.push(PpToken {
+            kind,
+            text: text[start..i].to_string(),
+            span: (start, i - start).into(),
+            line,
+            column: start - line_start,
+            bol,
+            space,
+            hideset: BTreeSet::new(),
+        });
+        (bol, space) = (false, false);
+    }
+    Ok(tokens)
+}
+
+enum Macro {
+    Object(Vec<PpToken>),
+    Function {
+        params: Vec<String>,
+        variadic: bool,
+        body: Vec<PpToken>,
+    },
+    Line,
+    File,
+}
+
+struct SourceFile {
+    path: Utf8PathBuf,
+    text: String,
+    /// The line of the `#include` of this file in the one that includes it.
+    included_at: Option<usize>,
+    /// The name that `#line` gave the file, if any.
+    name: Option<String>,
+    /// What `#line` adds to the physical line numbers of the file.
+    line_offset: isize,
+    /// The position in the search path of the directory the file was found in, where
+    /// `#include_next` carries on searching from.
+    found_in: Option<usize>,
+}
+
+impl SourceFile {
+    /// The name of the file for `__FILE__` and line markers.
+    fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(self.path.as_str())
+    }
+
+    /// The line that physical line `line` of the file is presumed to be, after `#line`.
+    fn line(&self, line: usize) -> usize {
+        line.saturating_add_signed(self.line_offset)
+    }
+}
+
+/// The state of one `#if` ... `#endif` group.
+struct Conditional {
+    /// Whether the lines of the current branch are kept.
+    active: bool,
+    /// Whether a branch has already been kept, or the whole group is being skipped, so that
+    /// the remaining branches are not.
+    taken: bool,
+    after_else: bool,
+    span: SourceSpan,
+}
+
+struct Preprocessor<'a> {
+    include_dirs: &'a [Utf8PathBuf],
+    system_include_dirs: &'a [Utf8PathBuf],
+    macros: HashMap<String, Rc<Macro>>,
+    /// Files that contained `#pragma once`.
+    once: HashSet<Utf8PathBuf>,
+    /// The stack of files being included.
+    files: Vec<SourceFile>,
+    output: String,
+    /// The line of the current file that the line being written to the output comes from.
+    line: usize,
+    /// The text of the last token written to the output.
+    last: String,
+    /// The diagnostics that did not stop preprocessing, from `#warning`.
+    warnings: Vec<miette::Report>,
+}
+
+/// Preprocesses `text`, the contents of the file at `path`, searching `include_dirs`, then the
+/// compiler's own headers, then `system_include_dirs` for headers. Returns the text of the
+/// translation unit along with any warnings.
+pub fn preprocess(
+    path: &Utf8Path,
+    text: String,
+    include_dirs: &[Utf8PathBuf],
+    system_include_dirs: &[Utf8PathBuf],
+) -> Result<(String, Vec<miette::Report>)> {
+    let mut preprocessor = Preprocessor::new(include_dirs, system_include_dirs);
+    preprocessor.run(path.to_owned(), text, None, None)?;
+    Ok((preprocessor.output, preprocessor.warnings))
+}
+
+/// Wraps `report` to say which `#include`s led to the file it points into, given the files
+/// and lines of the `#include`s, innermost first.
+pub fn included_from<'a>(
+    report: miette::Report,
+    chain: impl IntoIterator<Item = (&'a str, usize)>,
+) -> miette::Report {
+    let chain: Vec<_> = chain
+        .into_iter()
+        .map(|(file, line)| format!("{file}:{line}"))
+        .collect();
+    if chain.is_empty() {
+        return report;
+    }
+    report.wrap_err(format!("in file included from {}", chain.join(", from ")))
+}
+
+impl<'a> Preprocessor<'a> {
+    fn new(include_dirs: &'a [Utf8PathBuf], system_include_dirs: &'a [Utf8PathBuf]) -> Self {
+        let mut macros = HashMap::from([
+            ("__LINE__".to_string(), Rc::new(Macro::Line)),
+            ("__FILE__".to_string(), Rc::new(Macro::File)),
+        ]);
+        for (name, value) in PREDEFINED_MACROS {
+            let body = tokenize(value).unwrap();
+            macros.insert(name.to_string(), Rc::new(Macro::Object(body)));
+        }
+        Self {
+            include_dirs,
+            system_include_dirs,
+            macros,
+            once: HashSet::new(),
+            files: vec![],
+            output: String::new(),
+            line: 1,
+            last: String::new(),
+            warnings: vec![],
+        }
+    }
+
+    fn error(&self, error: PreprocessorError) -> miette::Report {
+        let file = self.files.last().unwrap();
+        let report = miette::Report::from(error)
+            .with_source_code(NamedSource::new(file.path.as_str(), file.text.clone()));
+        let chain = self.files.windows(2).rev();
+        included_from(
+            report,
+            chain.map(|files| (files[0].name(), files[1].included_at.unwrap())),
+        )
+    }
+
+    fn run(
+        &mut self,
+        path: Utf8PathBuf,
+        text: String,
+        included_at: Option<usize>,
+        found_in: Option<usize>,
+    ) -> Result<()> {
+        let tokens = tokenize(&text);
+        self.files.push(SourceFile {
+            path,
+            text,
+            included_at,
+            name: None,
+            line_offset: 0,
+            found_in,
+        });
+        self.mark(1, included_at.map(|_| 1));
+        let mut input = VecDeque::from(tokens.map_err(|e| self.error(e))?);
+        let mut conditionals = vec![];
+        while let Some(token) = input.front() {
+            if token.bol && token.is("#") {
+                let hash = input.pop_front().unwrap();
+                let mut line = vec![];
+                while input.front().is_some_and(|t| !t.bol) {
+                    line.push(input.pop_front().unwrap());
+                }
+                self.directive(&hash, &line, &mut conditionals)?;
+            } else if conditionals.last().is_some_and(|c: &Conditional| !c.active) {
+                input.pop_front();
+            } else {
+                // the expansion of a macro at the start of a line starts the line
+                let bol = token.bol;
+                if let Some(mut token) = self.next_expanded(&mut input)? {
+                    token.bol |= bol;
+                    self.emit(token);
+                }
+            }
+        }
+        if let Some(conditional) = conditionals.last() {
+            return Err(self.error(PreprocessorError::UnterminatedConditional {
+                span: conditional.span,
+            }));
+        }
+        if !self.output.ends_with('\n') {
+            self.output.push('\n');
+        }
+        self.files.pop();
+        if let Some(line) = included_at {
+            self.mark(line + 1, Some(2));
+        }
+        Ok(())
+    }
+
+    /// Writes a line marker, which says that the next line of the output comes from `line` of
+    /// the current file. As in GCC, flag 1 means the file has just been included, and flag 2
+    /// that it has just been returned to.
+    fn mark(&mut self, line: usize, flag: Option<u8>) {
+        if !self.output.is_empty() && !self.output.ends_with('\n') {
+            self.output.push('\n');
+        }
+        let path = string_literal(self.files.last().unwrap().name());
+        self.output.push_str(&format!("# {line} {path}"));
+        if let Some(flag) = flag {
+            self.output.push_str(&format!(" {flag}"));
+        }
+        self.output.push('\n');
+        self.line = line;
+    }
+
+    /// Moves the output on to `line` of the current file, with blank lines if it is ahead, or
+    /// else with a line marker. Lines are only ever skipped with blank lines, so that line
+    /// markers only separate different files.
+    fn sync(&mut self, line: usize) {
+        if !self.output.ends_with('\n') {
+            self.output.push('\n');
+            self.line += 1;
+        }
+        if line >= self.line {
+            self.output.push_str(&"\n".repeat(line - self.line));
+            self.line = line;
+        } else {
+            self.mark(line, None);
+        }
+    }
+
+    /// The line that physical `line` of the current file is presumed to be.
+    fn presumed(&self, line: usize) -> usize {
+        self.files.last().unwrap().line(line)
+    }
+
+    fn emit(&mut self, token: PpToken) {
+        if token.kind == Kind::Placemarker {
+            return;
+        }
+        if token.bol {
+            self.sync(self.presumed(token.line));
+            self.output.extend(std::iter::repeat_n(' ', token.column));
+        } else if token.space && !self.output.ends_with('\n') {
+            self.output.push(' ');
+        } else if !self.output.is_empty() && !self.output.ends_with(['\n', ' ']) {
+            // keep tokens that came out of different places from running together, as in
+            // `-X` where `X` is `-1`
+            let joined = format!("{}{}", self.last, token.text);
+            if tokenize(&joined).map_or(true, |tokens| tokens.len() != 2) {
+                self.output.push(' ');
+            }
+        }
+        self.output.push_str(&token.text);
+        self.last = token.text;
+    }
+
+    fn directive(
+        &mut self,
+        hash: &PpToken,
+        line: &[PpToken],
+        conditionals: &mut Vec<Conditional>,
+    ) -> Result<()> {
+        let active = conditionals.last().is_none_or(|c| c.active);
+        // the null directive
+        let Some((name, args)) = line.split_first() else {
+            return Ok(());
+        };
+        let unmatched = |this: &Self| {
+            this.error(PreprocessorError::UnmatchedConditional {
+                directive: name.text.clone(),
+                span: name.span,
+            })
+        };
+        match name.text.as_str() {
+            "if" | "ifdef" | "ifndef" => {
+                let keep = active
+                    && match name.text.as_str() {
+                        "if" => self.evaluate(name, args)?,
+                        "ifdef" => self.is_defined(name, args)?,
+                        _ => !self.is_defined(name, args)?,
+                    };
+                conditionals.push(Conditional {
+                    active: keep,
+                    taken: keep || !active,
+                    after_else: false,
+                    span: name.span,
+                });
+            }
+            "elif" | "else" => {
+                let Some(conditional) = conditionals.last_mut() else {
+                    return Err(unmatched(self));
+                };
+                if conditional.after_else {
+                    return Err(self.error(PreprocessorError::AfterElse {
+                        directive: name.text.clone(),
+                        span: name.span,
+                    }));
+                }
+                if conditional.taken {
+                    conditional.active = false;
+                } else if name.text == "elif" {
+                    conditional.active = self.evaluate(name, args)?;
+                    conditional.taken = conditional.active;
+                } else {
+                    conditional.active = true;
+                    conditional.taken = true;
+                }
+                conditional.after_else = name.text == "else";
+            }
+            "endif" => {
+                conditionals.pop().ok_or_else(|| unmatched(self))?;
+            }
+            _ if !active => {}
+            "define" => self.define(name, args)?,
+            "undef" => {
+                let name = self.macro_name(name, args)?;
+                self.macros.remove(&name.text);
+            }
+            "include" => self.include(name, args, false)?,
+            "include_next" => self.include(name, args, true)?,
+            "error" => {
+                let message = args.iter().map(|t| t.text.as_str()).collect::<Vec<_>>();
+                return Err(self.error(PreprocessorError::Error {
+                    message: message.join(" "),
+                    span: name.span,
+                }));
+            }
+            "warning" => {
+                let message = args.iter().map(|t| t.text.as_str()).collect::<Vec<_>>();
+                let warning = self.error(PreprocessorError::Warning {
+                    message: message.join(" "),
+                    span: name.span,
+                });
+                self.warnings.push(warning);
+            }
+            "line" => self.line_directive(name, args)?,
+            // the string would go into a comment section of the object file, which we do
+            // not write
+            "ident" | "sccs" => {
+                if !matches!(args, [string] if string.kind == Kind::String) {
+                    return Err(self.error(PreprocessorError::IdentSyntax {
+                        directive: name.text.clone(),
+                        span: args.first().unwrap_or(name).span,
+                    }));
+                }
+            }
+            "pragma" => {
+                if args.first().is_some_and(|t| t.is_identifier("once")) {
+                    let path = &self.files.last().unwrap().path;
+                    self.once.insert(canonical(path));
+                }
+            }
+            _ => {
+                return Err(self.error(PreprocessorError::UnknownDirective {
+                    name: name.text.clone(),
+                    span: SourceSpan::from(
+                        hash.span.offset()..name.span.offset() + name.span.len(),
+                    ),
+                }));
+            }
+        }
+        Ok(())
+    }
+
+    fn macro_name<'t>(&self, directive: &PpToken, args: &'t [PpToken]) -> Result<&'t PpToken> {
+        match args.first() {
+            Some(name) if name.kind == Kind::Identifier && name.text != "defined" => Ok(name),
+            token => Err(self.error(PreprocessorError::MacroName {
+                span: token.unwrap_or(directive).span,
+            })),
+        }
+    }
+
+    fn is_defined(&self, directive: &PpToken, args: &[PpToken]) -> Result<bool> {
+        let name = self.macro_name(directive, args)?;
+        Ok(self.macros.contains_key(&name.text))
+    }
+
+    fn define(&mut self, directive: &PpToken, args: &[PpToken]) -> Result<()> {
+        let name = self.macro_name(directive, args)?.text.clone();
+        let mut body = &args[1..];
+        let mut function = None;
+        // a function-like macro has its parameter list right after its name
+        if body.first().is_some_and(|t| t.is("(") && !t.space) {
+            let (mut params, mut variadic) = (vec![], false);
+            let mut i = 1;
+            if !body.get(i).is_some_and(|t| t.is(")")) {
+                loop {
+                    match body.get(i) {
+                        Some(t) if t.is("...") => {
+                            variadic = true;
+                            i += 1;
+                        }
+                        Some(t) if t.kind == Kind::Identifier => {
+                            params.push(t.text.clone());
+                            i += 1;
+                        }
+                        t => {
+                            return Err(self.error(PreprocessorError::MacroParameters {
+                                span: t.unwrap_or(&body[0]).span,
+                            }));
+                        }
+                    }
+                    match body.get(i) {
+                        Some(t) if t.is(",") && !variadic => i += 1,
+                        Some(t) if t.is(")") => break,
+                        t => {
+                            return Err(self.error(PreprocessorError::MacroParameters {
+                                span: t.unwrap_or(&body[i - 1]).span,
+                            }));
+                        }
+                    }
+                }
+            }
+            body = &body[i + 1..];
+            function = Some((params, variadic));
+        }
+
+        if let Some(token) = [body.first(), body.last()]
+            .into_iter()
+            .flatten()
+            .find(|t| t.is("##"))
+        {
+            return Err(self.error(PreprocessorError::PasteAtEdge { span: token.span }));
+        }
+        let mut body = body.to_vec();
+        if let Some(first) = body.first_mut() {
+            first.space = false;
+        }
+        let definition = match function {
+            Some((params, variadic)) => {
+                let is_param = |t: &PpToken| {
+                    t.kind == Kind::Identifier
+                        && (params.contains(&t.text) || variadic && t.text == "__VA_ARGS__")
+                };
+                for (i, token) in body.iter().enumerate() {
+                    if token.is("#") && !body.get(i + 1).is_some_and(is_param) {
+                        return Err(self
+                            .error(PreprocessorError::StringifyNonParameter { span: token.span }));
+                    }
+                }
+                Macro::Function {
+                    params,
+                    variadic,
+                    body,
+                }
+            }
+            None => Macro::Object(body),
+        };
+        self.macros.insert(name, Rc::new(definition));
+        Ok(())
+    }
+
+    /// Renumbers the lines after a `#line` directive, and renames the file if it gives a name.
+    fn line_directive(&mut self, directive: &PpToken, args: &[PpToken]) -> Result<()> {
+        let args = self.expand_all(args.to_vec())?;
+        let syntax = PreprocessorError::LineSyntax {
+            span: args.first().unwrap_or(directive).span,
+        };
+        let (number, name) = match args.as_slice() {
+            [number] => (number, None),
+            // a wide or unicode string is not allowed
+            [number, name] if name.kind == Kind::String && name.text.starts_with('"') => {
+                (number, Some(unquote(&name.text)))
+            }
+            _ => return Err(self.error(syntax)),
+        };
+        let line = Some(number.text.as_str())
+            .filter(|text| text.bytes().all(|c| c.is_ascii_digit()))
+            .and_then(|text| text.parse::<i32>().ok())
+            .filter(|line| *line > 0);
+        let Some(line) = line else {
+            return Err(self.error(syntax));
+        };
+        // the directive may be continued onto later lines
+        let end = args.iter().map(|t| t.line).max().unwrap_or(directive.line);
+        let file = self.files.last_mut().unwrap();
+        file.line_offset = line as isize - (end + 1) as isize;
+        if name.is_some() {
+            file.name = name;
+        }
+        self.mark(line as usize, None);
+        Ok(())
+    }
+
+    /// Includes a header. `#include_next` skips the directories of the search path up to and
+    /// including the one the current file was found in.
+    fn include(&mut self, directive: &PpToken, args: &[PpToken], next: bool) -> Result<()> {
+        // a computed include is macro expanded into one of the two forms
+        let expanded;
+        let args = if args
+            .first()
+            .is_some_and(|t| t.kind == Kind::String || t.is("<"))
+        {
+            args
+        } else {
+            expanded = self.expand_all(args.to_vec())?;
+            &expanded
+        };
+        let (name, quoted) = match args {
+            [file] if file.kind == Kind::String => {
+                (file.text[1..file.text.len() - 1].to_string(), true)
+            }
+            [open, name @ .., close] if open.is("<") && close.is(">") => {
+                let mut file = String::new();
+                for (i, token) in name.iter().enumerate() {
+                    if i > 0 && token.space {
+                        file.push(' ');
+                    }
+                    file.push_str(&token.text);
+                }
+                (file, false)
+            }
+            _ => {
+                return Err(self.error(PreprocessorError::IncludeSyntax {
+                    span: args.first().unwrap_or(directive).span,
+                }));
+            }
+        };
+        let (first, last) = (args[0].span, args[args.len() - 1].span);
+        let span = SourceSpan::from(first.offset()..last.offset() + last.len());
+
+        // quoted names are looked up next to the including file first
+        let current = self.files.last().unwrap();
+        let local = quoted
+            .then(|| current.path.parent())
+            .flatten()
+            .map(|dir| dir.join(&name))
+            .filter(|path| !next && path.is_file());
+        let start = match current.found_in {
+            Some(found_in) if next => found_in + 1,
+            _ => 0,
+        };
+        let (path, builtin, found_in) = match local {
+            Some(path) => (path, None, None),
+            None => match self.search(&name, start) {
+                Some((path, builtin, found_in)) => (path, builtin, Some(found_in)),
+                None => return Err(self.error(PreprocessorError::IncludeNotFound { name, span })),
+            },
+        };
+        if self.files.len() >= MAX_INCLUDE_DEPTH {
+            return Err(self.error(PreprocessorError::IncludeDepth { span }));
+        }
+        if self.once.contains(&canonical(&path)) {
+            return Ok(());
+        }
+        let text = match builtin {
+            Some(text) => text.to_string(),
+            None => fs_err::read_to_string(&path).into_diagnostic()?,
+        };
+        // the marker for the new file goes on the line of the `#include`, where it came from
+        let line = self.presumed(directive.line);
+        self.sync(line);
+        self.run(path, text, Some(line), found_in)
+    }
+
+    /// Looks for the header `name` in the search path from position `start`: the `-I`
+    /// directories, then our own headers, which come before the system ones as the compiler's
+    /// do in GCC, then the system directories. Returns the path of the header, its text if it
+    /// is one of ours, and its position in the search path.
+    fn search(
+        &self,
+        name: &str,
+        start: usize,
+    ) -> Option<(Utf8PathBuf, Option<&'static str>, usize)> {
+        let builtin = self.include_dirs.len();
+        (start..=builtin + self.system_include_dirs.len()).find_map(|i| {
+            let dir = match i.cmp(&builtin) {
+                Ordering::Less => &self.include_dirs[i],
+                Ordering::Equal => {
+                    let (header, text) = BUILTIN_HEADERS.iter().find(|(h, _)| *h == name)?;
+                    return Some((format!("<built-in>/{header}").into(), Some(*text), i));
+                }
+                Ordering::Greater => &self.system_include_dirs[i - builtin - 1],
+            };
+            let path = dir.join(name);
+            path.is_file().then_some((path, None, i))
+        })
+    }
+
+    /// Takes the next token of `input` that is not a macro name, expanding the macros in front
+    /// of it. The expansion of a macro is pushed back onto `input` to be rescanned along with
+    /// the rest of it, and the tokens it gives are hidden from the same macro.
+    fn next_expanded(&mut self, input: &mut VecDeque<PpToken>) -> Result<Option<PpToken>> {
+        // an expansion to nothing must not run into the directive on the next line
+        while input.front().is_some_and(|t| !(t.bol && t.is("#"))) {
+            let token = input.pop_front().unwrap();
+            if token.kind != Kind::Identifier || token.hideset.contains(&token.text) {
+                return Ok(Some(token));
+            }
+            let Some(definition) = self.macros.get(&token.text).cloned() else {
+                return Ok(Some(token));
+            };
+            let (mut expansion, mut hideset) = match definition.as_ref() {
+                Macro::Line => {
+                    let text = self.presumed(token.line).to_string();
+                    return Ok(Some(PpToken {
+                        kind: Kind::Number,
+                        text,
+                        ..token
+                    }));
+                }
+                Macro::File => {
+                    let text = string_literal(self.files.last().unwrap().name());
+                    return Ok(Some(PpToken {
+                        kind: Kind::String,
+                        text,
+                        ..token
+                    }));
+                }
+                Macro::Object(body) => (body.clone(), token.hideset.clone()),
+                Macro::Function {
+                    params,
+                    variadic,
+                    body,
+                } => {
+                    // the name of a function-like macro is left alone without arguments
+                    if !input.front().is_some_and(|t| t.is("(")) {
+                        return Ok(Some(token));
+                    }
+                    let (args, rparen) = self.arguments(&token, input, params.len(), *variadic)?;
+                    let expansion = self.substitute(params, *variadic, body, &args)?;
+                    let hideset = token
+                        .hideset
+                        .intersection(&rparen.hideset)
+                        .cloned()
+                        .collect();
+                    (expansion, hideset)
+                }
+            };
+            hideset.insert(token.text.clone());
+            for (i, t) in expansion.iter_mut().enumerate() {
+                t.hideset.extend(hideset.iter().cloned());
+                t.span = token.span;
+                t.line = token.line;
+                t.column = token.column;
+                t.bol = false;
+                if i == 0 {
+                    t.space = token.space;
+                }
+            }
+            for t in expansion.into_iter().rev() {
+                input.push_front(t);
+            }
+        }
+        Ok(None)
+    }
+
+    fn expand_all(&mut self, tokens: Vec<PpToken>) -> Result<Vec<PpToken>> {
+        let mut input = VecDeque::from(tokens);
+        let mut expanded = vec![];
+        while let Some(token) = self.next_expanded(&mut input)? {
+            expanded.push(token);
+        }
+        Ok(expanded)
+    }
+
+    /// Collects the arguments of a macro invocation up to the closing parenthesis, which is
+    /// returned too. The arguments after the named ones are kept together as the variable
+    /// argument.
+    fn arguments(
+        &self,
+        name: &PpToken,
+        input: &mut VecDeque<PpToken>,
+        params: usize,
+        variadic: bool,
+    ) -> Result<(Vec<Vec<PpToken>>, PpToken)> {
+        input.pop_front();
+        let mut args = vec![vec![]];
+        let mut depth = 0;
+        let rparen = loop {
+            let Some(mut token) = input.pop_front() else {
+                return Err(self.error(PreprocessorError::UnterminatedArguments {
+                    name: name.text.clone(),
+                    span: name.span,
+                }));
+            };
+            // arguments may span lines, but are joined into the line of the invocation
+            token.space |= token.bol;
+            token.bol = false;
+            if token.is("(") {
+                depth += 1;
+            } else if token.is(")") {
+                if depth == 0 {
+                    break token;
+                }
+                depth -= 1;
+            } else if token.is(",") && depth == 0 && (!variadic || args.len() <= params) {
+                args.push(vec![]);
+                continue;
+            }
+            args.last_mut().unwrap().push(token);
+        };
+
+        if params == 0 && args.len() == 1 && args[0].is_empty() {
+            args.clear();
+        }
+        if variadic && args.len() == params {
+            args.push(vec![]);
+        }
+        let expected = params + variadic as usize;
+        if args.len() != expected {
+            return Err(self.error(PreprocessorError::ArgumentCount {
+                name: name.text.clone(),
+                expected,
+                actual: args.len(),
+                span: name.span,
+            }));
+        }
+        Ok((args, rparen))
+    }
+
+    /// Replaces the parameters in the body of a function-like macro. Arguments are macro
+    /// expanded first, except where they are operands of `#` or `##`.
+    fn substitute(
+        &mut self,
+        params: &[String],
+        variadic: bool,
+        body: &[PpToken],
+        args: &[Vec<PpToken>],
+    ) -> Result<Vec<PpToken>> {
+        let param = |t: &PpToken| match t.kind {
+            Kind::Identifier if variadic && t.text == "__VA_ARGS__" => Some(params.len()),
+            Kind::Identifier => params.iter().position(|p| *p == t.text),
+            _ => None,
+        };
+        let mut out: Vec<PpToken> = vec![];
+        let mut i = 0;
+        while i < body.len() {
+            let token = &body[i];
+            let next = body.get(i + 1);
+            if token.is("#") {
+                let arg = &args[param(next.unwrap()).unwrap()];
+                out.push(stringize(token, arg));
+                i += 2;
+                continue;
+            }
+            // as in GCC, `, ## __VA_ARGS__` drops the comma when there are no variable arguments
+            if token.is(",")
+                && next.is_some_and(|t| t.is("##"))
+                && body.get(i + 2).and_then(param) == Some(params.len())
+                && variadic
+            {
+                let arg = &args[params.len()];
+                if !arg.is_empty() {
+                    out.push(token.clone());
+                    out.extend(arg.iter().cloned());
+                }
+                i += 3;
+                continue;
+            }
+            if token.is("##") {
+                let rhs = next.unwrap();
+                let mut rhs = match param(rhs) {
+                    Some(n) => args[n].clone(),
+                    None => vec![rhs.clone()],
+                };
+                let lhs = out.pop().unwrap();
+                if lhs.kind == Kind::Placemarker {
+                    out.extend(rhs);
+                } else if rhs.is_empty() {
+                    out.push(lhs);
+                } else {
+                    let first = rhs.remove(0);
+                    out.push(self.paste(lhs, &first)?);
+                    out.extend(rhs);
+                }
+                i += 2;
+                continue;
+            }
+            match param(token) {
+                // the left operand of `##` is not expanded either
+                Some(n) if next.is_some_and(|t| t.is("##")) => {
+                    let arg = &args[n];
+                    if arg.is_empty() {
+                        out.push(PpToken {
+                            kind: Kind::Placemarker,
+                            text: String::new(),
+                            ..token.clone()
+                        });
+                    } else {
+                        let start = out.len();
+                        out.extend(arg.iter().cloned());
+                        out[start].space = token.space;
+                    }
+                }
+                Some(n) => {
+                    let expanded = self.expand_all(args[n].clone())?;
+                    let start = out.len();
+                    out.extend(expanded);
+                    if let Some(first) = out.get_mut(start) {
+                        first.space = token.space;
+                    }
+                }
+                None => out.push(token.clone()),
+            }
+            i += 1;
+        }
+        out.retain(|t| t.kind != Kind::Placemarker);
+        Ok(out)
+    }
+
+    fn paste(&self, lhs: PpToken, rhs: &PpToken) -> Result<PpToken> {
+        let text = format!("{}{}", lhs.text, rhs.text);
+        match tokenize(&text).as_deref() {
+            Ok([token]) if token.text == text => Ok(PpToken {
+                kind: token.kind,
+                text,
+                ..lhs
+            }),
+            _ => Err(self.error(PreprocessorError::InvalidPaste {
+                lhs: lhs.text,
+                rhs: rhs.text.clone(),
+                span: rhs.span,
+            })),
+        }
+    }
+
+    /// Evaluates the condition of an `#if` or `#elif`.
+    fn evaluate(&mut self, directive: &PpToken, args: &[PpToken]) -> Result<bool> {
+        // `defined` is replaced first, since its operand must not be expanded
+        let mut tokens = vec![];
+        let mut i = 0;
+        while i < args.len() {
+            let token = &args[i];
+            if !token.is_identifier("defined") {
+                tokens.push(token.clone());
+                i += 1;
+                continue;
+            }
+            let parenthesized = args.get(i + 1).is_some_and(|t| t.is("("));
+            let name = self.macro_name(token, &args[i + 1 + parenthesized as usize..])?;
+            i += 2;
+            if parenthesized {
+                match args.get(i + 1) {
+                    Some(t) if t.is(")") => i += 2,
+                    t => {
+                        return Err(self.error(PreprocessorError::InvalidExpression {
+                            span: t.unwrap_or(name).span,
+                        }));
+                    }
+                }
+            }
+            let defined = self.macros.contains_key(&name.text);
+            tokens.push(PpToken {
+                kind: Kind::Number,
+                text: (defined as u8).to_string(),
+                ..token.clone()
+            });
+        }
+
+        let mut tokens = self.expand_all(tokens)?;
+        // identifiers that are not macros are zero
+        for token in tokens.iter_mut().filter(|t| t.kind == Kind::Identifier) {
+            token.kind = Kind::Number;
+            token.text = "0".into();
+        }
+        let mut evaluator = Evaluator {
+            tokens: &tokens,
+            pos: 0,
+            end: directive.span,
+        };
+        evaluator
+            .expression(0, true)
+            .and_then(|value| match tokens.get(evaluator.pos) {
+                Some(token) => Err(PreprocessorError::InvalidExpression { span: token.span }),
+                None => Ok(value.value != 0),
+            })
+            .map_err(|e| self.error(e))
+    }
+}
+
+fn canonical(path: &Utf8Path) -> Utf8PathBuf {
+    path.canonicalize_utf8().unwrap_or_else(|_| path.to_owned())
+}
+
+fn string_literal(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The contents of a string literal, with each escaped character taken as it is, as GCC does
+/// for the file name of `#line`.
+fn unquote(literal: &str) -> String {
+    let mut text = String::new();
+    let mut chars = literal[1..literal.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => text.extend(chars.next()),
+            c => text.push(c),
+        }
+    }
+    text
+}
+
+/// Turns a macro argument into a string literal, for the `#` operator.
+fn stringize(hash: &PpToken, arg: &[PpToken]) -> PpToken {
+    let mut text = String::from('"');
+    for (i, token) in arg.iter().enumerate() {
+        if i > 0 && token.space {
+            text.push(' ');
+        }
+        if matches!(token.kind, Kind::String | Kind::Character) {
+            text.push_str(&token.text.replace('\\', "\\\\").replace('"', "\\\""));
+        } else {
+            text.push_str(&token.text);
+        }
+    }
+    text.push('"');
+    PpToken {
+        kind: Kind::String,
+        text,
+        ..hash.clone()
+    }
+}
+
+/// An integer in a preprocessor expression, which has type `intmax_t` or `uintmax_t`.
+#[derive(Debug, Clone, Copy)]
+struct Value {
+    value: i64,
+    unsigned: bool,
+}
+
+impl Value {
+    fn truth(b: bool) -> Self {
+        Value {
+            value: b as i64,
+            unsigned: false,
+        }
+    }
+}
+
+/// Evaluates the constant expression of an `#if`. Operands that are not evaluated, like the
+/// right side of `0 && x`, are still parsed, but cannot fail.
+struct Evaluator<'t> {
+    tokens: &'t [PpToken],
+    pos: usize,
+    /// Where to report an expression that ends too early.
+    end: SourceSpan,
+}
+
+impl Evaluator<'_> {
+    fn expression(&mut self, min_bp: u8, live: bool) -> Result<Value, PreprocessorError> {
+        let mut lhs = self.unary(live)?;
+        while let Some(token) = self.tokens.get(self.pos) {
+            if token.is("?") && min_bp <= 2 {
+                self.pos += 1;
+                let cond = lhs.value != 0;
+                let if_true = self.expression(0, live && cond)?;
+                self.expect(":")?;
+                let if_false = self.expression(2, live && !cond)?;
+                lhs = Value {
+                    value: if cond { if_true.value } else { if_false.value },
+                    unsigned: if_true.unsigned || if_false.unsigned,
+                };
+                continue;
+            }
+            let bp = match token.text.as_str() {
+                _ if token.kind != Kind::Punctuator => break,
+                "||" => 4,
+                "&&" => 5,
+                "|" => 6,
+                "^" => 7,
+                "&" => 8,
+                "==" | "!=" => 9,
+                "<" | ">" | "<=" | ">=" => 10,
+                "<<" | ">>" => 11,
+                "+" | "-" => 12,
+                "*" | "/" | "%" => 13,
+                _ => break,
+            };
+            if bp < min_bp {
+                break;
+            }
+            self.pos += 1;
+            let rhs_live = match token.text.as_str() {
+                "&&" => live && lhs.value != 0,
+                "||" => live && lhs.value == 0,
+                _ => live,
+            };
+            let rhs = self.expression(bp + 1, rhs_live)?;
+            lhs = binary(token, lhs, rhs, live)?;
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self, live: bool) -> Result<Value, PreprocessorError> {
+        let Some(token) = self.tokens.get(self.pos) else {
+            return Err(PreprocessorError::InvalidExpression { span: self.end });
+        };
+        self.pos += 1;
+        match token.kind {
+            Kind::Number => return number(token),
+            Kind::Character => return character(token),
+            Kind::Punctuator => {}
+            _ => return Err(PreprocessorError::InvalidExpression { span: token.span }),
+        }
+        let mut value = match token.text.as_str() {
+            "(" => {
+                let value = self.expression(0, live)?;
+                self.expect(")")?;
+                return Ok(value);
+            }
+            "+" | "-" | "~" | "!" => self.unary(live)?,
+            _ => return Err(PreprocessorError::InvalidExpression { span: token.span }),
+        };
+        match token.text.as_str() {
+            "-" => value.value = value.value.wrapping_neg(),
+            "~" => value.value = !value.value,
+            "!" => value = Value::truth(value.value == 0),
+            _ => {}
+        }
+        Ok(value)
+    }
+
+    fn expect(&mut self, punctuator: &str) -> Result<(), PreprocessorError> {
+        match self.tokens.get(self.pos) {
+            Some(token) if token.is(punctuator) => {
+                self.pos += 1;
+                Ok(())
+            }
+            token => Err(PreprocessorError::InvalidExpression {
+                span: token.map_or(self.end, |t| t.span),
+            }),
+        }
+    }
+}
+
+fn binary(op: &PpToken, lhs: Value, rhs: Value, live: bool) -> Result<Value, PreprocessorError> {
+    let unsigned = lhs.unsigned || rhs.unsigned;
+    let (l, r) = (lhs.value, rhs.value);
+    let less = if unsigned {
+        (l as u64) < (r as u64)
+    } else {
+        l < r
+    };
+    let value = match op.text.as_str() {
+        "*" => l.wrapping_mul(r),
+        "/" | "%" if r == 0 => {
+            if live {
+                return Err(PreprocessorError::DivisionByZero { span: op.span });
+            }
+            0
+        }
+        "/" if unsigned => ((l as u64) / (r as u64)) as i64,
+        "/" => l.wrapping_div(r),
+        "%" if unsigned => ((l as u64) % (r as u64)) as i64,
+        "%" => l.wrapping_rem(r),
+        "+" => l.wrapping_add(r),
+        "-" => l.wrapping_sub(r),
+        "<<" => {
+            return Ok(Value {
+                value: l.wrapping_shl(r as u32),
+                unsigned: lhs.unsigned,
+            });
+        }
+        ">>" => {
+            let value = match lhs.unsigned {
+                true => ((l as u64).wrapping_shr(r as u32)) as i64,
+                false => l.wrapping_shr(r as u32),
+            };
+            return Ok(Value {
+                value,
+                unsigned: lhs.unsigned,
+            });
+        }
+        "<" => return Ok(Value::truth(less)),
+        ">=" => return Ok(Value::truth(!less)),
+        ">" => return Ok(Value::truth(!less && l != r)),
+        "<=" => return Ok(Value::truth(less || l == r)),
+        "==" => return Ok(Value::truth(l == r)),
+        "!=" => return Ok(Value::truth(l != r)),
+        "&" => l & r,
+        "^" => l ^ r,
+        "|" => l | r,
+        "&&" => return Ok(Value::truth(l != 0 && r != 0)),
+        "||" => return Ok(Value::truth(l != 0 || r != 0)),
+        _ => unreachable!("{} is not a binary operator", op.text),
+    };
+    Ok(Value { value, unsigned })
+}
+
+fn number(token: &PpToken) -> Result<Value, PreprocessorError> {
+    let text = token.text.as_str();
+    let digits = text.trim_end_matches(['u', 'U', 'l', 'L']);
+    let hex = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"));
+    let floating = match hex {
+        Some(hex) => hex.contains(['.', 'p', 'P']),
+        None => digits.contains(['.', 'e', 'E']),
+    };
+    if floating {
+        return Err(PreprocessorError::FloatingConstant { span: token.span });
+    }
+    let binary = digits
+        .strip_prefix("0b")
+        .or_else(|| digits.strip_prefix("0B"));
+    let (digits, radix) = match (hex, binary) {
+        (Some(hex), _) => (hex, 16),
+        (_, Some(binary)) => (binary, 2),
+        _ if digits.len() > 1 && digits.starts_with('0') => (&digits[1..], 8),
+        _ => (digits, 10),
+    };
+    let value = u64::from_str_radix(digits, radix)
+        .map_err(|_| PreprocessorError::InvalidExpression { span: token.span })?;
+    Ok(Value {
+        value: value as i64,
+        unsigned: text[digits.len()..].contains(['u', 'U']) || value > i64::MAX as u64,
+    })
+}
+
+/// The value of a character constant. Plain ones are `int`s holding a signed `char`, and
+/// prefixed ones have the type of a wide or unicode character.
+fn character(token: &PpToken) -> Result<Value, PreprocessorError> {
+    let invalid = PreprocessorError::InvalidExpression { span: token.span };
+    let (prefix, literal) = token.text.split_at(token.text.find('\'').unwrap());
+    let body = &literal[1..literal.len() - 1];
+    let mut chars = body.chars();
+    let value = match (chars.next(), chars.next()) {
+        (Some('\\'), Some(escape)) => match escape {
+            'n' => 10,
+            't' => 9,
+            'r' => 13,
+            'a' => 7,
+            'b' => 8,
+            'f' => 12,
+            'v' => 11,
+            '\\' | '\'' | '"' | '?' => escape as u32,
+            'x' => u32::from_str_radix(chars.as_str(), 16).map_err(|_| invalid)?,
+            '0'..='7' => {
+                u32::from_str_radix(&body[1..body.len().min(4)], 8).map_err(|_| invalid)?
+            }
+            _ => return Err(invalid),
+        },
+        (Some(c), None) => c as u32,
+        _ => return Err(invalid),
+    };
+    let value = match prefix {
+        "L" => (value as i32).into(),
+        "u" => (value as u16).into(),
+        "U" => value.into(),
+        "u8" => (value as u8).into(),
+        _ => (value as u8 as i8).into(),
+    };
+    Ok(Value {
+        value,
+        unsigned: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::prelude::*;
+    use camino::Utf8PathBuf;
+
+    use super::Preprocessor;
+    use crate::lexer::SourceMap;
+
+    fn preprocess_with_markers(src: &str) -> miette::Result<String> {
+        let mut preprocessor = Preprocessor::new(&[], &[]);
+        preprocessor.run("example.c".into(), src.into(), None, None)?;
+        Ok(preprocessor.output)
+    }
+
+    fn preprocess(src: &str) -> miette::Result<String> {
+        preprocess_with_markers(src).map(|output| without_markers(&output))
+    }
+
+    /// Drops line markers and blank lines, which most tests do not care about.
+    fn without_markers(output: &str) -> String {
+        output
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with("# "))
+            .map(|line| format!("{line}\n"))
+            .collect()
+    }
+
+    #[test]
+    fn test_object_macros() {
+        let src = "#define N -1\n#define TWICE N N\n#undef TWICE\nint x = -N; TWICE\n";
+        assert_eq!(preprocess(src).unwrap(), "int x = - -1; TWICE\n");
+    }
+
+    #[test]
+    fn test_function_macros() {
+        let src = r#"
+#define str(x) #x
+#define cat(a, b) a ## b
+#define max(a, b) ((a) > (b) ? (a) : (b))
+#define f(fmt, ...) printf(fmt, ## __VA_ARGS__)
+#define f2 f
+#define loop loop + 1
+str( "a\n"  +  b ) cat(x, 1) cat(, y) max(f(1), max(2, 3))
+f2("x") f2("y", 1, (2, 3)) loop max
+(1,
+2)
+"#;
+        assert_eq!(
+            preprocess(src).unwrap(),
+            concat!(
+                r#""\"a\\n\" + b" x1 y ((printf(1)) > (((2) > (3) ? (2) : (3))) ? "#,
+                r#"(printf(1)) : (((2) > (3) ? (2) : (3))))"#,
+                "\n",
+                r#"printf("x") printf("y", 1, (2, 3)) loop + 1 ((1) > (2) ? (1) : (2))"#,
+                "\n",
+            )
+        );
+        preprocess("#define f(a) a\nf(1, 2)").expect_err("too many arguments");
+        preprocess("#define f(a) #b").expect_err("# needs a parameter");
+        preprocess("#define cat(a, b) a ## b\ncat(+, /)").expect_err("+/ is not a token");
+    }
+
+    #[test]
+    fn test_conditionals() {
+        let src = r#"
+#define A 2
+#if A * 3 == 6 && defined(A) && !defined B
+one
+#if 0
+#error "skipped"
+#elif 1
+inner
+#else
+#bogus directive is skipped
+#endif
+#elif 1 / 0
+two
+#else
+three
+#endif
+#ifndef A
+four
+#elif -1 < 0u
+five
+#elif (1 ? 0 : 1 / 0) || 'a' == 97 || '\377' < 0
+six
+#endif
+"#;
+        assert_eq!(preprocess(src).unwrap(), "one\ninner\nsix\n");
+        preprocess("#if 1\n").expect_err("missing #endif");
+        preprocess("#if 1 / 0\n#endif").expect_err("division by zero");
+        preprocess("#else").expect_err("#else without #if");
+        preprocess("#error \"nope\"").expect_err("#error stops preprocessing");
+    }
+
+    #[test]
+    fn test_predefined_macros() {
+        let src = "__FILE__\n__LINE__ \\\n__LINE__ __STDC_VERSION__\n#if __x86_64__\nx86\n#endif";
+        assert_eq!(
+            preprocess(src).unwrap(),
+            "\"example.c\"\n2 3 201710L\nx86\n"
+        );
+    }
+
+    #[test]
+    fn test_include() {
+        let temp = assert_fs::TempDir::new().unwrap();
+        let dir = Utf8PathBuf::from_path_buf(temp.path().to_owned()).unwrap();
+        temp.child("inc/once.h")
+            .write_str("#pragma once\nint once;\n")
+            .unwrap();
+        temp.child("local.h")
+            .write_str("#include <once.h>\nint local = __LINE__;\n")
+            .unwrap();
+        temp.child("main.c")
+            .write_str("#include \"local.h\"\n#define H <once.h>\n#include H\nint main;\n")
+            .unwrap();
+
+        let include_dirs = [dir.join("inc")];
+        let mut preprocessor = Preprocessor::new(&include_dirs, &[]);
+        let main = dir.join("main.c");
+        let text = fs_err::read_to_string(&main).unwrap();
+        preprocessor.run(main, text, None, None).unwrap();
+        assert_eq!(
+            without_markers(&preprocessor.output),
+            "int once;\nint local = 2;\nint main;\n"
+        );
+
+        let mut preprocessor = Preprocessor::new(&[], &[]);
+        preprocessor
+            .run(
+                dir.join("main.c"),
+                "#include \"missing.h\"".into(),
+                None,
+                None,
+            )
+            .expect_err("missing.h does not exist");
+    }
+
+    #[test]
+    fn test_include_next() {
+        let temp = assert_fs::TempDir::new().unwrap();
+        let dir = Utf8PathBuf::from_path_buf(temp.path().to_owned()).unwrap();
+        temp.child("inc/stdio.h")
+            .write_str("#include_next <stdio.h>\nint wrapped;\n")
+            .unwrap();
+        temp.child("inc/stdbool.h")
+            .write_str("#include_next \"stdbool.h\"\nint mine;\n")
+            .unwrap();
+        temp.child("sys/stdio.h").write_str("int real;\n").unwrap();
+
+        let include_dirs = [dir.join("inc")];
+        let system_include_dirs = [dir.join("sys")];
+        let mut preprocessor = Preprocessor::new(&include_dirs, &system_include_dirs);
+        let src = "#include <stdio.h>\n#include <stdbool.h>\nbool b;\n";
+        preprocessor
+            .run(dir.join("main.c"), src.into(), None, None)
+            .unwrap();
+        assert_eq!(
+            without_markers(&preprocessor.output),
+            "int real;\nint wrapped;\nint mine;\n_Bool b;\n"
+        );
+
+        let mut preprocessor = Preprocessor::new(&include_dirs, &[]);
+        preprocessor
+            .run(dir.join("main.c"), "#include <stdio.h>".into(), None, None)
+            .expect_err("there is no stdio.h after inc");
+    }
+
+    #[test]
+    fn test_ident() {
+        assert_eq!(
+            preprocess("#ident \"v1\"\n#sccs \"v2\"\nint x;\n").unwrap(),
+            "int x;\n"
+        );
+        assert_eq!(preprocess("#if 0\n#ident v1\n#endif\n").unwrap(), "");
+        preprocess("#ident v1").expect_err("not a string literal");
+        preprocess("#ident").expect_err("missing string literal");
+    }
+
+    #[test]
+    fn test_stdarg() {
+        let src = "va_start(ap, n)\n#include <stdarg.h>\nva_list ap; va_start(ap, n); va_arg(ap, int *);\n";
+        assert_eq!(
+            preprocess(src).unwrap(),
+            concat!(
+                "va_start(ap, n)\n",
+                "typedef __builtin_va_list __gnuc_va_list;\n",
+                "typedef __builtin_va_list va_list;\n",
+                "va_list ap; __builtin_va_start(ap, n); __builtin_va_arg(ap, int *);\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_line_directive() {
+        let src = "a __LINE__\n#line 10\n__LINE__ __FILE__\n#define N 20\n#line N \"b.c\"\nb __LINE__ __FILE__\n";
+        let output = preprocess_with_markers(src).unwrap();
+        assert_eq!(
+            output,
+            concat!(
+                "# 1 \"example.c\"\na 1\n",
+                "# 10 \"example.c\"\n10 \"example.c\"\n",
+                "# 20 \"b.c\"\nb 20 \"b.c\"\n",
+            )
+        );
+        let map = SourceMap::new(&output, "example.c");
+        assert_eq!(map.locate(output.find("b 20").unwrap()), ("b.c", 20, 1));
+
+        preprocess("#line 0").expect_err("lines start at 1");
+        preprocess("#line 2147483648").expect_err("line out of range");
+        preprocess("#line 0x10").expect_err("not a decimal line");
+        preprocess("#line 1 L\"a.c\"").expect_err("wide file name");
+        preprocess("#line 1 \"a.c\" 2").expect_err("extra tokens");
+    }
+
+    #[test]
+    fn test_warning() {
+        let mut preprocessor = Preprocessor::new(&[], &[]);
+        let src = "#warning \"careful\"\n#if 0\n#warning skipped\n#endif\nint x;\n";
+        preprocessor
+            .run("example.c".into(), src.into(), None, None)
+            .unwrap();
+        assert_eq!(without_markers(&preprocessor.output), "int x;\n");
+        let warnings = preprocessor.warnings.iter().map(ToString::to_string);
+        assert_eq!(warnings.collect::<Vec<_>>(), ["#warning \"careful\""]);
+    }
+
+    #[test]
+    fn test_line_markers() {
+        let src = "int a;\n#include <stdbool.h>\nbool b;\n\n#define F(x) x\nF(\n1); int c;\n\n\n\n\n\n\n\n\n\nint d;\n";
+        assert_eq!(
+            preprocess_with_markers(src).unwrap(),
+            concat!(
+                "# 1 \"example.c\"\nint a;\n",
+                "# 1 \"<built-in>/stdbool.h\" 1\n",
+                "# 3 \"example.c\" 2\n_Bool b;\n\n\n1; int c;\n",
+                "\n\n\n\n\n\n\n\n\n\nint d;\n",
+            )
+        );
+    }
+}
@@ -2,7 +2,10 @@ use miette::Diagnostic;
 
 use crate::ast::Program;
 
-pub use type_check::{Symbol, SymbolAttributes, SymbolTable, Type};
+pub use type_check::{
+    Qualifiers, StaticInit, StructType, Symbol, SymbolAttributes, SymbolTable, Type,
+    points_to_volatile,
+};
 
 mod duplicate_cases;
 mod loop_labels;
@@ -36,7 +36,7 @@ impl Scope {}
 
 fn visit_statement(statement: &mut Statement, scope: &mut Scope) -> Result {
     match statement {
-        Statement::Return(_) => {}
+        Statement::Return { .. } => {}
         Statement::Expression(_) => {}
         Statement::If {
             cond: _,
@@ -121,7 +121,10 @@ fn visit_block(block: &mut Block, scope: &mut Scope) -> Result {
 
 fn visit_decl(decl: &mut Declaration, scope: &mut Scope) -> Result {
     match decl {
-        Declaration::Variable(_) => Ok(()),
+        Declaration::Variable(_)
+        | Declaration::Struct(_)
+        | Declaration::Enum(_)
+        | Declaration::Typedef(_) => Ok(()),
         Declaration::Function(function_declaration) => {
             if let Some(block) = function_declaration.body.as_mut() {
                 visit_block(block, scope)?;
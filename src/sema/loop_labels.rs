@@ -3,6 +3,7 @@ use miette::SourceSpan;
 use crate::ast::*;
 
 #[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     #[error("Break used outside of loop or switch")]
     InvalidBreak(#[label] SourceSpan),
@@ -10,11 +11,6 @@ pub enum Error {
     InvalidContinue(#[label] SourceSpan),
     #[error("case statement used outside of switch")]
     InvalidCase(#[label] SourceSpan),
-    #[error("Non-constant expression in case")]
-    NonConstantCase {
-        #[label("expression")]
-        span: SourceSpan,
-    },
 }
 
 type Result = std::result::Result<(), Error>;
@@ -53,7 +49,7 @@ impl Scope {
 
 fn visit_statement(statement: &mut Statement, loop_label: &mut Scope) -> Result {
     match statement {
-        Statement::Return(_) => {}
+        Statement::Return { .. } => {}
         Statement::Expression(_) => {}
         Statement::If {
             cond: _,
@@ -132,11 +128,8 @@ fn visit_statement(statement: &mut Statement, loop_label: &mut Scope) -> Result
                 .cases
                 .last_mut()
                 .ok_or(Error::InvalidCase(expr.span()))?;
+            // the expression is checked to be constant during type checking
             label.replace(switch_label.clone());
-            match expr {
-                Expression::Constant { .. } => {}
-                expr => return Err(Error::NonConstantCase { span: expr.span() }),
-            }
             visit_statement(statement, loop_label)?
         }
         Statement::Default {
@@ -168,7 +161,10 @@ fn visit_block(block: &mut Block, scope: &mut Scope) -> Result {
 
 fn visit_decl(decl: &mut Declaration, scope: &mut Scope) -> Result {
     match decl {
-        Declaration::Variable(_) => Ok(()),
+        Declaration::Variable(_)
+        | Declaration::Struct(_)
+        | Declaration::Enum(_)
+        | Declaration::Typedef(_) => Ok(()),
         Declaration::Function(function_declaration) => {
             if let Some(block) = function_declaration.body.as_mut() {
                 visit_block(block, scope)?;
@@ -179,8 +175,10 @@ fn visit_decl(decl: &mut Declaration, scope: &mut Scope) -> Result {
 }
 
 pub(super) fn run(program: &mut Program) -> Result {
+    // labels are numbered across the whole program, since they become assembly labels
+    let mut scope = Scope::default();
     for decl in program.declarations.iter_mut() {
-        visit_decl(decl, &mut Scope::default())?
+        visit_decl(decl, &mut scope)?
     }
     Ok(())
 }
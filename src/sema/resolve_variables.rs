@@ -2,7 +2,10 @@ use std::collections::{HashMap, hash_map::Entry};
 
 use miette::{Diagnostic, SourceSpan};
 
-use crate::ast::*;
+use crate::{
+    ast::*,
+    sema::{StructType, Type},
+};
 
 #[derive(Debug, thiserror::Error, Diagnostic)]
 #[error("Failed to resolve identifiers")]
@@ -13,10 +16,12 @@ pub enum Error {
     UnresolvedReference(String),
     #[error("{0} assigned without being declared")]
     UnresolvedAssignment(String, #[label("identifier")] SourceSpan),
-    #[error("cannot call non-identifier")]
-    NonIdentifierCall(#[label("identifier required here")] SourceSpan),
+    #[error("cannot assign to {0}, which is declared const")]
+    ConstAssignment(String, #[label("assigned here")] SourceSpan),
     #[error("cannot assign to non-identifier")]
     NonIdentifierAssignment(#[label("lvalue required here")] SourceSpan),
+    #[error("cannot take the address of a non-lvalue")]
+    AddressOfNonLvalue(#[label("lvalue required here")] SourceSpan),
     #[error("cannot define {0} inside another function")]
     NestedFunctionDefinition(String),
     #[error("cannot declare functions at block scope with static storage specifier")]
@@ -31,51 +36,117 @@ pub enum Error {
         #[label("here")]
         span: SourceSpan,
     },
-    #[error("initializers for static variables must be constant")]
-    StaticVariableNonConstantInitializer {
-        #[label("expression")]
+    #[error("{0} was previously declared as a different kind of tag")]
+    TagKindMismatch(String),
+    #[error("enumerator value must be an integer constant")]
+    EnumeratorNonConstant {
+        #[label("here")]
         span: SourceSpan,
     },
 }
 
 type Result<T = ()> = miette::Result<T, Error>;
 
+/// What an ordinary identifier refers to in some scope.
+struct Binding {
+    /// The unique name of the object or function.
+    name: String,
+    linkage: bool,
+    /// Whether the object is const-qualified, and so cannot be assigned to.
+    constant: bool,
+    /// The value of an enumeration constant, which is not an object at all.
+    value: Option<i32>,
+}
+
 #[derive(Default)]
 struct Scope {
-    vars: Vec<HashMap<String, (String, bool, SourceSpan)>>,
+    vars: Vec<HashMap<String, Binding>>,
+    /// Structure tags, and whether they have been defined in that scope.
+    tags: Vec<HashMap<String, (StructType, bool)>>,
     idx: u32,
 }
 
 impl Scope {
-    fn declare(&mut self, name: &str, linkage: bool, span: SourceSpan) -> Result<&String> {
+    fn declare(&mut self, name: &str, linkage: bool, constant: bool) -> Result<&String> {
         let last = self.vars.last_mut().unwrap();
         let resolved = match last.entry(name.to_string()) {
             Entry::Occupied(occupied_entry) => match linkage {
-                true if occupied_entry.get().1 => occupied_entry.into_mut(),
+                true if occupied_entry.get().linkage => occupied_entry.into_mut(),
                 _ => return Err(Error::DuplicateDeclaration(name.to_string())),
             },
-            Entry::Vacant(vacant_entry) => vacant_entry.insert((
-                match linkage {
+            Entry::Vacant(vacant_entry) => vacant_entry.insert(Binding {
+                name: match linkage {
                     false => format!("{name}.{}", self.idx),
                     true => name.into(),
                 },
                 linkage,
-                span,
-            )),
+                constant,
+                value: None,
+            }),
         };
 
         self.idx += 1;
 
-        Ok(&resolved.0)
+        Ok(&resolved.name)
     }
 
-    fn get(&self, name: &str) -> Option<&String> {
-        self.vars
-            .iter()
-            .rev()
-            .find_map(|s| s.get(name))
-            .as_ref()
-            .map(|(s, _, _)| s)
+    fn declare_enumerator(&mut self, name: &str, value: i32) -> Result {
+        match self.vars.last_mut().unwrap().entry(name.to_string()) {
+            Entry::Occupied(_) => Err(Error::DuplicateDeclaration(name.to_string())),
+            Entry::Vacant(vacant_entry) => {
+                vacant_entry.insert(Binding {
+                    name: name.to_string(),
+                    linkage: false,
+                    constant: false,
+                    value: Some(value),
+                });
+                Ok(())
+            }
+        }
+    }
+
+    /// Declares the structure or union tag of `ty` in the current scope, reusing the type if
+    /// the tag has already been declared there.
+    fn declare_tag(&mut self, ty: &StructType, definition: bool) -> Result<StructType> {
+        let tag = ty.tag();
+        let unique = format!("{tag}.{}", self.idx);
+        let (declared, defined) = self
+            .tags
+            .last_mut()
+            .unwrap()
+            .entry(tag.to_string())
+            .or_insert_with(|| match ty.is_union() {
+                true => (StructType::new_union(unique), false),
+                false => (StructType::new(unique), false),
+            });
+        if declared.is_union() != ty.is_union() {
+            return Err(Error::TagKindMismatch(tag.to_string()));
+        }
+        if definition {
+            if *defined {
+                return Err(Error::DuplicateDeclaration(format!("struct {tag}")));
+            }
+            *defined = true;
+        }
+        let declared = declared.clone();
+        self.idx += 1;
+        Ok(declared)
+    }
+
+    /// Resolves a reference to a structure or union tag, declaring it in the current scope if
+    /// it is not visible.
+    fn resolve_tag(&mut self, ty: &StructType) -> Result<StructType> {
+        match self.tags.iter().rev().find_map(|s| s.get(ty.tag())) {
+            Some((declared, _)) if declared.is_union() != ty.is_union() => {
+                Err(Error::TagKindMismatch(ty.tag().to_string()))
+            }
+            Some((declared, _)) => Ok(declared.clone()),
+            None => self.declare_tag(ty, false),
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Binding> {
+        self.vars.iter().rev().find_map(|s| s.get(name))
     }
 
     fn nest(&mut self, f: impl FnOnce(&mut Self) -> Result) -> Result {
@@ -86,11 +157,13 @@ impl Scope {
     }
 
     fn push(&mut self) {
-        self.vars.push(Default::default())
+        self.vars.push(Default::default());
+        self.tags.push(Default::default());
     }
 
     fn pop(&mut self) {
         self.vars.pop().unwrap();
+        self.tags.pop().unwrap();
     }
 
     const fn is_file(&self) -> bool {
@@ -98,6 +171,26 @@ impl Scope {
     }
 }
 
+fn resolve_type(ty: &mut Type, scope: &mut Scope) -> Result {
+    match ty {
+        // builtin structures such as the element of `va_list` are complete already
+        Type::Struct(s) if s.layout().is_some() => {}
+        Type::Struct(s) => *s = scope.resolve_tag(s)?,
+        Type::Pointer(referenced) | Type::Qualified(referenced, _) => {
+            resolve_type(referenced, scope)?
+        }
+        Type::Array { element, .. } => resolve_type(element, scope)?,
+        Type::Function { params, ret, .. } => {
+            for param in params.iter_mut() {
+                resolve_type(param, scope)?;
+            }
+            resolve_type(ret, scope)?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
 fn visit_expr(expression: &mut Expression, scope: &mut Scope) -> Result {
     match expression {
         Expression::Unary { op, expr, .. } => match op {
@@ -111,11 +204,19 @@ fn visit_expr(expression: &mut Expression, scope: &mut Scope) -> Result {
             visit_expr(lhs, scope)?;
             visit_expr(rhs, scope)?;
         }
-        Expression::Var { name, .. } => {
-            *name = scope
-                .get(name)
-                .ok_or_else(|| Error::UnresolvedReference(name.clone()))?
-                .clone();
+        Expression::Var { name, span } => {
+            let binding = scope
+                .lookup(name)
+                .ok_or_else(|| Error::UnresolvedReference(name.clone()))?;
+            match binding.value {
+                Some(value) => {
+                    *expression = Expression::Constant {
+                        constant: Constant::Int(value),
+                        span: *span,
+                    }
+                }
+                None => *name = binding.name.clone(),
+            }
         }
         Expression::Assignment { lhs, rhs, .. }
         | Expression::CompoundAssignment { lhs, rhs, .. } => {
@@ -132,21 +233,46 @@ fn visit_expr(expression: &mut Expression, scope: &mut Scope) -> Result {
             visit_expr(if_true, scope)?;
             visit_expr(if_false, scope)?;
         }
-        Expression::Constant { .. } => {}
+        Expression::Constant { .. } | Expression::String { .. } => {}
+        Expression::SizeOfType { ty, .. } => resolve_type(ty, scope)?,
         Expression::FunctionCall {
             function, params, ..
         } => {
             visit_expr(function, scope)?;
-            match function.as_ref() {
-                Expression::Var { name, .. } if scope.get(name).is_some() => {}
-                expr => return Err(Error::NonIdentifierCall(expr.span())),
-            }
             for expr in params.iter_mut() {
                 visit_expr(expr, scope)?;
             }
         }
-        Expression::Cast { expr, .. } => {
+        Expression::Cast { to, expr, .. } => {
+            resolve_type(to, scope)?;
+            visit_expr(expr, scope)?;
+        }
+        Expression::Dereference { expr, .. }
+        | Expression::SizeOf { expr, .. }
+        | Expression::Dot { expr, .. }
+        | Expression::Arrow { expr, .. }
+        | Expression::VaStart { ap: expr, .. }
+        | Expression::VaEnd { ap: expr, .. } => {
+            visit_expr(expr, scope)?;
+        }
+        Expression::VaArg { ap, ty, .. } => {
+            visit_expr(ap, scope)?;
+            resolve_type(ty, scope)?;
+        }
+        Expression::VaCopy { dst, src, .. } => {
+            visit_expr(dst, scope)?;
+            visit_expr(src, scope)?;
+        }
+        Expression::Subscript { array, index, .. } => {
+            visit_expr(array, scope)?;
+            visit_expr(index, scope)?;
+        }
+        Expression::AddrOf { expr, .. } => {
+            // checked after resolving, since enumerators are not lvalues
             visit_expr(expr, scope)?;
+            if !is_lvalue(expr) {
+                return Err(Error::AddressOfNonLvalue(expr.span()));
+            }
         }
     }
     Ok(())
@@ -161,9 +287,8 @@ fn visit_optional_expression(expression: &mut Option<Expression>, scope: &mut Sc
 
 fn visit_statement(statement: &mut Statement, scope: &mut Scope) -> Result {
     match statement {
-        Statement::Return(expression) | Statement::Expression(expression) => {
-            visit_expr(expression, scope)
-        }
+        Statement::Return { expr, .. } => visit_optional_expression(expr, scope),
+        Statement::Expression(expression) => visit_expr(expression, scope),
         Statement::Null
         | Statement::Goto { .. }
         | Statement::Break { .. }
@@ -215,7 +340,7 @@ fn visit_statement(statement: &mut Statement, scope: &mut Scope) -> Result {
             body,
             label: _,
         } => scope.nest(|scope| {
-            match init {
+            match init.as_mut() {
                 ForInit::Decl(variable_declaration) => {
                     if variable_declaration.storage.is_some() {
                         return Err(Error::ForInitStorageSpecifier {
@@ -259,25 +384,43 @@ fn visit_variable_decl(decl: &mut VariableDeclaration, scope: &mut Scope) -> Res
         Some(StorageClass::Static) => scope.is_file(),
         None => false,
     };
-    decl.name = scope.declare(&decl.name, linkage, decl.span)?.clone();
+    resolve_type(&mut decl.ty, scope)?;
+    let constant = decl.ty.qualifiers().constant;
+    decl.name = scope.declare(&decl.name, linkage, constant)?.clone();
     if let Some(init) = decl.init.as_mut() {
-        visit_expr(init, scope)?;
-        match storage {
-            Some(StorageClass::Extern) if !scope.is_file() => {
-                return Err(Error::ExternVariableInitializer { span: init.span() });
-            }
-            Some(StorageClass::Static) if !matches!(init, Expression::Constant { .. }) => {
-                return Err(Error::StaticVariableNonConstantInitializer { span: init.span() });
-            }
-            None if scope.is_file() && !matches!(init, Expression::Constant { .. }) => {
-                return Err(Error::StaticVariableNonConstantInitializer { span: init.span() });
-            }
-            _ => {}
+        visit_initializer(init, scope)?;
+        // type checking evaluates the initializers of static variables, which must be constant
+        if storage == Some(StorageClass::Extern) && !scope.is_file() {
+            return Err(Error::ExternVariableInitializer { span: init.span() });
         }
     }
     Ok(())
 }
 
+fn visit_initializer(init: &mut Initializer, scope: &mut Scope) -> Result {
+    match init {
+        Initializer::Single(expr) => visit_expr(expr, scope),
+        Initializer::Compound(inits, _) => inits
+            .iter_mut()
+            .try_for_each(|init| visit_initializer(init, scope)),
+    }
+}
+
+/// The value of an enumerator's initializer, which must be an integer constant.
+fn enumerator_value(expr: &Expression) -> Option<i32> {
+    match expr {
+        Expression::Constant { constant, .. } if constant.ty().is_integral() => {
+            Some(constant.as_long() as i32)
+        }
+        Expression::Unary {
+            op: UnaryOperator::Minus,
+            expr,
+            ..
+        } => enumerator_value(expr).map(i32::wrapping_neg),
+        _ => None,
+    }
+}
+
 fn visit_block(block: &mut Block, scope: &mut Scope) -> Result {
     for item in block.items.iter_mut() {
         match item {
@@ -292,21 +435,49 @@ fn visit_block(block: &mut Block, scope: &mut Scope) -> Result {
 fn visit_decl(decl: &mut Declaration, scope: &mut Scope) -> Result {
     match decl {
         Declaration::Variable(decl) => visit_variable_decl(decl, scope),
+        Declaration::Struct(StructDeclaration { ty, members, .. }) => {
+            *ty = scope.declare_tag(ty, members.is_some())?;
+            for (member, _, _) in members.iter_mut().flatten() {
+                resolve_type(member, scope)?;
+            }
+            Ok(())
+        }
+        // typedef names are replaced by their types during parsing
+        Declaration::Typedef(_) => Ok(()),
+        Declaration::Enum(EnumDeclaration { enumerators, .. }) => {
+            let mut next = 0;
+            for (name, value, _) in enumerators.iter_mut() {
+                if let Some(expr) = value {
+                    visit_expr(expr, scope)?;
+                    next = enumerator_value(expr)
+                        .ok_or(Error::EnumeratorNonConstant { span: expr.span() })?;
+                }
+                scope.declare_enumerator(name, next)?;
+                next = next.wrapping_add(1);
+            }
+            Ok(())
+        }
         Declaration::Function(FunctionDeclaration {
             identifier,
             body,
             params,
+            ret,
             storage,
-            span,
             ..
         }) => {
-            scope.declare(identifier, true, *span)?;
+            scope.declare(identifier, true, false)?;
+            resolve_type(ret, scope)?;
+            for (ty, _, _) in params.iter_mut() {
+                resolve_type(ty, scope)?;
+            }
 
             let is_file = scope.is_file();
 
             scope.push();
-            for (_, name, span) in params.iter_mut() {
-                *name = scope.declare(name, false, *span)?.clone();
+            for (ty, name, _) in params.iter_mut() {
+                *name = scope
+                    .declare(name, false, ty.qualifiers().constant)?
+                    .clone();
             }
             if !is_file {
                 if body.is_some() {
@@ -328,17 +499,42 @@ fn visit_decl(decl: &mut Declaration, scope: &mut Scope) -> Result {
 fn visit_assignment_lhs(expr: &mut Expression, scope: &mut Scope) -> Result {
     match expr {
         Expression::Var { name, span } => {
-            *name = scope
-                .get(name)
-                .ok_or_else(|| Error::UnresolvedAssignment(name.clone(), *span))?
-                .clone();
+            let binding = scope
+                .lookup(name)
+                .ok_or_else(|| Error::UnresolvedAssignment(name.clone(), *span))?;
+            if binding.value.is_some() {
+                return Err(Error::NonIdentifierAssignment(*span));
+            }
+            if binding.constant {
+                return Err(Error::ConstAssignment(name.clone(), *span));
+            }
+            *name = binding.name.clone();
 
             Ok(())
         }
+        Expression::Dereference { .. }
+        | Expression::Subscript { .. }
+        | Expression::Arrow { .. } => visit_expr(expr, scope),
+        Expression::Dot { .. } if is_lvalue(expr) => visit_expr(expr, scope),
         expr => Err(Error::NonIdentifierAssignment(expr.span())),
     }
 }
 
+fn is_lvalue(expr: &Expression) -> bool {
+    match expr {
+        // a member of a structure is an lvalue if the structure is
+        Expression::Dot { expr, .. } => is_lvalue(expr),
+        expr => matches!(
+            expr,
+            Expression::Var { .. }
+                | Expression::Dereference { .. }
+                | Expression::Subscript { .. }
+                | Expression::Arrow { .. }
+                | Expression::String { .. }
+        ),
+    }
+}
+
 pub fn run(program: &mut Program) -> Result {
     let mut scope = Scope::default();
     scope.push();
@@ -28,7 +28,7 @@ fn visit_block(block: &Block, labels: &mut HashSet<String>, error: bool) -> Resu
 fn visit_statement(statement: &Statement, labels: &mut HashSet<String>, error: bool) -> Result {
     match statement {
         Statement::Compound(block) => visit_block(block, labels, error)?,
-        Statement::Return(_) => {}
+        Statement::Return { .. } => {}
         Statement::Expression(_) => {}
         Statement::Null => {}
         Statement::Break { .. } => {}
@@ -90,7 +90,10 @@ fn visit_statement(statement: &Statement, labels: &mut HashSet<String>, error: b
 
 fn visit_decl(decl: &Declaration) -> Result {
     match decl {
-        Declaration::Variable(_) => {}
+        Declaration::Variable(_)
+        | Declaration::Struct(_)
+        | Declaration::Enum(_)
+        | Declaration::Typedef(_) => {}
         Declaration::Function(function_declaration) => {
             let mut labels = HashSet::new();
             if let Some(body) = function_declaration.body.as_ref() {
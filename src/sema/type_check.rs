@@ -1,7 +1,11 @@
 use std::{
-    collections::{HashMap, hash_map::Entry},
+    cmp::Ordering,
+    collections::{HashMap, HashSet, hash_map::Entry},
+    fmt::Debug,
+    hash::Hash,
     mem::take,
     ops::{Deref, DerefMut},
+    sync::{Arc, OnceLock},
 };
 
 use miette::{Diagnostic, SourceSpan};
@@ -10,8 +14,8 @@ use crate::{
     assembly_gen::Width,
     ast::{
         BinaryOperator, Block, BlockItem, Constant, Declaration, Expression, ForInit,
-        FunctionDeclaration, Program, Spanned, Statement, StorageClass, UnaryOperator,
-        VariableDeclaration,
+        FunctionDeclaration, Initializer, Program, Spanned, Statement, StorageClass,
+        StructDeclaration, UnaryOperator, VariableDeclaration,
     },
 };
 
@@ -36,6 +40,13 @@ pub enum TypeCheckError {
         span: SourceSpan,
         position: &'static str,
     },
+    #[error("expected scalar expression in {position}")]
+    NonScalar {
+        actual: Type,
+        #[label("is {actual:?}")]
+        span: SourceSpan,
+        position: &'static str,
+    },
     #[error("expected integral expression in {position}")]
     NonIntegral {
         actual: Type,
@@ -92,12 +103,134 @@ pub enum TypeCheckError {
         #[label(collection, "previously declared here as {expected:?}")]
         old: Vec<SourceSpan>,
     },
+    #[error("cannot dereference non-pointer")]
+    DereferenceNonPointer {
+        actual: Type,
+        #[label("is {actual:?}")]
+        span: SourceSpan,
+    },
+    #[error("invalid cast from {from:?} to {to:?}")]
+    InvalidCast {
+        from: Type,
+        to: Type,
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("incompatible operand types {lhs:?} and {rhs:?}")]
+    IncompatibleTypes {
+        lhs: Type,
+        rhs: Type,
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("cannot assign to array")]
+    ArrayAssignment {
+        #[label("is {actual:?}")]
+        span: SourceSpan,
+        actual: Type,
+    },
+    #[error("cannot assign to function")]
+    FunctionAssignment {
+        #[label("is {actual:?}")]
+        span: SourceSpan,
+        actual: Type,
+    },
+    #[error("cannot modify a read-only object")]
+    ConstModification {
+        #[label("is {actual:?}")]
+        span: SourceSpan,
+        actual: Type,
+    },
+    #[error("conversion from {actual:?} to {expected:?} discards qualifiers")]
+    DiscardsQualifiers {
+        expected: Type,
+        actual: Type,
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("too many elements in initializer for {ty:?}")]
+    TooManyInitializers {
+        ty: Type,
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("cannot initialize {ty:?} with a compound initializer")]
+    ScalarCompoundInitializer {
+        ty: Type,
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("variable {name} has incomplete type {ty:?}")]
+    IncompleteType {
+        name: String,
+        ty: Type,
+        #[label("declared here")]
+        span: SourceSpan,
+    },
+    #[error("{position} requires a complete object type")]
+    IncompleteOperand {
+        ty: Type,
+        #[label("is {ty:?}")]
+        span: SourceSpan,
+        position: &'static str,
+    },
+    #[error("return with a value in a function returning void")]
+    ReturnValueFromVoid {
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("return without a value in a function returning {ty:?}")]
+    MissingReturnValue {
+        ty: Type,
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("duplicate member {name} in structure")]
+    DuplicateMember {
+        name: String,
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("{ty:?} has no member named {member}")]
+    NoSuchMember {
+        ty: Type,
+        member: String,
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("member access on non-structure")]
+    NonStructMember {
+        actual: Type,
+        #[label("is {actual:?}")]
+        span: SourceSpan,
+    },
     #[error("initializer on local extern declaration of {name}")]
     InitializerOnLocalExtern {
         name: String,
         #[label("here")]
         span: SourceSpan,
     },
+    #[error("initializers for static variables must be constant")]
+    NonConstantInitializer {
+        #[label("not a constant")]
+        span: SourceSpan,
+    },
+    #[error("case labels must be integer constants")]
+    NonConstantCase {
+        #[label("not a constant")]
+        span: SourceSpan,
+    },
+    #[error("va_start used in a function without variable arguments")]
+    VaStartFixedArguments {
+        #[label("here")]
+        span: SourceSpan,
+    },
+    #[error("va_arg cannot take an argument of type {ty:?}")]
+    VaArgType {
+        ty: Type,
+        #[label("here")]
+        span: SourceSpan,
+    },
 }
 
 #[derive(Debug, Clone, Default)]
@@ -156,7 +289,7 @@ impl SymbolTable {
                                 });
                             }
                         }
-                        match (&old_init, init) {
+                        match (&*old_init, &init) {
                             (Initial::Some(_) | Initial::None, Initial::Tentative)
                             | (Initial::None, Initial::None) => {}
                             (Initial::Tentative, _) | (Initial::None, Initial::Some(_)) => {
@@ -274,6 +407,9 @@ struct Checker {
     symbols: SymbolTable,
     switches: HashMap<String, Type>,
     return_type: Type,
+    /// Whether the function being checked takes variable arguments.
+    variadic: bool,
+    string_literals: usize,
 
     toplevel: bool,
 }
@@ -291,7 +427,7 @@ impl Symbol {
             SymbolAttributes::Function { global, .. } | SymbolAttributes::Static { global, .. } => {
                 global
             }
-            SymbolAttributes::Local => false,
+            SymbolAttributes::Local | SymbolAttributes::Constant(_) => false,
         }
     }
 }
@@ -307,21 +443,36 @@ pub enum SymbolAttributes {
         init: Initial,
         global: bool,
     },
+    /// A read-only object holding a string literal.
+    Constant(StaticInit),
     Local,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Initial {
     Tentative,
-    Some(Constant),
+    Some(Vec<StaticInit>),
     None,
 }
 
+/// One piece of a flattened static initializer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StaticInit {
+    Scalar(Constant),
+    /// `n` bytes of zeroes.
+    Zero(u64),
+    /// The bytes of a string, optionally followed by a null terminator.
+    String(Vec<u8>, bool),
+    /// The address of a static object or function, plus a byte offset into it.
+    Pointer(String, i64),
+}
+
 impl Initial {
-    pub fn constant(&self) -> Constant {
+    /// The initializer list to emit for a variable of type `ty`.
+    pub fn values(&self, ty: &Type) -> Vec<StaticInit> {
         match self {
-            Initial::Tentative | Initial::None => Constant::Int(0),
-            Initial::Some(c) => *c,
+            Initial::Tentative | Initial::None => vec![StaticInit::Zero(ty.size())],
+            Initial::Some(inits) => inits.clone(),
         }
     }
 
@@ -346,19 +497,171 @@ impl From<StorageClass> for Linkage {
 }
 
 impl Constant {
-    fn cast(&mut self, to: &Type) -> Result {
+    /// Converts the constant to `to`, as C would at runtime.
+    pub fn cast(&mut self, to: &Type) {
         use Constant::*;
         use Type as T;
-        match (&self, to) {
-            (_, T::Function { .. }) => unreachable!(),
+        match (&self, to.unqualified()) {
+            (_, T::Function { .. } | T::Array { .. } | T::Void | T::Struct(_)) => unreachable!(),
+            (_, T::Qualified(..)) => unreachable!(),
             (_, T::Int) => *self = Int(self.as_long() as i32),
             (_, T::UInt) => *self = UInt(self.as_long() as u32),
             (_, T::Long) => *self = Long(self.as_long()),
-            (_, T::ULong) => *self = ULong(self.as_long() as u64),
-            (Double(_), T::Double) => {}
-            (_, T::Double) => *self = Double(self.as_long() as f64),
+            (Float(_) | Double(_), T::ULong) => *self = ULong(self.as_double() as u64),
+            (_, T::ULong | T::Pointer(_)) => *self = ULong(self.as_long() as u64),
+            (_, T::Char | T::SChar) => *self = Char(self.as_long() as i8),
+            (_, T::UChar) => *self = UChar(self.as_long() as u8),
+            (_, T::Short) => *self = Short(self.as_long() as i16),
+            (_, T::UShort) => *self = UShort(self.as_long() as u16),
+            // booleans are represented as unsigned chars
+            (_, T::Bool) => *self = UChar((self.as_double() != 0.0) as u8),
+            (Double(_), T::Double) | (Float(_), T::Float) => {}
+            (_, T::Double) => *self = Double(self.as_double()),
+            // integers are rounded once, straight to float
+            (ULong(v), T::Float) => *self = Float(*v as f32),
+            (Double(_), T::Float) => *self = Float(self.as_double() as f32),
+            (_, T::Float) => *self = Float(self.as_long() as f32),
         }
-        Ok(())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.as_double() == 0.0
+    }
+
+    /// Applies `op` as C would at runtime. Integer arithmetic wraps around.
+    pub fn unary(self, op: UnaryOperator) -> Constant {
+        let ty = self.ty();
+        let mut result = match op {
+            UnaryOperator::Minus if ty.is_floating() => Constant::Double(-self.as_double()),
+            UnaryOperator::Minus => Constant::Long(self.as_long().wrapping_neg()),
+            UnaryOperator::Complement => Constant::Long(!self.as_long()),
+            UnaryOperator::Not => return Constant::Int(self.is_zero() as i32),
+            op => unreachable!("{op:?} has no constant value"),
+        };
+        result.cast(&ty);
+        result
+    }
+
+    /// Applies `op` to operands of the same type, as C would at runtime, except for shifts
+    /// whose right operand may have any integer type. Returns `None` for a division by zero,
+    /// whose result is undefined.
+    pub fn binary(self, op: BinaryOperator, rhs: Constant) -> Option<Constant> {
+        use BinaryOperator::*;
+        let ty = self.ty();
+        let ordering = if ty.is_floating() {
+            self.as_double().partial_cmp(&rhs.as_double())
+        } else if ty.signed() {
+            Some(self.as_long().cmp(&rhs.as_long()))
+        } else {
+            Some((self.as_long() as u64).cmp(&(rhs.as_long() as u64)))
+        };
+        let truth = match op {
+            Equals => ordering == Some(Ordering::Equal),
+            NotEqual => ordering != Some(Ordering::Equal),
+            LessThan => ordering == Some(Ordering::Less),
+            LessThanOrEqual => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+            GreaterThan => ordering == Some(Ordering::Greater),
+            GreaterThanOrEqual => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+            And => !self.is_zero() && !rhs.is_zero(),
+            Or => !self.is_zero() || !rhs.is_zero(),
+            _ => return self.arithmetic(op, rhs),
+        };
+        Some(Constant::Int(truth as i32))
+    }
+
+    /// Computes an arithmetic or bitwise operation in the widest type of the same kind, then
+    /// converts the result back, which gives the wrapping behaviour of the narrower type.
+    fn arithmetic(self, op: BinaryOperator, rhs: Constant) -> Option<Constant> {
+        use BinaryOperator::*;
+        let ty = self.ty();
+        let mut result = if ty.is_floating() {
+            let (l, r) = (self.as_double(), rhs.as_double());
+            Constant::Double(match op {
+                Add => l + r,
+                Subtract => l - r,
+                Multiply => l * r,
+                Divide => l / r,
+                op => unreachable!("{op:?} on {ty:?}"),
+            })
+        } else {
+            let (l, r) = (self.as_long(), rhs.as_long());
+            // shifting by at least the width is undefined, so any result will do
+            let shift = r as u32;
+            let value = match op {
+                Add => l.wrapping_add(r),
+                Subtract => l.wrapping_sub(r),
+                Multiply => l.wrapping_mul(r),
+                Divide | Remainder if r == 0 => return None,
+                Divide if ty.signed() => l.wrapping_div(r),
+                Divide => ((l as u64) / (r as u64)) as i64,
+                Remainder if ty.signed() => l.wrapping_rem(r),
+                Remainder => ((l as u64) % (r as u64)) as i64,
+                BitwiseAnd => l & r,
+                BitwiseOr => l | r,
+                Xor => l ^ r,
+                LeftShift => l.wrapping_shl(shift),
+                RightShift if ty.signed() => l.wrapping_shr(shift),
+                RightShift => (l as u64).wrapping_shr(shift) as i64,
+                op => unreachable!("{op:?} is not arithmetic"),
+            };
+            Constant::Long(value)
+        };
+        result.cast(&ty);
+        Some(result)
+    }
+}
+
+/// Evaluates a constant expression, or gives the span of the part of it that is not constant.
+/// Type checking has already made every conversion explicit, so the operands of each operator
+/// have the type it operates on.
+fn evaluate(expr: &Expression) -> std::result::Result<Constant, SourceSpan> {
+    match expr {
+        Expression::Constant { constant, .. } => Ok(*constant),
+        Expression::Cast { to, expr, .. } => {
+            let mut constant = evaluate(expr)?;
+            constant.cast(to);
+            Ok(constant)
+        }
+        Expression::Unary {
+            op: op @ (UnaryOperator::Minus | UnaryOperator::Complement | UnaryOperator::Not),
+            expr,
+            ..
+        } => Ok(evaluate(expr)?.unary(*op)),
+        // the right operand is not evaluated if the left one decides the result
+        Expression::Binary {
+            op: op @ (BinaryOperator::And | BinaryOperator::Or),
+            lhs,
+            rhs,
+            ..
+        } => {
+            let lhs = evaluate(lhs)?;
+            match (op, lhs.is_zero()) {
+                (BinaryOperator::And, true) => Ok(Constant::Int(0)),
+                (BinaryOperator::Or, false) => Ok(Constant::Int(1)),
+                _ => Ok(Constant::Int(!evaluate(rhs)?.is_zero() as i32)),
+            }
+        }
+        // constant expressions may not contain a comma operator
+        Expression::Binary {
+            op: BinaryOperator::Comma,
+            span,
+            ..
+        } => Err(*span),
+        Expression::Binary { op, lhs, rhs, span } => {
+            let lhs = evaluate(lhs)?;
+            let rhs = evaluate(rhs)?;
+            lhs.binary(*op, rhs).ok_or(*span)
+        }
+        Expression::Ternary {
+            cond,
+            if_true,
+            if_false,
+            ..
+        } => match evaluate(cond)?.is_zero() {
+            false => evaluate(if_true),
+            true => evaluate(if_false),
+        },
+        expr => Err(expr.span()),
     }
 }
 
@@ -379,52 +682,94 @@ impl Checker {
             Declaration::Function(function_declaration) => {
                 self.visit_function_declaration(function_declaration)
             }
+            Declaration::Struct(struct_declaration) => {
+                self.visit_struct_declaration(struct_declaration)
+            }
+            // enumerators are replaced by their values during name resolution
+            Declaration::Enum(_) => Ok(()),
+            // typedef names are replaced by their types during parsing
+            Declaration::Typedef(_) => Ok(()),
+        }
+    }
+
+    /// Lays out the members of a structure definition, aligning each to its own alignment and
+    /// padding the whole to the largest of them. The members of a union all overlap at the
+    /// start.
+    fn visit_struct_declaration(&mut self, decl: &mut StructDeclaration) -> Result {
+        let Some(members) = &decl.members else {
+            return Ok(());
+        };
+        let mut names = HashSet::new();
+        let mut layout = StructLayout {
+            members: vec![],
+            size: 0,
+            alignment: 1,
+        };
+        for (ty, name, span) in members {
+            if !names.insert(name) {
+                return Err(TypeCheckError::DuplicateMember {
+                    name: name.clone(),
+                    span: *span,
+                });
+            }
+            if !ty.is_complete() {
+                return Err(TypeCheckError::IncompleteType {
+                    name: name.clone(),
+                    ty: ty.clone(),
+                    span: *span,
+                });
+            }
+            let offset = match decl.ty.is_union() {
+                true => 0,
+                false => layout.size.next_multiple_of(ty.alignment()),
+            };
+            layout.alignment = layout.alignment.max(ty.alignment());
+            layout.size = layout.size.max(offset + ty.size());
+            layout.members.push(Member {
+                name: name.clone(),
+                ty: ty.clone(),
+                offset,
+            });
         }
+        layout.size = layout.size.next_multiple_of(layout.alignment);
+        decl.ty.define(layout);
+        Ok(())
     }
 
     fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration) -> Result {
+        self.complete_array_type(decl)?;
+        if !decl.ty.is_complete() && decl.storage != Some(StorageClass::Extern) {
+            return Err(TypeCheckError::IncompleteType {
+                name: decl.name.clone(),
+                ty: decl.ty.clone(),
+                span: decl.span,
+            });
+        }
         if self.toplevel {
-            if let Some(init) = decl.init.as_mut() {
-                self.make_cast(init, &decl.ty)?;
-            }
+            let init = match decl.init.as_mut() {
+                Some(init) => Some(self.static_initializer(init, &decl.ty)?),
+                None => None,
+            };
             match decl.storage {
                 Some(StorageClass::Extern) => self.symbols.declare_static(
                     &decl.name,
                     decl.ty.clone(),
                     None,
-                    match decl.init {
-                        Some(Expression::Constant { constant, .. }) => Initial::Some(constant),
-                        None => Initial::Tentative,
-                        Some(_) => {
-                            todo!("non-constant initializer on global variable {}", decl.name)
-                        }
-                    },
+                    init.map_or(Initial::Tentative, Initial::Some),
                     decl.span,
                 )?,
                 None => self.symbols.declare_static(
                     &decl.name,
                     decl.ty.clone(),
                     Some(true),
-                    match decl.init {
-                        Some(Expression::Constant { constant, .. }) => Initial::Some(constant),
-                        None => Initial::None,
-                        Some(_) => {
-                            todo!("non-constant initializer on global variable {}", decl.name)
-                        }
-                    },
+                    init.map_or(Initial::None, Initial::Some),
                     decl.span,
                 )?,
                 Some(StorageClass::Static) => self.symbols.declare_static(
                     &decl.name,
                     decl.ty.clone(),
                     Some(false),
-                    match decl.init {
-                        Some(Expression::Constant { constant, .. }) => Initial::Some(constant),
-                        None => Initial::Tentative,
-                        Some(_) => {
-                            todo!("non-constant initializer on static variable {}", decl.name)
-                        }
-                    },
+                    init.map_or(Initial::Tentative, Initial::Some),
                     decl.span,
                 )?,
             }
@@ -447,25 +792,22 @@ impl Checker {
                     )?;
                 }
                 Some(StorageClass::Static) => {
-                    let init = match decl.init {
-                        Some(Expression::Constant { constant, .. }) => Initial::Some(constant),
-                        None => Initial::Some(Constant::Int(0)),
-                        Some(_) => {
-                            todo!("non-constant initializer on static variable {}", decl.name)
-                        }
+                    let init = match decl.init.as_mut() {
+                        Some(init) => self.static_initializer(init, &decl.ty)?,
+                        None => vec![StaticInit::Zero(decl.ty.size())],
                     };
                     self.symbols.declare_static(
                         &decl.name,
                         decl.ty.clone(),
                         Some(false),
-                        init,
+                        Initial::Some(init),
                         decl.span,
                     )?;
                 }
                 None => {
                     self.symbols.declare_automatic(&decl.name, decl.ty.clone());
-                    if let Some(expr) = decl.init.as_mut() {
-                        self.make_cast(expr, &decl.ty)?;
+                    if let Some(init) = decl.init.as_mut() {
+                        self.visit_initializer(init, &decl.ty)?;
                     }
                 }
             }
@@ -474,28 +816,277 @@ impl Checker {
         Ok(())
     }
 
+    /// Fills in the size of an array declared with `[]` from its initializer. Only `extern`
+    /// declarations may leave it unspecified.
+    fn complete_array_type(&mut self, decl: &mut VariableDeclaration) -> Result {
+        let Type::Array { size, .. } = &mut decl.ty else {
+            return Ok(());
+        };
+        if *size != 0 {
+            return Ok(());
+        }
+        match &decl.init {
+            Some(Initializer::Compound(inits, _)) if !inits.is_empty() => {
+                *size = inits.len() as u64;
+                Ok(())
+            }
+            Some(Initializer::Single(Expression::String { value, .. })) => {
+                *size = value.len() as u64 + 1;
+                Ok(())
+            }
+            None if decl.storage == Some(StorageClass::Extern) => Ok(()),
+            _ => Err(TypeCheckError::IncompleteType {
+                name: decl.name.clone(),
+                ty: decl.ty.clone(),
+                span: decl.span,
+            }),
+        }
+    }
+
+    fn visit_initializer(&mut self, init: &mut Initializer, ty: &Type) -> Result {
+        match (init, ty.unqualified()) {
+            (
+                Initializer::Single(Expression::String { value, span }),
+                Type::Array { element, size },
+            ) if element.is_character() => {
+                if value.len() as u64 > *size {
+                    return Err(TypeCheckError::TooManyInitializers {
+                        ty: ty.clone(),
+                        span: *span,
+                    });
+                }
+            }
+            (Initializer::Single(expr), _) => {
+                self.convert_by_assignment(expr, ty)?;
+            }
+            (Initializer::Compound(inits, span), Type::Array { element, size }) => {
+                if inits.len() as u64 > *size {
+                    return Err(TypeCheckError::TooManyInitializers {
+                        ty: ty.clone(),
+                        span: *span,
+                    });
+                }
+                for init in inits {
+                    self.visit_initializer(init, element)?;
+                }
+            }
+            (Initializer::Compound(inits, span), Type::Struct(s)) => {
+                let members = s.initialized_members();
+                if inits.len() > members.len() {
+                    return Err(TypeCheckError::TooManyInitializers {
+                        ty: ty.clone(),
+                        span: *span,
+                    });
+                }
+                for (init, member) in inits.iter_mut().zip(members) {
+                    self.visit_initializer(init, &member.ty)?;
+                }
+            }
+            (Initializer::Compound(_, span), _) => {
+                return Err(TypeCheckError::ScalarCompoundInitializer {
+                    ty: ty.clone(),
+                    span: *span,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn static_initializer(&mut self, init: &mut Initializer, ty: &Type) -> Result<Vec<StaticInit>> {
+        match (init, ty.unqualified()) {
+            (
+                Initializer::Single(Expression::String { value, span }),
+                Type::Array { element, size },
+            ) if element.is_character() => {
+                let len = value.len() as u64;
+                if len > *size {
+                    return Err(TypeCheckError::TooManyInitializers {
+                        ty: ty.clone(),
+                        span: *span,
+                    });
+                }
+                let mut values = vec![StaticInit::String(value.clone(), len < *size)];
+                if len + 1 < *size {
+                    values.push(StaticInit::Zero(*size - len - 1));
+                }
+                Ok(values)
+            }
+            (Initializer::Single(expr), _) => {
+                self.convert_by_assignment(expr, ty)?;
+                if let Some((name, offset, _)) = self.static_pointer(expr) {
+                    return Ok(vec![StaticInit::Pointer(name, offset)]);
+                }
+                match evaluate(expr) {
+                    Ok(constant) => Ok(vec![StaticInit::Scalar(constant)]),
+                    Err(span) => Err(TypeCheckError::NonConstantInitializer { span }),
+                }
+            }
+            (Initializer::Compound(inits, span), Type::Array { element, size }) => {
+                if inits.len() as u64 > *size {
+                    return Err(TypeCheckError::TooManyInitializers {
+                        ty: ty.clone(),
+                        span: *span,
+                    });
+                }
+                let mut values = vec![];
+                for init in inits.iter_mut() {
+                    values.extend(self.static_initializer(init, element)?);
+                }
+                let remaining = *size - inits.len() as u64;
+                if remaining > 0 {
+                    values.push(StaticInit::Zero(remaining * element.size()));
+                }
+                Ok(values)
+            }
+            (Initializer::Compound(inits, span), Type::Struct(s)) => {
+                let members = s.initialized_members();
+                if inits.len() > members.len() {
+                    return Err(TypeCheckError::TooManyInitializers {
+                        ty: ty.clone(),
+                        span: *span,
+                    });
+                }
+                let mut values = vec![];
+                let mut end = 0;
+                for (init, member) in inits.iter_mut().zip(members) {
+                    if member.offset > end {
+                        values.push(StaticInit::Zero(member.offset - end));
+                    }
+                    values.extend(self.static_initializer(init, &member.ty)?);
+                    end = member.offset + member.ty.size();
+                }
+                if ty.size() > end {
+                    values.push(StaticInit::Zero(ty.size() - end));
+                }
+                Ok(values)
+            }
+            (Initializer::Compound(_, span), _) => Err(TypeCheckError::ScalarCompoundInitializer {
+                ty: ty.clone(),
+                span: *span,
+            }),
+        }
+    }
+
+    /// Finds the object that the type checked lvalue `expr` designates, if it lies at a
+    /// constant offset into a static object or a function: the name of that, the byte offset
+    /// and the type of the object designated.
+    fn static_lvalue(&self, expr: &Expression) -> Option<(String, i64, Type)> {
+        match expr {
+            Expression::Var { name, .. } => {
+                let symbol = &self.symbols[name.as_str()];
+                match symbol.attributes {
+                    SymbolAttributes::Local => None,
+                    _ => Some((name.clone(), 0, symbol.ty.clone())),
+                }
+            }
+            Expression::Dereference { expr, .. } => self.static_pointer(expr),
+            Expression::Subscript { array, index, .. } => {
+                let ((name, offset, element), index) = match self.static_pointer(array) {
+                    Some(pointer) => (pointer, index),
+                    None => (self.static_pointer(index)?, array),
+                };
+                let index = evaluate(index).ok()?.as_long();
+                Some((name, offset + index * element.size() as i64, element))
+            }
+            Expression::Dot { expr, member, .. } => {
+                let (name, offset, ty) = self.static_lvalue(expr)?;
+                static_member(name, offset, &ty, member)
+            }
+            Expression::Arrow { expr, member, .. } => {
+                let (name, offset, ty) = self.static_pointer(expr)?;
+                static_member(name, offset, &ty, member)
+            }
+            _ => None,
+        }
+    }
+
+    /// Finds what the type checked pointer `expr` points to, if it is an address constant:
+    /// the name of a static object or a function, the byte offset into it and the type
+    /// pointed to.
+    fn static_pointer(&self, expr: &Expression) -> Option<(String, i64, Type)> {
+        match expr {
+            Expression::AddrOf { expr, .. } => self.static_lvalue(expr),
+            Expression::Cast {
+                to: Type::Pointer(referenced),
+                expr,
+                ..
+            } => {
+                let (name, offset, _) = self.static_pointer(expr)?;
+                Some((name, offset, referenced.as_ref().clone()))
+            }
+            Expression::Binary {
+                op: op @ (BinaryOperator::Add | BinaryOperator::Subtract),
+                lhs,
+                rhs,
+                ..
+            } => {
+                let ((name, offset, referenced), n) = match self.static_pointer(lhs) {
+                    Some(pointer) => (pointer, rhs),
+                    None if matches!(op, BinaryOperator::Add) => (self.static_pointer(rhs)?, lhs),
+                    None => return None,
+                };
+                let mut n = evaluate(n).ok()?.as_long() * referenced.size() as i64;
+                if matches!(op, BinaryOperator::Subtract) {
+                    n = -n;
+                }
+                Some((name, offset + n, referenced))
+            }
+            // arrays and functions decay to a pointer to their first element and to themselves
+            expr => {
+                let (name, offset, ty) = self.static_lvalue(expr)?;
+                match ty.unqualified() {
+                    Type::Array { element, .. } => Some((name, offset, element.as_ref().clone())),
+                    Type::Function { .. } => Some((name, offset, ty)),
+                    _ => None,
+                }
+            }
+        }
+    }
+
     fn visit_function_declaration(&mut self, decl: &mut FunctionDeclaration) -> Result {
         let global = decl.storage.is_none_or(|s| s != StorageClass::Static);
         let defined = decl.body.is_some();
 
         self.symbols.declare_fn(
             &decl.identifier,
+            // qualifiers on parameters only affect them inside the body
             Type::Function {
-                params: decl.params.iter().map(|(ty, _, _)| ty).cloned().collect(),
-                ret: Box::new(decl.ret.clone()),
+                params: decl
+                    .params
+                    .iter()
+                    .map(|(ty, _, _)| ty.unqualified().clone())
+                    .collect(),
+                ret: Box::new(decl.ret.unqualified().clone()),
+                variadic: decl.variadic,
             },
             global,
             defined,
             decl.span,
         )?;
 
-        for (ty, name, _) in decl.params.iter() {
+        for (ty, name, span) in decl.params.iter() {
+            // parameters of a declaration may have incomplete structure types
+            if *ty == Type::Void || defined && !ty.is_complete() {
+                return Err(TypeCheckError::IncompleteType {
+                    name: name.clone(),
+                    ty: ty.clone(),
+                    span: *span,
+                });
+            }
             self.symbols.declare_automatic(name, ty.clone());
         }
 
         if let Some(body) = decl.body.as_mut() {
+            if decl.ret.is_struct() && !decl.ret.is_complete() {
+                return Err(TypeCheckError::IncompleteType {
+                    name: decl.identifier.clone(),
+                    ty: decl.ret.clone(),
+                    span: decl.span,
+                });
+            }
             self.toplevel = false;
-            self.return_type = decl.ret.clone();
+            self.return_type = decl.ret.unqualified().clone();
+            self.variadic = decl.variadic;
 
             self.visit_block(body)?
         }
@@ -514,9 +1105,16 @@ impl Checker {
 
     fn visit_statement(&mut self, statement: &mut Statement) -> Result {
         match statement {
-            Statement::Return(expression) => {
-                self.make_cast(expression, &self.return_type)?;
-            }
+            Statement::Return { expr, span } => match (expr, self.return_type.clone()) {
+                (Some(expr), Type::Void) => {
+                    return Err(TypeCheckError::ReturnValueFromVoid { span: expr.span() });
+                }
+                (Some(expr), return_type) => {
+                    self.convert_by_assignment(expr, &return_type)?;
+                }
+                (None, Type::Void) => {}
+                (None, ty) => return Err(TypeCheckError::MissingReturnValue { ty, span: *span }),
+            },
             Statement::Expression(expression) => {
                 self.visit_expression(expression)?;
             }
@@ -525,7 +1123,7 @@ impl Checker {
                 if_true: statement,
                 if_false: statement1,
             } => {
-                self.visit_numeric_expression(expression, "if condition")?;
+                self.visit_scalar_expression(expression, "if condition")?;
                 if let Some(statement) = statement1 {
                     self.visit_statement(statement)?;
                 }
@@ -542,7 +1140,7 @@ impl Checker {
                 statement,
                 label: _,
             } => {
-                self.visit_numeric_expression(expression, "while loop control condition")?;
+                self.visit_scalar_expression(expression, "while loop control condition")?;
                 self.visit_statement(statement)?
             }
             Statement::DoWhile {
@@ -551,7 +1149,7 @@ impl Checker {
                 label: _,
             } => {
                 self.visit_statement(statement)?;
-                self.visit_numeric_expression(expression, "do-while loop control condition")?;
+                self.visit_scalar_expression(expression, "do-while loop control condition")?;
             }
             Statement::For {
                 init,
@@ -560,7 +1158,7 @@ impl Checker {
                 body,
                 ..
             } => {
-                match init {
+                match init.as_mut() {
                     ForInit::Decl(variable_declaration) => {
                         self.visit_variable_declaration(variable_declaration)?;
                     }
@@ -570,7 +1168,7 @@ impl Checker {
                     ForInit::Expr(None) => {}
                 }
                 if let Some(expression) = condition {
-                    self.visit_numeric_expression(expression, "for loop condition")?;
+                    self.visit_scalar_expression(expression, "for loop condition")?;
                 }
                 if let Some(expression) = post {
                     self.visit_expression(expression)?;
@@ -586,6 +1184,7 @@ impl Checker {
                     expression,
                     "switch statement controlling condition",
                 )?;
+                let ty = self.promote(expression, ty)?;
                 self.switches.insert(label.clone().unwrap(), ty.clone());
                 self.visit_statement(statement)?
             }
@@ -594,11 +1193,15 @@ impl Checker {
                 statement,
                 label,
             } => {
-                self.visit_integral_expression(expression, "case condition")?;
-                self.make_cast(
-                    expression,
-                    self.switches.get(label.as_ref().unwrap()).unwrap(),
-                )?;
+                let actual = self.visit_integral_expression(expression, "case condition")?;
+                let ty = self.switches[label.as_ref().unwrap()].clone();
+                self.make_cast(expression, &actual, &ty)?;
+                let constant = evaluate(expression)
+                    .map_err(|span| TypeCheckError::NonConstantCase { span })?;
+                *expression = Expression::Constant {
+                    constant,
+                    span: expression.span(),
+                };
                 self.visit_statement(statement)?
             }
             Statement::Default {
@@ -612,45 +1215,67 @@ impl Checker {
     }
 
     fn cast_to_lhs(
-        &self,
-        lhs: &mut Expression,
+        &mut self,
+        lt: Type,
         rhs: &mut Expression,
+        rt: Type,
     ) -> miette::Result<Type, TypeCheckError> {
-        let lt = self.visit_expression(lhs)?;
-        let rt = self.visit_expression(rhs)?;
         self.check_cast(&rt, &lt, rhs.span())?;
-        self.make_cast(rhs, &lt)
+        self.make_cast(rhs, &rt, &lt)
     }
 
     fn cast_to_common(
-        &self,
+        &mut self,
         lhs: &mut Expression,
+        lt: Type,
         rhs: &mut Expression,
+        rt: Type,
     ) -> miette::Result<Type, TypeCheckError> {
-        let lt = &self.visit_expression(lhs)?;
-        let rt = &self.visit_expression(rhs)?;
-        self.check_cast(rt, lt, rhs.span())?;
-
-        match (lt, rt) {
-            (Type::Function { .. }, _) | (_, Type::Function { .. }) => unreachable!(),
-
-            (Type::Int, Type::Int) => Ok(Type::Int),
-            (Type::Long, Type::Long) => Ok(Type::Long),
-            (Type::ULong, Type::ULong) => Ok(Type::ULong),
-            (Type::UInt, Type::UInt) => Ok(Type::UInt),
-            (Type::Double, Type::Double) => Ok(Type::Double),
-
-            (Type::Int | Type::UInt, Type::Long) => self.make_cast(lhs, rt),
-            (Type::Long, Type::Int | Type::UInt) => self.make_cast(rhs, lt),
-
-            (Type::Int, Type::UInt) => self.make_cast(lhs, rt),
-            (Type::UInt, Type::Int) => self.make_cast(rhs, lt),
+        let common = if lt.is_pointer() || rt.is_pointer() {
+            self.common_pointer_type(lhs, &lt, rhs, &rt)?
+        } else {
+            Type::common(&lt, &rt).ok_or_else(|| TypeCheckError::IncompatibleTypes {
+                lhs: lt.clone(),
+                rhs: rt.clone(),
+                span: rhs.span(),
+            })?
+        };
 
-            (Type::Int | Type::UInt | Type::Long, Type::ULong) => self.make_cast(lhs, rt),
-            (Type::ULong, Type::Int | Type::UInt | Type::Long) => self.make_cast(rhs, lt),
+        self.make_cast(lhs, &lt, &common)?;
+        self.make_cast(rhs, &rt, &common)
+    }
 
-            (_, Type::Double) => self.make_cast(lhs, rt),
-            (Type::Double, _) => self.make_cast(rhs, lt),
+    fn common_pointer_type(
+        &self,
+        lhs: &Expression,
+        lt: &Type,
+        rhs: &Expression,
+        rt: &Type,
+    ) -> miette::Result<Type, TypeCheckError> {
+        if is_null_pointer_constant(rhs) {
+            return Ok(lt.clone());
+        } else if is_null_pointer_constant(lhs) {
+            return Ok(rt.clone());
+        }
+        // the result points to a type with the qualifiers of both operands
+        let pointer = |referenced: &Type, l: &Type, r: &Type| {
+            let qualifiers = l.qualifiers().union(r.qualifiers());
+            Type::Pointer(referenced.clone().qualified(qualifiers).into())
+        };
+        match (lt, rt) {
+            (Type::Pointer(l), Type::Pointer(r)) if l.unqualified() == r.unqualified() => {
+                Ok(pointer(l.unqualified(), l, r))
+            }
+            (Type::Pointer(l), Type::Pointer(r))
+                if *l.unqualified() == Type::Void || *r.unqualified() == Type::Void =>
+            {
+                Ok(pointer(&Type::Void, l, r))
+            }
+            _ => Err(TypeCheckError::IncompatibleTypes {
+                lhs: lt.clone(),
+                rhs: rt.clone(),
+                span: rhs.span(),
+            }),
         }
     }
 
@@ -661,10 +1286,18 @@ impl Checker {
         span: SourceSpan,
     ) -> miette::Result<(), TypeCheckError> {
         match (from, to) {
-            (
-                Type::Int | Type::Long | Type::UInt | Type::ULong | Type::Double,
-                Type::Int | Type::Long | Type::UInt | Type::ULong | Type::Double,
-            ) => Ok(()),
+            (floating, Type::Pointer(_)) | (Type::Pointer(_), floating)
+                if floating.is_floating() =>
+            {
+                Err(TypeCheckError::InvalidCast {
+                    from: from.clone(),
+                    to: to.clone(),
+                    span,
+                })
+            }
+            (from, to) if from.is_scalar() && to.is_scalar() || from == to => Ok(()),
+            // any expression can be cast to void to discard its value
+            (_, Type::Void) => Ok(()),
             (actual, to) => Err(TypeCheckError::Error {
                 expected: to.clone(),
                 actual: actual.clone(),
@@ -674,21 +1307,64 @@ impl Checker {
         }
     }
 
-    fn make_cast(&self, expr: &mut Expression, to: &Type) -> miette::Result<Type, TypeCheckError> {
+    /// Converts `expr` to `to` following the rules for assignment: arithmetic types convert
+    /// freely, but pointers only accept the same pointer type or a null pointer constant.
+    fn convert_by_assignment(
+        &mut self,
+        expr: &mut Expression,
+        to: &Type,
+    ) -> miette::Result<Type, TypeCheckError> {
+        let to = to.unqualified();
+        let actual = self.visit_expression(expr)?;
+        if let (Type::Pointer(from), Type::Pointer(referenced)) = (&actual, to)
+            && !referenced.qualifiers().contains(from.qualifiers())
+        {
+            return Err(TypeCheckError::DiscardsQualifiers {
+                expected: to.clone(),
+                actual,
+                span: expr.span(),
+            });
+        }
+        if actual == *to
+            || (actual.is_arithmetic() && to.is_arithmetic())
+            || (to.is_pointer() && is_null_pointer_constant(expr))
+            || (to.is_void_pointer() && actual.is_pointer())
+            || (to.is_pointer() && actual.is_void_pointer())
+            || same_pointee(&actual, to)
+            || (*to == Type::Bool && actual.is_pointer())
+        {
+            self.make_cast(expr, &actual, to)
+        } else {
+            Err(TypeCheckError::Error {
+                expected: to.clone(),
+                actual,
+                span: expr.span(),
+                declarations: vec![],
+            })
+        }
+    }
+
+    /// Converts `expr`, which has already been type checked as `actual`, to `to`.
+    fn make_cast(
+        &mut self,
+        expr: &mut Expression,
+        actual: &Type,
+        to: &Type,
+    ) -> miette::Result<Type, TypeCheckError> {
+        let to = to.unqualified();
         match expr {
             Expression::Cast { to: t, .. } if t == to => {
                 return Ok(to.clone());
             }
-            Expression::Constant { constant, .. } => {
-                constant.cast(to)?;
+            Expression::Constant { constant, .. } if to.is_arithmetic() => {
+                constant.cast(to);
                 return Ok(to.clone());
             }
             _ => {}
         }
 
-        let actual = self.visit_expression(expr)?;
-        self.check_cast(&actual, to, expr.span())?;
-        if actual != *to {
+        self.check_cast(actual, to, expr.span())?;
+        if actual != to {
             let span = expr.span();
             *expr = Expression::Cast {
                 to: to.clone(),
@@ -699,28 +1375,88 @@ impl Checker {
         Ok(to.clone())
     }
 
+    /// Type checks an expression in a value context, where arrays decay to a pointer to their
+    /// first element and functions to a pointer to the function.
     fn visit_expression(
-        &self,
+        &mut self,
         expression: &mut Expression,
     ) -> miette::Result<Type, TypeCheckError> {
-        match expression {
-            Expression::Unary {
-                op: UnaryOperator::Complement,
-                expr,
-                ..
-            } => self.visit_integral_expression(expr, "complement"),
-            Expression::Unary { expr, .. } => {
-                self.visit_numeric_expression(expr, "unary expression")
-            }
+        match self.visit_undecayed(expression)? {
+            Type::Array { element, .. } => Ok(Type::Pointer(element)),
+            ty @ Type::Function { .. } => Ok(Type::Pointer(ty.into())),
+            // the value of an object does not have the qualifiers of the object
+            Type::Qualified(ty, _) => Ok(*ty),
+            ty => Ok(ty),
+        }
+    }
+
+    /// Type checks the operand of an assignment or increment, which must be a modifiable
+    /// lvalue.
+    fn visit_modifiable(&mut self, expression: &mut Expression) -> Result<Type> {
+        let ty = self.visit_undecayed(expression)?;
+        match ty {
+            Type::Array { .. } => Err(TypeCheckError::ArrayAssignment {
+                span: expression.span(),
+                actual: ty,
+            }),
+            Type::Function { .. } => Err(TypeCheckError::FunctionAssignment {
+                span: expression.span(),
+                actual: ty,
+            }),
+            ty if ty.is_read_only() => Err(TypeCheckError::ConstModification {
+                span: expression.span(),
+                actual: ty,
+            }),
+            ty => Ok(ty),
+        }
+    }
+
+    fn visit_undecayed(
+        &mut self,
+        expression: &mut Expression,
+    ) -> miette::Result<Type, TypeCheckError> {
+        match expression {
+            Expression::Unary {
+                op: UnaryOperator::Complement,
+                expr,
+                ..
+            } => {
+                let ty = self.visit_integral_expression(expr, "complement")?;
+                self.promote(expr, ty)
+            }
+            Expression::Unary {
+                op: UnaryOperator::Not,
+                expr,
+                ..
+            } => {
+                self.visit_scalar_expression(expr, "logical not")?;
+                Ok(Type::Int)
+            }
+            Expression::Unary {
+                op: UnaryOperator::Minus,
+                expr,
+                ..
+            } => {
+                let ty = self.visit_numeric_expression(expr, "unary expression")?;
+                self.promote(expr, ty)
+            }
+            Expression::Unary { expr, .. } => {
+                self.visit_modifiable(expr)?;
+                let ty = self.visit_scalar_expression(expr, "increment")?;
+                complete_pointee(&ty, expr.span(), "increment")?;
+                Ok(ty)
+            }
             Expression::Binary {
                 op: BinaryOperator::LeftShift | BinaryOperator::RightShift,
                 lhs,
                 rhs,
                 ..
             } => {
-                self.visit_integral_expression(lhs, "bitshift")?;
-                self.visit_integral_expression(rhs, "bitshift")?;
-                self.cast_to_lhs(lhs, rhs)
+                let lt = self.visit_integral_expression(lhs, "bitshift")?;
+                let rt = self.visit_integral_expression(rhs, "bitshift")?;
+                let lt = self.promote(lhs, lt)?;
+                let rt = self.promote(rhs, rt)?;
+                self.cast_to_lhs(lt, rhs, rt)
             }
             Expression::Binary {
                 op: BinaryOperator::Xor | BinaryOperator::BitwiseAnd | BinaryOperator::BitwiseOr,
@@ -728,9 +1464,9 @@ impl Checker {
                 rhs,
                 ..
             } => {
-                self.visit_integral_expression(lhs, "bitwise operation")?;
-                self.visit_integral_expression(rhs, "bitwise operation")?;
-                self.cast_to_common(lhs, rhs)
+                let lt = self.visit_integral_expression(lhs, "bitwise operation")?;
+                let rt = self.visit_integral_expression(rhs, "bitwise operation")?;
+                self.cast_to_common(lhs, lt, rhs, rt)
             }
             Expression::Binary {
                 op: BinaryOperator::Remainder,
@@ -738,28 +1474,142 @@ impl Checker {
                 rhs,
                 ..
             } => {
-                self.visit_integral_expression(lhs, "remainder dividend")?;
-                self.visit_integral_expression(rhs, "remainder divisor")?;
-                self.cast_to_common(lhs, rhs)
+                let lt = self.visit_integral_expression(lhs, "remainder dividend")?;
+                let rt = self.visit_integral_expression(rhs, "remainder divisor")?;
+                self.cast_to_common(lhs, lt, rhs, rt)
+            }
+            Expression::Binary {
+                op: BinaryOperator::And | BinaryOperator::Or,
+                lhs,
+                rhs,
+                ..
+            } => {
+                self.visit_scalar_expression(lhs, "logical operation")?;
+                self.visit_scalar_expression(rhs, "logical operation")?;
+                Ok(Type::Int)
+            }
+            Expression::Binary {
+                op: BinaryOperator::Comma,
+                lhs,
+                rhs,
+                ..
+            } => {
+                self.visit_expression(lhs)?;
+                self.visit_expression(rhs)
+            }
+            Expression::Binary {
+                op: BinaryOperator::Equals | BinaryOperator::NotEqual,
+                lhs,
+                rhs,
+                ..
+            } => {
+                let lt = self.visit_scalar_expression(lhs, "equality comparison")?;
+                let rt = self.visit_scalar_expression(rhs, "equality comparison")?;
+                self.cast_to_common(lhs, lt, rhs, rt)?;
+                Ok(Type::Int)
+            }
+            Expression::Binary {
+                op:
+                    BinaryOperator::LessThan
+                    | BinaryOperator::LessThanOrEqual
+                    | BinaryOperator::GreaterThan
+                    | BinaryOperator::GreaterThanOrEqual,
+                lhs,
+                rhs,
+                ..
+            } => {
+                let lt = self.visit_scalar_expression(lhs, "relational comparison")?;
+                let rt = self.visit_scalar_expression(rhs, "relational comparison")?;
+                if lt.is_pointer() || rt.is_pointer() {
+                    if !same_pointee(&lt, &rt) {
+                        return Err(TypeCheckError::IncompatibleTypes {
+                            lhs: lt,
+                            rhs: rt,
+                            span: rhs.span(),
+                        });
+                    }
+                } else {
+                    self.cast_to_common(lhs, lt, rhs, rt)?;
+                }
+                Ok(Type::Int)
+            }
+            Expression::Binary {
+                op: BinaryOperator::Add,
+                lhs,
+                rhs,
+                ..
+            } => {
+                let lt = self.visit_expression(lhs)?;
+                let rt = self.visit_expression(rhs)?;
+                if lt.is_pointer() && rt.is_integral() {
+                    complete_pointee(&lt, lhs.span(), "pointer arithmetic")?;
+                    self.make_cast(rhs, &rt, &Type::Long)?;
+                    Ok(lt)
+                } else if lt.is_integral() && rt.is_pointer() {
+                    complete_pointee(&rt, rhs.span(), "pointer arithmetic")?;
+                    self.make_cast(lhs, &lt, &Type::Long)?;
+                    Ok(rt)
+                } else {
+                    let lt = numeric(lt, lhs, "arithmetic operation")?;
+                    let rt = numeric(rt, rhs, "arithmetic operation")?;
+                    self.cast_to_common(lhs, lt, rhs, rt)
+                }
+            }
+            Expression::Binary {
+                op: BinaryOperator::Subtract,
+                lhs,
+                rhs,
+                ..
+            } => {
+                let lt = self.visit_expression(lhs)?;
+                let rt = self.visit_expression(rhs)?;
+                if lt.is_pointer() && rt.is_integral() {
+                    complete_pointee(&lt, lhs.span(), "pointer arithmetic")?;
+                    self.make_cast(rhs, &rt, &Type::Long)?;
+                    Ok(lt)
+                } else if lt.is_pointer() && rt.is_pointer() {
+                    complete_pointee(&lt, lhs.span(), "pointer arithmetic")?;
+                    if !same_pointee(&lt, &rt) {
+                        return Err(TypeCheckError::IncompatibleTypes {
+                            lhs: lt,
+                            rhs: rt,
+                            span: rhs.span(),
+                        });
+                    }
+                    Ok(Type::Long)
+                } else {
+                    let lt = numeric(lt, lhs, "arithmetic operation")?;
+                    let rt = numeric(rt, rhs, "arithmetic operation")?;
+                    self.cast_to_common(lhs, lt, rhs, rt)
+                }
+            }
+            Expression::Binary { lhs, rhs, .. } => {
+                let lt = self.visit_numeric_expression(lhs, "arithmetic operation")?;
+                let rt = self.visit_numeric_expression(rhs, "arithmetic operation")?;
+                self.cast_to_common(lhs, lt, rhs, rt)
             }
-            Expression::Binary { lhs, rhs, .. } => self.cast_to_common(lhs, rhs),
             Expression::Var { name, .. } => self
                 .symbols
                 .get(name)
                 .map(|t| t.ty.clone())
                 .ok_or_else(|| unreachable!("no var {name}")),
-            Expression::Assignment { lhs, rhs, .. } => self.cast_to_lhs(lhs, rhs),
-            Expression::CompoundAssignment {
-                lhs,
-                op: BinaryOperator::LeftShift | BinaryOperator::RightShift,
-                rhs,
-                ..
-            } => {
-                self.visit_integral_expression(lhs, "compound assignment bit shift")?;
-                self.visit_integral_expression(rhs, "compound assignment bit shift")?;
-                self.cast_to_lhs(lhs, rhs)
+            Expression::Assignment { lhs, rhs, .. } => {
+                let lt = self.visit_modifiable(lhs)?;
+                self.convert_by_assignment(rhs, &lt)
             }
             Expression::CompoundAssignment { lhs, op, rhs, span } => {
+                self.visit_modifiable(lhs)?;
+                // shifts keep the type of their left operand, so they can be done in place
+                // unless it needs to be promoted first
+                if matches!(op, BinaryOperator::LeftShift | BinaryOperator::RightShift) {
+                    let lt =
+                        self.visit_integral_expression(lhs, "compound assignment bit shift")?;
+                    if !lt.is_promotable() {
+                        let rt =
+                            self.visit_integral_expression(rhs, "compound assignment bit shift")?;
+                        return self.cast_to_lhs(lt, rhs, rt);
+                    }
+                }
                 *expression = Expression::Assignment {
                     lhs: lhs.clone(),
                     rhs: Expression::Binary {
@@ -771,7 +1621,7 @@ impl Checker {
                     .into(),
                     span: *span,
                 };
-                self.visit_expression(expression)
+                self.visit_undecayed(expression)
             }
             Expression::Ternary {
                 cond,
@@ -779,22 +1629,36 @@ impl Checker {
                 if_false,
                 ..
             } => {
-                self.visit_numeric_expression(cond, "ternary condition")?;
-                self.cast_to_common(if_true, if_false)
+                self.visit_scalar_expression(cond, "ternary condition")?;
+                let tt = self.visit_expression(if_true)?;
+                let ft = self.visit_expression(if_false)?;
+                if tt == ft && matches!(tt, Type::Void | Type::Struct(_)) {
+                    return Ok(tt);
+                }
+                self.cast_to_common(if_true, tt, if_false, ft)
             }
             Expression::FunctionCall {
                 function,
                 params: expressions,
                 ..
             } => {
-                let name = match function.as_ref() {
-                    Expression::Var { name, .. } => name.clone(),
-                    _ => unreachable!(),
+                // functions decay to pointers, so direct calls are made through a pointer too
+                let callee = match self.visit_expression(function)? {
+                    Type::Pointer(referenced) if matches!(*referenced, Type::Function { .. }) => {
+                        *referenced
+                    }
+                    ty => ty,
                 };
-                match self.visit_expression(function)? {
-                    Type::Function { params, ret } => {
-                        if params.len() != expressions.len() {
-                            let old = self.symbols.get(&name).unwrap().declarations.clone();
+                match callee {
+                    Type::Function {
+                        params,
+                        ret,
+                        variadic,
+                    } => {
+                        if expressions.len() < params.len()
+                            || !variadic && expressions.len() > params.len()
+                        {
+                            let (name, old) = self.callee(function);
                             Err(TypeCheckError::FunctionArity {
                                 name,
                                 passed: expressions.len(),
@@ -803,14 +1667,44 @@ impl Checker {
                                 old,
                             })
                         } else {
-                            for (expr, ty) in expressions.iter_mut().zip(params) {
-                                self.make_cast(expr, &ty)?;
+                            let (fixed, extra) = expressions.split_at_mut(params.len());
+                            for (expr, ty) in fixed.iter_mut().zip(params) {
+                                if !ty.is_complete() {
+                                    return Err(TypeCheckError::IncompleteOperand {
+                                        ty,
+                                        span: expr.span(),
+                                        position: "function argument",
+                                    });
+                                }
+                                self.convert_by_assignment(expr, &ty)?;
+                            }
+                            // the default argument promotions apply to the variable arguments
+                            for expr in extra {
+                                let ty = self.visit_expression(expr)?;
+                                let mut ty = self.promote(expr, ty)?;
+                                if ty == Type::Float {
+                                    ty = self.make_cast(expr, &ty, &Type::Double)?;
+                                }
+                                if !ty.is_complete() {
+                                    return Err(TypeCheckError::IncompleteOperand {
+                                        ty,
+                                        span: expr.span(),
+                                        position: "function argument",
+                                    });
+                                }
+                            }
+                            if ret.is_struct() && !ret.is_complete() {
+                                return Err(TypeCheckError::IncompleteOperand {
+                                    ty: *ret,
+                                    span: function.span(),
+                                    position: "function call",
+                                });
                             }
                             Ok(ret.as_ref().clone())
                         }
                     }
                     ty => {
-                        let old = self.symbols.get(&name).unwrap().declarations.clone();
+                        let (name, old) = self.callee(function);
                         Err(TypeCheckError::NonFunctionCall {
                             name,
                             span: function.span(),
@@ -822,6 +1716,8 @@ impl Checker {
             }
             Expression::Constant { constant, .. } => Ok(constant.ty()),
             Expression::Cast { to, expr, .. } => {
+                // a cast yields a value, which is never qualified
+                *to = to.unqualified().clone();
                 let actual = self.visit_expression(expr.as_mut())?;
                 self.check_cast(&actual, to, expr.span())?;
 
@@ -832,39 +1728,256 @@ impl Checker {
                     Ok(to.clone())
                 }
             }
+            Expression::AddrOf { expr, .. } => {
+                let ty = self.visit_undecayed(expr)?;
+                Ok(Type::Pointer(ty.into()))
+            }
+            Expression::Dereference { expr, .. } => match self.visit_expression(expr)? {
+                Type::Pointer(referenced) if *referenced.unqualified() == Type::Void => {
+                    Err(TypeCheckError::IncompleteOperand {
+                        ty: Type::Pointer(referenced),
+                        span: expr.span(),
+                        position: "dereference",
+                    })
+                }
+                Type::Pointer(referenced) => {
+                    mark_volatile_pointee(expr, &referenced);
+                    Ok(*referenced)
+                }
+                actual => Err(TypeCheckError::DereferenceNonPointer {
+                    actual,
+                    span: expr.span(),
+                }),
+            },
+            Expression::Subscript { array, index, .. } => {
+                let at = self.visit_expression(array)?;
+                let it = self.visit_expression(index)?;
+                complete_pointee(&at, array.span(), "subscript")?;
+                complete_pointee(&it, index.span(), "subscript")?;
+                let referenced = match (at, it) {
+                    (Type::Pointer(referenced), it) if it.is_integral() => {
+                        self.make_cast(index, &it, &Type::Long)?;
+                        mark_volatile_pointee(array, &referenced);
+                        referenced
+                    }
+                    (at, Type::Pointer(referenced)) if at.is_integral() => {
+                        self.make_cast(array, &at, &Type::Long)?;
+                        mark_volatile_pointee(index, &referenced);
+                        referenced
+                    }
+                    (at, it) => {
+                        return Err(TypeCheckError::IncompatibleTypes {
+                            lhs: at,
+                            rhs: it,
+                            span: expression.span(),
+                        });
+                    }
+                };
+                Ok(*referenced)
+            }
+            Expression::String { value, span } => {
+                // string literals in expressions become references to a read-only object
+                let name = format!("string.literal.{}", self.string_literals);
+                self.string_literals += 1;
+                let ty = Type::Array {
+                    element: Type::Char.into(),
+                    size: value.len() as u64 + 1,
+                };
+                self.symbols.insert(
+                    name.clone(),
+                    Symbol {
+                        ty: ty.clone(),
+                        attributes: SymbolAttributes::Constant(StaticInit::String(
+                            take(value),
+                            true,
+                        )),
+                        declarations: vec![*span],
+                    },
+                );
+                *expression = Expression::Var { name, span: *span };
+                Ok(ty)
+            }
+            Expression::SizeOf { expr, span } => {
+                // the operand is only type checked, never evaluated
+                let ty = self.visit_undecayed(expr)?;
+                if !ty.is_complete() {
+                    return Err(TypeCheckError::IncompleteOperand {
+                        ty,
+                        span: expr.span(),
+                        position: "sizeof",
+                    });
+                }
+                *expression = Expression::Constant {
+                    constant: Constant::ULong(ty.size()),
+                    span: *span,
+                };
+                Ok(Type::ULong)
+            }
+            Expression::Dot { expr, member, span } => {
+                let ty = self.visit_undecayed(expr)?;
+                self.member_type(ty, member, expr.span(), *span)
+            }
+            Expression::Arrow { expr, member, span } => match self.visit_expression(expr)? {
+                Type::Pointer(referenced) if referenced.is_struct() => {
+                    mark_volatile_pointee(expr, &referenced);
+                    self.member_type(*referenced, member, expr.span(), *span)
+                }
+                actual => Err(TypeCheckError::NonStructMember {
+                    actual,
+                    span: expr.span(),
+                }),
+            },
+            Expression::VaStart { ap, span } => {
+                if !self.variadic {
+                    return Err(TypeCheckError::VaStartFixedArguments { span: *span });
+                }
+                self.visit_va_list(ap)?;
+                Ok(Type::Void)
+            }
+            Expression::VaArg { ap, ty, span } => {
+                self.visit_va_list(ap)?;
+                if !ty.is_complete() || ty.is_array() {
+                    return Err(TypeCheckError::VaArgType {
+                        ty: ty.clone(),
+                        span: *span,
+                    });
+                }
+                *ty = ty.unqualified().clone();
+                Ok(ty.clone())
+            }
+            Expression::VaCopy { dst, src, .. } => {
+                self.visit_va_list(dst)?;
+                self.visit_va_list(src)?;
+                Ok(Type::Void)
+            }
+            Expression::VaEnd { ap, .. } => {
+                self.visit_va_list(ap)?;
+                Ok(Type::Void)
+            }
+            Expression::SizeOfType { ty, span } => {
+                if !ty.is_complete() {
+                    return Err(TypeCheckError::IncompleteOperand {
+                        ty: ty.clone(),
+                        span: *span,
+                        position: "sizeof",
+                    });
+                }
+                *expression = Expression::Constant {
+                    constant: Constant::ULong(ty.size()),
+                    span: *span,
+                };
+                Ok(Type::ULong)
+            }
         }
     }
 
-    fn visit_numeric_expression(
+    /// Checks that `ap` is a `va_list`, which decays to a pointer to its only element.
+    fn visit_va_list(&mut self, ap: &mut Expression) -> Result {
+        let actual = self.visit_expression(ap)?;
+        if actual != Type::Pointer(Type::Struct(StructType::va_list_tag()).into()) {
+            return Err(TypeCheckError::Error {
+                expected: Type::va_list(),
+                actual,
+                span: ap.span(),
+                declarations: vec![],
+            });
+        }
+        Ok(())
+    }
+
+    /// Describes the callee of a call for diagnostics, with its declarations if it is named.
+    fn callee(&self, function: &Expression) -> (String, Vec<SourceSpan>) {
+        match function {
+            Expression::Var { name, .. } => (
+                name.clone(),
+                self.symbols[name.as_str()].declarations.clone(),
+            ),
+            _ => ("expression".into(), vec![]),
+        }
+    }
+
+    /// The type of `member` in the structure type `ty`, which must be complete.
+    fn member_type(
         &self,
+        ty: Type,
+        member: &str,
+        operand: SourceSpan,
+        span: SourceSpan,
+    ) -> Result<Type> {
+        // members of a qualified structure have its qualifiers too
+        let qualifiers = ty.qualifiers();
+        let Type::Struct(s) = ty.unqualified() else {
+            return Err(TypeCheckError::NonStructMember {
+                actual: ty,
+                span: operand,
+            });
+        };
+        let Some(layout) = s.layout() else {
+            return Err(TypeCheckError::IncompleteOperand {
+                ty,
+                span: operand,
+                position: "member access",
+            });
+        };
+        match layout.member(member) {
+            Some(member) => Ok(member.ty.clone().qualified(qualifiers)),
+            None => Err(TypeCheckError::NoSuchMember {
+                member: member.to_string(),
+                ty,
+                span,
+            }),
+        }
+    }
+
+    /// Applies the integer promotions to `expression`, which has already been type checked
+    /// as `ty`.
+    fn promote(&mut self, expression: &mut Expression, ty: Type) -> Result<Type> {
+        if ty.is_promotable() {
+            self.make_cast(expression, &ty, &Type::Int)
+        } else {
+            Ok(ty)
+        }
+    }
+
+    fn visit_numeric_expression(
+        &mut self,
         expression: &mut Expression,
         position: &'static str,
     ) -> Result<Type> {
         let ty = self.visit_expression(expression)?;
-        match ty {
-            Type::Function { .. } => Err(TypeCheckError::NonNumeric {
-                actual: ty.clone(),
+        numeric(ty, expression, position)
+    }
+
+    fn visit_scalar_expression(
+        &mut self,
+        expression: &mut Expression,
+        position: &'static str,
+    ) -> Result<Type> {
+        let ty = self.visit_expression(expression)?;
+        if !ty.is_scalar() {
+            return Err(TypeCheckError::NonScalar {
+                actual: ty,
                 span: expression.span(),
                 position,
-            }),
-            Type::Double | Type::Int | Type::Long | Type::UInt | Type::ULong => Ok(ty),
+            });
         }
+        Ok(ty)
     }
 
     fn visit_integral_expression(
-        &self,
+        &mut self,
         expression: &mut Expression,
         position: &'static str,
     ) -> Result<Type> {
         let ty = self.visit_expression(expression)?;
-        match ty {
-            Type::Function { .. } | Type::Double => Err(TypeCheckError::NonIntegral {
-                actual: ty.clone(),
+        if !ty.is_integral() {
+            return Err(TypeCheckError::NonIntegral {
+                actual: ty,
                 span: expression.span(),
                 position,
-            }),
-            Type::Int | Type::Long | Type::UInt | Type::ULong => Ok(ty),
+            });
         }
+        Ok(ty)
     }
 }
 
@@ -873,33 +1986,553 @@ pub enum Type {
     Function {
         params: Vec<Type>,
         ret: Box<Type>,
+        /// Whether further arguments may follow the declared parameters.
+        variadic: bool,
     },
     #[default]
     Int,
     Long,
     UInt,
     ULong,
+    Short,
+    UShort,
+    Char,
+    SChar,
+    UChar,
+    Bool,
+    Float,
     Double,
+    Void,
+    Pointer(Box<Type>),
+    Array {
+        element: Box<Type>,
+        size: u64,
+    },
+    Struct(StructType),
+    /// A qualified version of another type, which is never itself qualified. Arrays are never
+    /// qualified either: their elements are instead.
+    Qualified(Box<Type>, Qualifiers),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Qualifiers {
+    pub constant: bool,
+    pub volatile: bool,
+    pub restrict: bool,
+}
+
+impl Qualifiers {
+    pub const CONST: Qualifiers = Qualifiers {
+        constant: true,
+        volatile: false,
+        restrict: false,
+    };
+
+    pub const fn is_empty(&self) -> bool {
+        !self.constant && !self.volatile && !self.restrict
+    }
+
+    /// Whether every qualifier in `other` is also in `self`.
+    pub const fn contains(&self, other: Qualifiers) -> bool {
+        (self.constant || !other.constant)
+            && (self.volatile || !other.volatile)
+            && (self.restrict || !other.restrict)
+    }
+
+    pub const fn union(self, other: Qualifiers) -> Qualifiers {
+        Qualifiers {
+            constant: self.constant || other.constant,
+            volatile: self.volatile || other.volatile,
+            restrict: self.restrict || other.restrict,
+        }
+    }
+}
+
+impl Debug for Qualifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = [
+            (self.constant, "const"),
+            (self.volatile, "volatile"),
+            (self.restrict, "restrict"),
+        ];
+        let names: Vec<_> = names
+            .iter()
+            .filter(|(set, _)| *set)
+            .map(|(_, n)| *n)
+            .collect();
+        f.write_str(&names.join(" "))
+    }
+}
+
+/// A structure or union type, identified by its tag. Every reference to a tag shares the same
+/// layout, which is filled in once the definition has been type checked.
+#[derive(Clone)]
+pub struct StructType(Arc<StructTypeInner>);
+
+struct StructTypeInner {
+    tag: String,
+    union: bool,
+    layout: OnceLock<StructLayout>,
+}
+
+#[derive(Debug)]
+pub struct StructLayout {
+    pub members: Vec<Member>,
+    pub size: u64,
+    pub alignment: u64,
+}
+
+#[derive(Debug)]
+pub struct Member {
+    pub name: String,
+    pub ty: Type,
+    pub offset: u64,
+}
+
+impl StructType {
+    pub fn new(tag: impl Into<String>) -> Self {
+        Self(Arc::new(StructTypeInner {
+            tag: tag.into(),
+            union: false,
+            layout: OnceLock::new(),
+        }))
+    }
+
+    /// A union type, whose members all start at offset zero.
+    pub fn new_union(tag: impl Into<String>) -> Self {
+        Self(Arc::new(StructTypeInner {
+            tag: tag.into(),
+            union: true,
+            layout: OnceLock::new(),
+        }))
+    }
+
+    /// The element type of `va_list`, laid out as the System V ABI specifies. It is complete
+    /// from the start, so name resolution leaves it alone.
+    pub fn va_list_tag() -> Self {
+        let member = |name: &str, ty, offset| Member {
+            name: name.into(),
+            ty,
+            offset,
+        };
+        let void_ptr = || Type::Pointer(Type::Void.into());
+        let layout = OnceLock::new();
+        let _ = layout.set(StructLayout {
+            members: vec![
+                member("gp_offset", Type::UInt, 0),
+                member("fp_offset", Type::UInt, 4),
+                member("overflow_arg_area", void_ptr(), 8),
+                member("reg_save_area", void_ptr(), 16),
+            ],
+            size: 24,
+            alignment: 8,
+        });
+        Self(Arc::new(StructTypeInner {
+            tag: "__va_list_tag".into(),
+            union: false,
+            layout,
+        }))
+    }
+
+    pub fn tag(&self) -> &str {
+        &self.0.tag
+    }
+
+    pub fn is_union(&self) -> bool {
+        self.0.union
+    }
+
+    /// The members initialized by the elements of an initializer list, in order. Only the
+    /// first member of a union can be initialized.
+    pub fn initialized_members(&self) -> &[Member] {
+        let members = &self.layout_unchecked().members;
+        if self.is_union() {
+            &members[..1]
+        } else {
+            members
+        }
+    }
+
+    /// The layout of the structure, or `None` if it is still incomplete.
+    pub fn layout(&self) -> Option<&StructLayout> {
+        self.0.layout.get()
+    }
+
+    /// Completes the structure type. Tags are unique per definition, so this happens at most
+    /// once.
+    fn define(&self, layout: StructLayout) {
+        if self.0.layout.set(layout).is_err() {
+            unreachable!("redefinition of struct {}", self.tag());
+        }
+    }
+
+    pub fn layout_unchecked(&self) -> &StructLayout {
+        self.layout()
+            .unwrap_or_else(|| unreachable!("incomplete struct {}", self.tag()))
+    }
+}
+
+impl StructLayout {
+    pub fn member(&self, name: &str) -> Option<&Member> {
+        self.members.iter().find(|m| m.name == name)
+    }
+}
+
+impl PartialEq for StructType {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag() == other.tag()
+    }
+}
+
+impl Eq for StructType {}
+
+impl Hash for StructType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.tag().hash(state);
+    }
+}
+
+impl Debug for StructType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_union() {
+            f.write_str("union ")?;
+        }
+        self.tag().fmt(f)
+    }
 }
 
 impl Type {
+    /// The type of `va_list`: a one element array, so that it is passed by reference.
+    pub fn va_list() -> Type {
+        Type::Array {
+            element: Type::Struct(StructType::va_list_tag()).into(),
+            size: 1,
+        }
+    }
+
+    /// The type without its top-level qualifiers.
+    pub fn unqualified(&self) -> &Type {
+        match self {
+            Type::Qualified(ty, _) => ty,
+            ty => ty,
+        }
+    }
+
+    pub fn qualifiers(&self) -> Qualifiers {
+        match self {
+            Type::Qualified(_, qualifiers) => *qualifiers,
+            _ => Qualifiers::default(),
+        }
+    }
+
+    /// Adds `qualifiers` to the type. Qualifying an array type qualifies its elements.
+    pub fn qualified(self, qualifiers: Qualifiers) -> Type {
+        match self {
+            _ if qualifiers.is_empty() => self,
+            Type::Qualified(ty, old) => Type::Qualified(ty, old.union(qualifiers)),
+            Type::Array { element, size } => Type::Array {
+                element: element.qualified(qualifiers).into(),
+                size,
+            },
+            ty => Type::Qualified(ty.into(), qualifiers),
+        }
+    }
+
+    /// The type with all qualifiers removed, including those of the types it is derived from.
+    /// Qualifiers only matter while type checking, so later stages never see them.
+    pub fn strip_qualifiers(&self) -> Type {
+        match self {
+            Type::Qualified(ty, _) => ty.strip_qualifiers(),
+            Type::Pointer(referenced) => Type::Pointer(referenced.strip_qualifiers().into()),
+            Type::Array { element, size } => Type::Array {
+                element: element.strip_qualifiers().into(),
+                size: *size,
+            },
+            Type::Function {
+                params,
+                ret,
+                variadic,
+            } => Type::Function {
+                params: params.iter().map(Type::strip_qualifiers).collect(),
+                ret: ret.strip_qualifiers().into(),
+                variadic: *variadic,
+            },
+            ty => ty.clone(),
+        }
+    }
+
+    /// Whether an object of this type is read-only: it is const-qualified, or an array of
+    /// such objects, or a structure with such a member.
+    pub fn is_read_only(&self) -> bool {
+        match self {
+            Type::Qualified(ty, qualifiers) => qualifiers.constant || ty.is_read_only(),
+            Type::Array { element, .. } => element.is_read_only(),
+            Type::Struct(s) => s
+                .layout()
+                .is_some_and(|l| l.members.iter().any(|m| m.ty.is_read_only())),
+            _ => false,
+        }
+    }
+
+    /// Whether objects of this type are const as a whole. Arrays are if their elements are.
+    /// Whether an object of this type is volatile, or has a volatile element or member, so
+    /// that every access to it must be performed as written.
+    pub fn is_volatile(&self) -> bool {
+        match self {
+            Type::Qualified(ty, qualifiers) => qualifiers.volatile || ty.is_volatile(),
+            Type::Array { element, .. } => element.is_volatile(),
+            Type::Struct(s) => s
+                .layout()
+                .is_some_and(|l| l.members.iter().any(|m| m.ty.is_volatile())),
+            _ => false,
+        }
+    }
+
+    pub fn is_const(&self) -> bool {
+        match self {
+            Type::Array { element, .. } => element.is_const(),
+            ty => ty.qualifiers().constant,
+        }
+    }
+
     pub fn width(&self) -> Width {
         match self {
+            Type::Qualified(ty, _) => ty.width(),
             Type::Function { .. } => Width::Eight,
-            Type::Int | Type::UInt => Width::Four,
-            Type::Long | Type::ULong | Type::Double => Width::Eight,
+            Type::Char | Type::SChar | Type::UChar | Type::Bool => Width::One,
+            Type::Short | Type::UShort => Width::Two,
+            Type::Int | Type::UInt | Type::Float => Width::Four,
+            Type::Long | Type::ULong | Type::Double | Type::Pointer(_) => Width::Eight,
+            // Aggregates are only ever addressed, never moved as a whole.
+            Type::Array { .. } => Width::Eight,
+            Type::Struct(_) => Width::Eight,
+            Type::Void => unreachable!("void has no width"),
+        }
+    }
+
+    pub fn size(&self) -> u64 {
+        match self.unqualified() {
+            Type::Array { element, size } => element.size() * size,
+            Type::Struct(s) => s.layout_unchecked().size,
+            _ => self.width().bytes() as u64,
+        }
+    }
+
+    pub fn alignment(&self) -> u64 {
+        match self.unqualified() {
+            Type::Array { element, .. } => element.alignment(),
+            Type::Struct(s) => s.layout_unchecked().alignment,
+            _ => self.width().bytes() as u64,
+        }
+    }
+
+    /// The alignment of a variable of this type. The System V ABI requires arrays of 16 bytes
+    /// or more to be 16-byte aligned.
+    pub fn variable_alignment(&self) -> u64 {
+        match self {
+            Type::Array { .. } if self.size() >= 16 => 16,
+            _ => self.alignment(),
         }
     }
 
     pub fn signed(&self) -> bool {
+        match self.unqualified() {
+            Type::Function { .. } | Type::Pointer(_) | Type::Array { .. } | Type::Struct(_) => {
+                false
+            }
+            Type::Float | Type::Double | Type::Void => false,
+            Type::Int | Type::Long | Type::Short | Type::Char | Type::SChar => true,
+            Type::UInt | Type::ULong | Type::UShort | Type::UChar | Type::Bool => false,
+            Type::Qualified(..) => unreachable!(),
+        }
+    }
+
+    pub fn is_pointer(&self) -> bool {
+        matches!(self.unqualified(), Type::Pointer(_))
+    }
+
+    pub fn is_void_pointer(&self) -> bool {
+        matches!(self.unqualified(), Type::Pointer(referenced) if *referenced.unqualified() == Type::Void)
+    }
+
+    /// Whether the size of objects of this type is known.
+    pub fn is_complete(&self) -> bool {
+        match self.unqualified() {
+            Type::Void | Type::Function { .. } => false,
+            Type::Array { element, size } => *size != 0 && element.is_complete(),
+            Type::Struct(s) => s.layout().is_some(),
+            _ => true,
+        }
+    }
+
+    pub fn is_struct(&self) -> bool {
+        matches!(self.unqualified(), Type::Struct(_))
+    }
+
+    pub fn is_array(&self) -> bool {
+        matches!(self, Type::Array { .. })
+    }
+
+    pub fn is_character(&self) -> bool {
+        matches!(self.unqualified(), Type::Char | Type::SChar | Type::UChar)
+    }
+
+    pub fn is_integral(&self) -> bool {
+        matches!(
+            self.unqualified(),
+            Type::Int | Type::Long | Type::UInt | Type::ULong
+        ) || self.is_promotable()
+    }
+
+    /// Whether the integer promotions convert values of this type to `int`, which can represent
+    /// all of them.
+    pub fn is_promotable(&self) -> bool {
+        matches!(self.unqualified(), Type::Short | Type::UShort | Type::Bool) || self.is_character()
+    }
+
+    /// The integer conversion rank. `long long` is represented as `long`, so it needs no rank of
+    /// its own.
+    fn rank(&self) -> u8 {
+        match self {
+            Type::Bool => 1,
+            Type::Char | Type::SChar | Type::UChar => 2,
+            Type::Short | Type::UShort => 3,
+            Type::Int | Type::UInt => 4,
+            Type::Long | Type::ULong => 5,
+            ty => unreachable!("{ty:?} has no integer conversion rank"),
+        }
+    }
+
+    /// The unsigned type corresponding to the signed integer type `self`.
+    fn to_unsigned(&self) -> Type {
         match self {
-            Type::Function { .. } => false,
-            Type::Double => false,
-            Type::Int | Type::Long => true,
-            Type::UInt | Type::ULong => false,
+            Type::Int => Type::UInt,
+            Type::Long => Type::ULong,
+            ty => unreachable!("{ty:?} is not a promoted signed type"),
+        }
+    }
+
+    pub fn is_floating(&self) -> bool {
+        matches!(self.unqualified(), Type::Float | Type::Double)
+    }
+
+    pub fn is_arithmetic(&self) -> bool {
+        self.is_integral() || self.is_floating()
+    }
+
+    pub fn is_scalar(&self) -> bool {
+        self.is_arithmetic() || self.is_pointer()
+    }
+
+    /// The common real type of two arithmetic operands, per the usual arithmetic conversions.
+    fn common(lhs: &Type, rhs: &Type) -> Option<Type> {
+        if !lhs.is_arithmetic() || !rhs.is_arithmetic() {
+            return None;
+        }
+        let lhs = if lhs.is_promotable() { &Type::Int } else { lhs };
+        let rhs = if rhs.is_promotable() { &Type::Int } else { rhs };
+        if lhs == rhs {
+            return Some(lhs.clone());
+        }
+        if *lhs == Type::Double || *rhs == Type::Double {
+            return Some(Type::Double);
         }
+        if *lhs == Type::Float || *rhs == Type::Float {
+            return Some(Type::Float);
+        }
+        let (signed, unsigned) = match (lhs.signed(), rhs.signed()) {
+            (true, false) => (lhs, rhs),
+            (false, true) => (rhs, lhs),
+            _ => return Some(std::cmp::max_by_key(lhs, rhs, |ty| ty.rank()).clone()),
+        };
+        if unsigned.rank() >= signed.rank() {
+            Some(unsigned.clone())
+        } else if signed.size() > unsigned.size() {
+            Some(signed.clone())
+        } else {
+            Some(signed.to_unsigned())
+        }
+    }
+}
+
+/// Checks that a pointer operand of `position` points to a complete type.
+fn complete_pointee(ty: &Type, span: SourceSpan, position: &'static str) -> Result {
+    match ty {
+        Type::Pointer(referenced) if !referenced.is_complete() => {
+            Err(TypeCheckError::IncompleteOperand {
+                ty: ty.clone(),
+                span,
+                position,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks that the already type checked operand `expression` of `position` has an arithmetic
+/// type.
+fn numeric(ty: Type, expression: &Expression, position: &'static str) -> Result<Type> {
+    if !ty.is_arithmetic() {
+        return Err(TypeCheckError::NonNumeric {
+            actual: ty,
+            span: expression.span(),
+            position,
+        });
+    }
+    Ok(ty)
+}
+
+/// Whether two pointer types point to the same type, ignoring its qualifiers.
+fn same_pointee(lhs: &Type, rhs: &Type) -> bool {
+    matches!((lhs, rhs), (Type::Pointer(l), Type::Pointer(r)) if l.unqualified() == r.unqualified())
+}
+
+/// Finds `member` of the structure type `ty`, for an object of that type found at `offset`
+/// into the static object `name`.
+fn static_member(
+    name: String,
+    offset: i64,
+    ty: &Type,
+    member: &str,
+) -> Option<(String, i64, Type)> {
+    let Type::Struct(s) = ty.unqualified() else {
+        return None;
+    };
+    let member = s.layout_unchecked().member(member)?;
+    Some((name, offset + member.offset as i64, member.ty.clone()))
+}
+
+/// Wraps `ptr`, which points to `referenced`, in a cast to its own type if `referenced` is
+/// volatile. Later stages only see unqualified types, so the cast is how they tell that an
+/// access through `ptr` is volatile.
+fn mark_volatile_pointee(ptr: &mut Expression, referenced: &Type) {
+    if referenced.is_volatile() && !points_to_volatile(ptr) {
+        let span = ptr.span();
+        *ptr = Expression::Cast {
+            to: Type::Pointer(referenced.clone().into()),
+            expr: take(ptr).into(),
+            span,
+        };
     }
 }
+
+/// Whether type checking has marked `ptr` as pointing to a volatile object.
+pub fn points_to_volatile(ptr: &Expression) -> bool {
+    matches!(ptr, Expression::Cast { to: Type::Pointer(referenced), .. } if referenced.is_volatile())
+}
+
+/// Whether the type checked `expr` is a null pointer constant: an integer constant expression
+/// with the value 0, or such an expression cast to `void *`.
+fn is_null_pointer_constant(expr: &Expression) -> bool {
+    let expr = match expr {
+        Expression::Cast { to, expr, .. } if to.is_void_pointer() => expr,
+        // constants cast to any other pointer type evaluate to an integer too
+        Expression::Cast { to, .. } if to.is_pointer() => return false,
+        expr => expr,
+    };
+    evaluate(expr).is_ok_and(|constant| constant.ty().is_integral() && constant.is_zero())
+}
+
 impl Symbol {
     fn assert(
         &self,
@@ -923,3 +2556,228 @@ pub fn run(program: &mut Program) -> miette::Result<SymbolTable, TypeCheckError>
     checker.visit_program(program)?;
     Ok(checker.symbols)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Initial, StaticInit, TypeCheckError};
+    use crate::{
+        ast::{BlockItem, Constant, Declaration, Expression, FunctionDeclaration, Statement},
+        lexer::lex,
+        parser::parse,
+        sema::{SemaError, SymbolAttributes, SymbolTable, validate},
+    };
+
+    fn check(src: &str) -> Result<SymbolTable, SemaError> {
+        let tokens = lex(src, "example.c").unwrap();
+        let mut program = parse(src, tokens, "example.c").unwrap();
+        validate(&mut program)
+    }
+
+    /// The type checked expression statements and return values in the body of the function
+    /// defined last.
+    fn expressions(src: &str) -> Vec<Expression> {
+        let tokens = lex(src, "example.c").unwrap();
+        let mut program = parse(src, tokens, "example.c").unwrap();
+        validate(&mut program).unwrap();
+        let Some(Declaration::Function(FunctionDeclaration {
+            body: Some(body), ..
+        })) = program.declarations.pop()
+        else {
+            panic!("no function is defined last");
+        };
+        body.items
+            .into_iter()
+            .filter_map(|item| match item {
+                BlockItem::Statement(
+                    Statement::Expression(expr)
+                    | Statement::Return {
+                        expr: Some(expr), ..
+                    },
+                ) => Some(expr),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The static initializer of `x`.
+    fn init(src: &str) -> Vec<StaticInit> {
+        let symbols = check(src).unwrap();
+        match &symbols["x"].attributes {
+            SymbolAttributes::Static {
+                init: Initial::Some(values),
+                ..
+            } => values.clone(),
+            attributes => panic!("{attributes:?} has no initializer"),
+        }
+    }
+
+    /// The value of the static initializer of the scalar `x`.
+    fn value(src: &str) -> Constant {
+        match init(src).as_slice() {
+            [StaticInit::Scalar(constant)] => *constant,
+            values => panic!("{values:?} is not a scalar"),
+        }
+    }
+
+    #[test]
+    fn test_static_initializers() {
+        use Constant::*;
+        assert_eq!(value("int x = 2147483647 + 1;"), Int(i32::MIN));
+        assert_eq!(value("unsigned x = -1;"), UInt(u32::MAX));
+        assert_eq!(value("char x = 300;"), Char(44));
+        assert_eq!(value("long x = (int)4294967298L * 2;"), Long(4));
+        assert_eq!(value("double x = (float)0.1;"), Double(0.1f32 as f64));
+        assert_eq!(
+            value("unsigned long x = sizeof(long) * 3 + sizeof(char[5]);"),
+            ULong(29)
+        );
+        assert_eq!(
+            value("int x = 10 % 3 << 2 | (5 > 3 ? 64 : 1 / 0);"),
+            Int(68)
+        );
+        // the operands that are not evaluated need not have a value
+        assert_eq!(value("int x = 1 || 1 / 0;"), Int(1));
+    }
+
+    #[test]
+    fn test_address_constants() {
+        let pointer = |name: &str, offset| vec![StaticInit::Pointer(name.into(), offset)];
+        assert_eq!(init("int y; int *x = &y;"), pointer("y", 0));
+        assert_eq!(init("int a[3]; int *x = a;"), pointer("a", 0));
+        assert_eq!(init("int a[3]; int *x = &a[2];"), pointer("a", 8));
+        assert_eq!(init("int a[3]; int *x = 3 + a - 1;"), pointer("a", 8));
+        assert_eq!(init("long y; char *x = (char *)&y + 3;"), pointer("y", 3));
+        assert_eq!(
+            init("char *x = \"abc\" + 1;"),
+            pointer("string.literal.0", 1)
+        );
+        assert_eq!(init("int f(void); int (*x)(void) = &f;"), pointer("f", 0));
+        assert_eq!(
+            init("struct p { char c; long m[2]; }; struct p y; long *x = &y.m[1];"),
+            pointer("y", 16)
+        );
+        assert_eq!(
+            init("struct p { char c; int m; }; struct p a[2]; int *x = &(a + 1)->m;"),
+            pointer("a", 12)
+        );
+        assert_eq!(init("int a[2][3]; int *x = a[1];"), pointer("a", 12));
+    }
+
+    #[test]
+    fn test_non_constant_initializers() {
+        assert!(matches!(
+            check("int x = 1 / 0;"),
+            Err(SemaError::TypeCheck(TypeCheckError::NonConstantInitializer { span }))
+                if span.offset() == 8
+        ));
+        assert!(matches!(
+            check("int y; int x = y + 1;"),
+            Err(SemaError::TypeCheck(TypeCheckError::NonConstantInitializer { span }))
+                if span == (15, 1).into()
+        ));
+        assert!(matches!(
+            check("int x = (1, 2);"),
+            Err(SemaError::TypeCheck(
+                TypeCheckError::NonConstantInitializer { .. }
+            ))
+        ));
+        assert!(matches!(
+            check("int *p; int *x = p;"),
+            Err(SemaError::TypeCheck(
+                TypeCheckError::NonConstantInitializer { .. }
+            ))
+        ));
+        assert!(matches!(
+            check("int main(void) { int y; static int *x = &y; return 0; }"),
+            Err(SemaError::TypeCheck(
+                TypeCheckError::NonConstantInitializer { .. }
+            ))
+        ));
+        assert!(matches!(
+            check("int f(void); int x = f();"),
+            Err(SemaError::TypeCheck(
+                TypeCheckError::NonConstantInitializer { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_null_pointer_constants() {
+        assert_eq!(value("int *x = 1 - 1;"), Constant::ULong(0));
+        assert_eq!(value("int *x = (void *)'\\0';"), Constant::ULong(0));
+        assert_eq!(value("int *x = (char)256;"), Constant::ULong(0));
+        let src = "int main(void) { long *p = 0; return (p == (1 ? 0 : 1)) + (2 * 0 != p); }";
+        check(src).unwrap();
+        for src in [
+            "int *x = 1;",
+            "int *x = 0.0;",
+            "int *x = (long *)0;",
+            "int main(void) { int i = 0; int *p = i; return 0; }",
+        ] {
+            assert!(
+                matches!(
+                    check(src),
+                    Err(SemaError::TypeCheck(TypeCheckError::Error { .. }))
+                ),
+                "{src}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_operands_are_converted_once() {
+        let src = "int printf(char *format, ...); int main(void) { char c = 1; float f = 2; int a[2] = {0, 1}; printf(\"%f\", f); return c + a[c]; }";
+        insta::assert_debug_snapshot!(expressions(src), @r#"
+        [
+            FunctionCall {
+                function: Var("printf"),
+                params: [
+                    Var("string.literal.0"),
+                    Cast {
+                        to: Double,
+                        expr: Var("f.4"),
+                    },
+                ],
+            },
+            Binary {
+                op: Add,
+                lhs: Cast {
+                    to: Int,
+                    expr: Var("c.3"),
+                },
+                rhs: Subscript {
+                    array: Var("a.5"),
+                    index: Cast {
+                        to: Long,
+                        expr: Var("c.3"),
+                    },
+                },
+            },
+        ]
+        "#);
+
+        // visiting each operand again while converting it took time exponential in the depth
+        let depth = 24;
+        let src = format!(
+            "int main(void) {{ char c = 1; return {}c{}; }}",
+            "(".repeat(depth),
+            " + c)".repeat(depth)
+        );
+        check(&src).unwrap();
+    }
+
+    #[test]
+    fn test_case_labels() {
+        let src = "int main(void) { int y = 0; switch (y) { case 1 + 1: return 1; case y: return 2; } return 0; }";
+        assert!(matches!(
+            check(src),
+            Err(SemaError::TypeCheck(TypeCheckError::NonConstantCase { span }))
+                if span == (68, 1).into()
+        ));
+        let src = "int main(void) { switch (0L) { case 2: case (char)258: ; } return 0; }";
+        assert!(matches!(check(src), Err(SemaError::DuplicateCases(_))));
+        let src =
+            "int main(void) { switch (0) { case 4294967298L: case 1 ? 3 : 1 / 0: ; } return 0; }";
+        check(src).unwrap();
+    }
+}
@@ -0,0 +1,373 @@
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display},
+};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use miette::{LabeledSpan, SourceCode, SourceSpan};
+
+use crate::{
+    assembly_gen::{self, generate_assembly},
+    ast,
+    code_emission::emit_asm,
+    lexer::{SourceMap, Token, lex},
+    optimize,
+    parser::parse,
+    preprocessor::preprocess,
+    sema::{SymbolTable, validate},
+    tacky,
+    target::Target,
+};
+
+/// The options that apply to every translation unit compiled in a session.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub target: Target,
+    /// The directories searched for headers, after the directory of the including file for
+    /// quoted names.
+    pub include_dirs: Vec<Utf8PathBuf>,
+    /// Whether to evaluate operations on constants at compile time.
+    pub fold_constants: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            target: Target::host(),
+            include_dirs: vec![],
+            fold_constants: false,
+        }
+    }
+}
+
+/// Runs the stages of compilation, one at a time or all together, on translation units given
+/// as text. Each stage that can fail returns a [`Diagnostic`] that points into the original
+/// files.
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    options: Options,
+}
+
+/// A preprocessed translation unit, along with the name of the file it came from.
+#[derive(Debug)]
+pub struct Source {
+    name: Utf8PathBuf,
+    text: String,
+    warnings: Vec<Diagnostic>,
+}
+
+impl Source {
+    pub fn name(&self) -> &Utf8Path {
+        &self.name
+    }
+
+    /// The preprocessed text, which includes line markers.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Maps positions in the text back to the files and lines they came from.
+    pub fn source_map(&self) -> SourceMap {
+        SourceMap::new(&self.text, &self.name)
+    }
+
+    /// The diagnostics that preprocessing gave without failing, such as those of `#warning`.
+    pub fn warnings(&self) -> &[Diagnostic] {
+        &self.warnings
+    }
+}
+
+/// The results of every stage after parsing, as given by [`Session::compile`].
+#[derive(Debug)]
+pub struct Compilation {
+    pub source: Source,
+    /// The AST after semantic analysis.
+    pub program: ast::Program,
+    pub symbols: SymbolTable,
+    /// The TACKY after optimization.
+    pub tacky: tacky::Program,
+    pub assembly: assembly_gen::Program,
+    /// The assembly for the session's target.
+    pub asm: String,
+}
+
+impl Session {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Preprocesses `text`, the contents of the file `name`.
+    pub fn preprocess(
+        &self,
+        name: impl AsRef<Utf8Path>,
+        text: impl Into<String>,
+    ) -> Result<Source, Diagnostic> {
+        let name = name.as_ref();
+        let system_include_dirs: Vec<Utf8PathBuf> = (self.options.target)
+            .system_include_dirs()
+            .iter()
+            .map(Utf8PathBuf::from)
+            .collect();
+        let (text, warnings) = preprocess(
+            name,
+            text.into(),
+            &self.options.include_dirs,
+            &system_include_dirs,
+        )?;
+        Ok(Source {
+            name: name.to_owned(),
+            text,
+            warnings: warnings.into_iter().map(Diagnostic::from).collect(),
+        })
+    }
+
+    pub fn lex(&self, source: &Source) -> Result<Vec<(Token, SourceSpan)>, Diagnostic> {
+        Ok(lex(&source.text, &source.name)?)
+    }
+
+    pub fn parse(
+        &self,
+        source: &Source,
+        tokens: Vec<(Token, SourceSpan)>,
+    ) -> Result<ast::Program, Diagnostic> {
+        Ok(parse(&source.text, tokens, source.name.as_str())?)
+    }
+
+    /// Resolves names and labels and checks types, rewriting `program` to make implicit
+    /// conversions explicit, and returns the symbol table.
+    pub fn validate(
+        &self,
+        source: &Source,
+        program: &mut ast::Program,
+    ) -> Result<SymbolTable, Diagnostic> {
+        validate(program).map_err(|e| source.source_map().attach(miette::Report::from(e)).into())
+    }
+
+    pub fn lower(&self, program: &ast::Program, symbols: &SymbolTable) -> tacky::Program {
+        tacky::lower(program, symbols)
+    }
+
+    /// Runs the optimizations that the options ask for on `program`.
+    pub fn optimize(&self, program: &mut tacky::Program) {
+        if self.options.fold_constants {
+            optimize::fold_constants(program);
+        }
+    }
+
+    pub fn generate_assembly(
+        &self,
+        program: &tacky::Program,
+        symbols: &SymbolTable,
+    ) -> assembly_gen::Program {
+        generate_assembly(program, symbols)
+    }
+
+    /// The assembly for `program` on the session's target.
+    pub fn emit_asm(&self, program: &assembly_gen::Program) -> String {
+        let mut assembly = vec![];
+        emit_asm(program, self.options.target, &mut assembly)
+            .expect("writing to a Vec cannot fail");
+        String::from_utf8(assembly).expect("assembly is ASCII")
+    }
+
+    /// Runs every stage on `text`, the contents of the file `name`.
+    pub fn compile(
+        &self,
+        name: impl AsRef<Utf8Path>,
+        text: impl Into<String>,
+    ) -> Result<Compilation, Diagnostic> {
+        let source = self.preprocess(name, text)?;
+        let tokens = self.lex(&source)?;
+        let mut program = self.parse(&source, tokens)?;
+        let symbols = self.validate(&source, &mut program)?;
+        let mut tacky = self.lower(&program, &symbols);
+        self.optimize(&mut tacky);
+        let assembly = self.generate_assembly(&tacky, &symbols);
+        let asm = self.emit_asm(&assembly);
+        Ok(Compilation {
+            source,
+            program,
+            symbols,
+            tacky,
+            assembly,
+            asm,
+        })
+    }
+}
+
+/// An error in a translation unit. Besides rendering as a `miette` diagnostic, it gives its
+/// message and the places it points to as plain data.
+pub struct Diagnostic {
+    report: miette::Report,
+}
+
+/// A place that a [`Diagnostic`] points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub label: Option<String>,
+    /// The file the place is in, after following line markers.
+    pub file: Option<String>,
+    /// The line, counting from one.
+    pub line: usize,
+    /// The column, counting from one.
+    pub column: usize,
+    /// The offset into the preprocessed text.
+    pub offset: usize,
+    pub length: usize,
+}
+
+impl Diagnostic {
+    /// What went wrong, without the context around it.
+    pub fn message(&self) -> String {
+        self.report.root_cause().to_string()
+    }
+
+    /// The context that the message was found in, outermost first, such as the `#include`s
+    /// that led to the file.
+    pub fn context(&self) -> Vec<String> {
+        let mut chain: Vec<_> = self.report.chain().map(ToString::to_string).collect();
+        chain.pop();
+        chain
+    }
+
+    pub fn locations(&self) -> Vec<Location> {
+        let Some(labels) = self.report.labels() else {
+            return vec![];
+        };
+        let source_code = self.report.source_code();
+        labels
+            .map(|label| {
+                let contents =
+                    source_code.and_then(|code| code.read_span(label.inner(), 0, 0).ok());
+                Location {
+                    label: label.label().map(str::to_string),
+                    file: contents
+                        .as_ref()
+                        .and_then(|contents| contents.name().map(str::to_string)),
+                    line: contents.as_ref().map_or(0, |contents| contents.line()) + 1,
+                    column: contents.as_ref().map_or(0, |contents| contents.column()) + 1,
+                    offset: label.offset(),
+                    length: label.len(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn into_report(self) -> miette::Report {
+        self.report
+    }
+}
+
+impl From<miette::Report> for Diagnostic {
+    fn from(report: miette::Report) -> Self {
+        Self { report }
+    }
+}
+
+impl Debug for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.report, f)
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.report, f)
+    }
+}
+
+impl Error for Diagnostic {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.report.source()
+    }
+}
+
+impl miette::Diagnostic for Diagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.report.code()
+    }
+
+    fn severity(&self) -> Option<miette::Severity> {
+        self.report.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.report.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.report.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.report.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.report.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+        self.report.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn miette::Diagnostic> {
+        self.report.diagnostic_source()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::{Diagnostic as _, Severity};
+
+    use super::{Options, Session};
+    use crate::target::Target;
+
+    #[test]
+    fn test_compile() {
+        let session = Session::new(Options {
+            target: Target::Linux,
+            ..Options::default()
+        });
+        let compilation = session
+            .compile("a.c", "#define N 3\nint main(void) { return N; }\n")
+            .unwrap();
+        assert_eq!(compilation.tacky.functions[0].identifier, "main");
+        assert!(compilation.asm.contains("\tmovl $3, %eax\n"));
+    }
+
+    #[test]
+    fn test_warnings() {
+        let source = Session::default()
+            .preprocess("a.c", "#warning \"not yet\"\nint x;\n")
+            .unwrap();
+        let warnings = source.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message(), "#warning \"not yet\"");
+        assert_eq!(warnings[0].severity(), Some(Severity::Warning));
+        assert_eq!(warnings[0].locations()[0].line, 1);
+        assert!(source.text().contains("int x;"));
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let session = Session::default();
+        let source = session
+            .preprocess("a.c", "int main(void) {\n  x = 1;\n}\n")
+            .unwrap();
+        let tokens = session.lex(&source).unwrap();
+        let mut program = session.parse(&source, tokens).unwrap();
+        let diagnostic = session.validate(&source, &mut program).unwrap_err();
+
+        let locations = diagnostic.locations();
+        assert_eq!(locations.len(), 1);
+        assert_eq!(locations[0].file.as_deref(), Some("a.c"));
+        assert_eq!((locations[0].line, locations[0].column), (2, 3));
+        assert_eq!(diagnostic.message(), "x assigned without being declared");
+        assert!(diagnostic.context().is_empty());
+    }
+}
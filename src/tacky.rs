@@ -5,11 +5,11 @@ use std::{
 
 pub use crate::ast::BinaryOperator;
 use crate::{
-    assembly_gen::Width,
     ast::{
-        self, Block, BlockItem, Constant, Declaration, Expression, Statement, VariableDeclaration,
+        self, Block, BlockItem, Constant, Declaration, Expression, Initializer, Statement,
+        VariableDeclaration,
     },
-    sema::{self, SymbolAttributes, SymbolTable, Type},
+    sema::{self, StaticInit, SymbolAttributes, SymbolTable, Type},
 };
 
 #[derive(Debug, Default)]
@@ -29,13 +29,15 @@ pub struct Function {
 pub struct StaticVariable {
     pub identifier: String,
     pub global: bool,
-    pub init: Constant,
-    pub width: Width,
+    pub init: Vec<StaticInit>,
+    pub alignment: u64,
+    /// Whether the variable is const, so that it can be placed in read-only memory.
+    pub read_only: bool,
 }
 
 #[derive(Debug)]
 pub enum Instruction {
-    Return(Val),
+    Return(Option<Val>),
     Unary {
         op: UnaryOperator,
         src: Val,
@@ -55,7 +57,7 @@ pub enum Instruction {
     JumpIfZero(Val, String),
     JumpIfNotZero(Val, String),
     Label(String),
-    Call(String, Vec<Val>, Val),
+    Call(Callee, Vec<Val>, Option<Val>),
     SignExtend {
         src: Val,
         dst: Val,
@@ -84,6 +86,90 @@ pub enum Instruction {
         src: Val,
         dst: Val,
     },
+    FloatToDouble {
+        src: Val,
+        dst: Val,
+    },
+    DoubleToFloat {
+        src: Val,
+        dst: Val,
+    },
+    GetAddress {
+        src: Val,
+        dst: Val,
+    },
+    /// Loads and stores, and the copies to and from offsets below, are the only instructions
+    /// that access volatile objects. Those that do are marked `volatile`, and must be kept
+    /// exactly as they are by optimizations.
+    Load {
+        src_ptr: Val,
+        dst: Val,
+        volatile: bool,
+    },
+    Store {
+        src: Val,
+        dst_ptr: Val,
+        volatile: bool,
+    },
+    /// `dst = ptr + index * scale`
+    AddPtr {
+        ptr: Val,
+        index: Val,
+        scale: i64,
+        dst: Val,
+    },
+    CopyToOffset {
+        src: Val,
+        dst: String,
+        offset: i64,
+        volatile: bool,
+    },
+    CopyFromOffset {
+        src: String,
+        offset: i64,
+        dst: Val,
+        volatile: bool,
+    },
+    /// Points the `va_list` element at `ap` to the variable arguments of the current function.
+    VaStart(Val),
+    /// Takes the next variable argument from the `va_list` element at `ap`.
+    VaArg {
+        ap: Val,
+        dst: Val,
+    },
+}
+
+impl Instruction {
+    /// Whether the instruction accesses a volatile object.
+    pub fn is_volatile(&self) -> bool {
+        match self {
+            Instruction::Load { volatile, .. }
+            | Instruction::Store { volatile, .. }
+            | Instruction::CopyToOffset { volatile, .. }
+            | Instruction::CopyFromOffset { volatile, .. } => *volatile,
+            _ => false,
+        }
+    }
+}
+
+/// The function called by a `Call`: either named directly, or through a function pointer.
+#[derive(Debug)]
+pub enum Callee {
+    Function(String, Type),
+    Pointer(Val),
+}
+
+impl Callee {
+    /// The type of the called function.
+    pub fn ty(&self) -> Type {
+        match self {
+            Callee::Function(_, ty) => ty.clone(),
+            Callee::Pointer(ptr) => match ptr.ty() {
+                Type::Pointer(referenced) => *referenced,
+                ty => unreachable!("calling non-function {ty:?}"),
+            },
+        }
+    }
 }
 
 #[derive(Clone, PartialEq)]
@@ -108,6 +194,137 @@ impl Val {
             Val::Var(_, ty) => ty.clone(),
         }
     }
+
+    fn name(&self) -> &str {
+        match self {
+            Val::Var(name, _) => name,
+            Val::Constant(constant) => unreachable!("{constant:?} is not an object"),
+        }
+    }
+}
+
+/// The result of lowering an expression that may be an lvalue: either a plain value, a
+/// pointer to the object that the expression designates, or a member of a structure variable.
+/// Volatile variables are accessed as a sub-object at offset 0, so that plain values are never
+/// volatile.
+enum ExpResult {
+    Plain(Val),
+    Dereferenced {
+        ptr: Val,
+        volatile: bool,
+    },
+    SubObject {
+        base: Val,
+        offset: i64,
+        ty: Type,
+        volatile: bool,
+    },
+}
+
+impl ExpResult {
+    fn ty(&self) -> Type {
+        match self {
+            ExpResult::Plain(val) => val.ty(),
+            ExpResult::Dereferenced { ptr, .. } => match ptr.ty() {
+                Type::Pointer(referenced) => *referenced,
+                ty => unreachable!("dereferencing non-pointer {ty:?}"),
+            },
+            ExpResult::SubObject { ty, .. } => ty.clone(),
+        }
+    }
+
+    fn load(&self, state: &mut State) -> Val {
+        match self {
+            ExpResult::Plain(val) => val.clone(),
+            ExpResult::Dereferenced { ptr, volatile } => {
+                let dst = state.var(self.ty());
+                state.push(Instruction::Load {
+                    src_ptr: ptr.clone(),
+                    dst: dst.clone(),
+                    volatile: *volatile,
+                });
+                dst
+            }
+            ExpResult::SubObject {
+                base,
+                offset,
+                ty,
+                volatile,
+            } => {
+                let dst = state.var(ty.clone());
+                state.push(Instruction::CopyFromOffset {
+                    src: base.name().to_string(),
+                    offset: *offset,
+                    dst: dst.clone(),
+                    volatile: *volatile,
+                });
+                dst
+            }
+        }
+    }
+
+    /// A pointer to the object designated by the result, which must be an lvalue.
+    fn address(&self, state: &mut State) -> Val {
+        match self {
+            ExpResult::Plain(src) => {
+                let dst = state.var(Type::Pointer(src.ty().into()));
+                state.push(Instruction::GetAddress {
+                    src: src.clone(),
+                    dst: dst.clone(),
+                });
+                dst
+            }
+            ExpResult::Dereferenced { ptr, .. } => ptr.clone(),
+            ExpResult::SubObject {
+                base, offset, ty, ..
+            } => {
+                let ptr = ExpResult::Plain(base.clone()).address(state);
+                offset_ptr(ptr, *offset, ty.clone(), state)
+            }
+        }
+    }
+
+    /// Converts the result to a value, decaying arrays to a pointer to their first element and
+    /// functions to a pointer to the function.
+    fn rvalue(&self, state: &mut State) -> Val {
+        let element = match self.ty() {
+            Type::Array { element, .. } => element,
+            Type::Function { .. } => return self.address(state),
+            _ => return self.load(state),
+        };
+        let dst = state.var(Type::Pointer(element));
+        let src = self.address(state);
+        state.push(Instruction::Copy {
+            src,
+            dst: dst.clone(),
+        });
+        dst
+    }
+
+    fn store(&self, src: Val, state: &mut State) {
+        match self {
+            ExpResult::Plain(val) => state.push(Instruction::Copy {
+                src,
+                dst: val.clone(),
+            }),
+            ExpResult::Dereferenced { ptr, volatile } => state.push(Instruction::Store {
+                src,
+                dst_ptr: ptr.clone(),
+                volatile: *volatile,
+            }),
+            ExpResult::SubObject {
+                base,
+                offset,
+                volatile,
+                ..
+            } => state.push(Instruction::CopyToOffset {
+                src,
+                dst: base.name().to_string(),
+                offset: *offset,
+                volatile: *volatile,
+            }),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -174,6 +391,7 @@ pub fn lower(program: &ast::Program, symbols: &sema::SymbolTable) -> Program {
                     functions.push(function);
                 }
             }
+            Declaration::Struct(_) | Declaration::Enum(_) | Declaration::Typedef(_) => {}
         }
     }
     Program {
@@ -184,19 +402,30 @@ pub fn lower(program: &ast::Program, symbols: &sema::SymbolTable) -> Program {
 
 fn constant(ty: Type, value: i64) -> Val {
     match ty {
-        Type::Function { .. } => unreachable!(),
+        Type::Function { .. }
+        | Type::Array { .. }
+        | Type::Void
+        | Type::Struct(_)
+        | Type::Qualified(..) => unreachable!(),
         Type::Int => Val::Constant(Constant::Int(value as i32)),
         Type::Long => Val::Constant(Constant::Long(value)),
         Type::UInt => Val::Constant(Constant::UInt(value as u32)),
         Type::ULong => Val::Constant(Constant::ULong(value as u64)),
+        Type::Float => Val::Constant(Constant::Float(value as f32)),
         Type::Double => Val::Constant(Constant::Double(value as f64)),
+        Type::Pointer(_) => Val::Constant(Constant::ULong(value as u64)),
+        Type::Char | Type::SChar => Val::Constant(Constant::Char(value as i8)),
+        Type::UChar => Val::Constant(Constant::UChar(value as u8)),
+        Type::Bool => Val::Constant(Constant::UChar((value != 0) as u8)),
+        Type::Short => Val::Constant(Constant::Short(value as i16)),
+        Type::UShort => Val::Constant(Constant::UShort(value as u16)),
     }
 }
 
 fn lower_variable_declaration<'i>(decl: &VariableDeclaration, state: &mut State<'i>) {
     let symbol = &state.symbols[&decl.name];
-    match symbol.attributes {
-        SymbolAttributes::Function { .. } => unreachable!(),
+    match &symbol.attributes {
+        SymbolAttributes::Function { .. } | SymbolAttributes::Constant(_) => unreachable!(),
         SymbolAttributes::Static {
             init, global: true, ..
         } if init.tentative() => {}
@@ -206,26 +435,194 @@ fn lower_variable_declaration<'i>(decl: &VariableDeclaration, state: &mut State<
                 .entry(decl.name.clone())
                 .or_insert_with(|| StaticVariable {
                     identifier: decl.name.clone(),
-                    global,
-                    init: init.constant(),
-                    width: decl.ty.width(),
+                    global: *global,
+                    init: init.values(&symbol.ty),
+                    alignment: symbol.ty.variable_alignment(),
+                    read_only: symbol.ty.is_const(),
                 });
         }
         SymbolAttributes::Local => {
-            let var = Val::Var(
-                decl.name.clone(),
-                state.symbols.get(&decl.name).unwrap().ty.clone(),
-            );
-            if let Some(rhs) = walk_optional(&decl.init, state) {
-                state.push(Instruction::Copy { src: rhs, dst: var })
+            let ty = symbol.ty.strip_qualifiers();
+            match &decl.init {
+                // volatile variables are initialized like aggregates, with volatile copies
+                Some(Initializer::Single(expr)) if !ty.is_array() && !symbol.ty.is_volatile() => {
+                    let src = walk(expr, state);
+                    state.push(Instruction::Copy {
+                        src,
+                        dst: Val::Var(decl.name.clone(), ty),
+                    })
+                }
+                Some(init) => lower_initializer(init, &decl.name, &ty, 0, state),
+                None => {}
             }
         }
     }
 }
 
+/// Initializes the part of the aggregate `name` at `offset`, zero filling any elements that
+/// have no initializer.
+fn lower_initializer<'i>(
+    init: &Initializer,
+    name: &str,
+    ty: &Type,
+    offset: i64,
+    state: &mut State<'i>,
+) {
+    match (init, ty) {
+        (Initializer::Single(Expression::String { value, .. }), Type::Array { size, .. }) => {
+            let mut bytes = value.clone();
+            bytes.resize(*size as usize, 0);
+            // copy eight bytes at a time where possible
+            let mut i = 0;
+            while i < bytes.len() {
+                let rest = &bytes[i..];
+                let (src, len) = if let Some(chunk) = rest.first_chunk::<8>() {
+                    (Constant::Long(i64::from_le_bytes(*chunk)), 8)
+                } else if let Some(chunk) = rest.first_chunk::<4>() {
+                    (Constant::Int(i32::from_le_bytes(*chunk)), 4)
+                } else {
+                    (Constant::Char(rest[0] as i8), 1)
+                };
+                state.push(Instruction::CopyToOffset {
+                    src: Val::Constant(src),
+                    dst: name.to_string(),
+                    offset: offset + i as i64,
+                    volatile: state.symbols[name].ty.is_volatile(),
+                });
+                i += len;
+            }
+        }
+        (Initializer::Single(expr), _) => {
+            let src = walk(expr, state);
+            state.push(Instruction::CopyToOffset {
+                src,
+                dst: name.to_string(),
+                offset,
+                volatile: state.symbols[name].ty.is_volatile(),
+            });
+        }
+        (Initializer::Compound(inits, _), Type::Array { element, size }) => {
+            let stride = element.size() as i64;
+            for (i, init) in inits.iter().enumerate() {
+                lower_initializer(init, name, element, offset + i as i64 * stride, state);
+            }
+            for i in inits.len() as u64..*size {
+                zero_initialize(name, element, offset + i as i64 * stride, state);
+            }
+        }
+        (Initializer::Compound(inits, _), Type::Struct(s)) => {
+            for (i, member) in s.initialized_members().iter().enumerate() {
+                let offset = offset + member.offset as i64;
+                let ty = member.ty.strip_qualifiers();
+                match inits.get(i) {
+                    Some(init) => lower_initializer(init, name, &ty, offset, state),
+                    None => zero_initialize(name, &ty, offset, state),
+                }
+            }
+        }
+        (Initializer::Compound(..), _) => unreachable!("compound initializer for {ty:?}"),
+    }
+}
+
+fn zero_initialize<'i>(name: &str, ty: &Type, offset: i64, state: &mut State<'i>) {
+    match ty {
+        Type::Array { element, size } => {
+            let stride = element.size() as i64;
+            for i in 0..*size as i64 {
+                zero_initialize(name, element, offset + i * stride, state);
+            }
+        }
+        Type::Struct(s) => {
+            for member in s.initialized_members() {
+                let ty = member.ty.strip_qualifiers();
+                zero_initialize(name, &ty, offset + member.offset as i64, state);
+            }
+        }
+        _ => state.push(Instruction::CopyToOffset {
+            src: constant(ty.clone(), 0),
+            dst: name.to_string(),
+            offset,
+            volatile: state.symbols[name].ty.is_volatile(),
+        }),
+    }
+}
+
+/// The size of the object `ptr` points to, by which pointer arithmetic scales its operand.
+fn pointee_size(ptr: &Val) -> i64 {
+    match ptr.ty() {
+        Type::Pointer(referenced) => referenced.size() as i64,
+        ty => unreachable!("pointer arithmetic on {ty:?}"),
+    }
+}
+
 fn walk<'i>(expr: &Expression, state: &mut State<'i>) -> Val {
     match expr {
         Expression::Constant { constant, .. } => Val::Constant(*constant),
+        Expression::Unary {
+            op:
+                op @ (ast::UnaryOperator::PrefixIncrement
+                | ast::UnaryOperator::PrefixDecrement
+                | ast::UnaryOperator::PostfixIncrement
+                | ast::UnaryOperator::PostfixDecrement),
+            expr,
+            ..
+        } => {
+            let lvalue = walk_lvalue(expr, state);
+            let src = lvalue.load(state);
+            let delta = match op {
+                ast::UnaryOperator::PrefixIncrement | ast::UnaryOperator::PostfixIncrement => 1,
+                _ => -1,
+            };
+            let result = match op {
+                ast::UnaryOperator::PostfixIncrement | ast::UnaryOperator::PostfixDecrement => {
+                    let dst = state.var(src.ty());
+                    state.push(Instruction::Copy {
+                        src: src.clone(),
+                        dst: dst.clone(),
+                    });
+                    Some(dst)
+                }
+                _ => None,
+            };
+            let updated = match &lvalue {
+                ExpResult::Plain(val) => val.clone(),
+                _ => state.var(src.ty()),
+            };
+            if src.ty().is_pointer() {
+                state.push(Instruction::AddPtr {
+                    ptr: src.clone(),
+                    index: constant(Type::Long, delta),
+                    scale: pointee_size(&src),
+                    dst: updated.clone(),
+                });
+            } else if src.ty().is_promotable() {
+                // small integers are promoted to int for the arithmetic
+                let promoted = convert(src.clone(), &Type::Int, state);
+                let sum = state.var(Type::Int);
+                state.push(Instruction::Binary {
+                    op: BinaryOperator::Add,
+                    lhs: promoted,
+                    rhs: constant(Type::Int, delta),
+                    dst: sum.clone(),
+                });
+                let sum = convert(sum, &src.ty(), state);
+                state.push(Instruction::Copy {
+                    src: sum,
+                    dst: updated.clone(),
+                });
+            } else {
+                state.push(Instruction::Binary {
+                    op: BinaryOperator::Add,
+                    lhs: src.clone(),
+                    rhs: constant(src.ty(), delta),
+                    dst: updated.clone(),
+                });
+            }
+            if !matches!(lvalue, ExpResult::Plain(_)) {
+                lvalue.store(updated.clone(), state);
+            }
+            result.unwrap_or(updated)
+        }
         Expression::Unary { op, expr, .. } => {
             let src = walk(expr, state);
             match op {
@@ -248,7 +645,7 @@ fn walk<'i>(expr: &Expression, state: &mut State<'i>) -> Val {
                     dst
                 }
                 ast::UnaryOperator::Not => {
-                    let dst = state.var(src.ty());
+                    let dst = state.var(Type::Int);
                     state.push(Instruction::Unary {
                         op: UnaryOperator::Not,
                         src,
@@ -256,52 +653,7 @@ fn walk<'i>(expr: &Expression, state: &mut State<'i>) -> Val {
                     });
                     dst
                 }
-                ast::UnaryOperator::PrefixIncrement => {
-                    state.push(Instruction::Binary {
-                        op: BinaryOperator::Add,
-                        lhs: src.clone(),
-                        rhs: constant(src.ty(), 1),
-                        dst: src.clone(),
-                    });
-                    src
-                }
-                ast::UnaryOperator::PrefixDecrement => {
-                    state.push(Instruction::Binary {
-                        op: BinaryOperator::Add,
-                        lhs: src.clone(),
-                        rhs: constant(src.ty(), -1),
-                        dst: src.clone(),
-                    });
-                    src.clone()
-                }
-                ast::UnaryOperator::PostfixIncrement => {
-                    let dst = state.var(src.ty());
-                    state.push(Instruction::Copy {
-                        src: src.clone(),
-                        dst: dst.clone(),
-                    });
-                    state.push(Instruction::Binary {
-                        op: BinaryOperator::Add,
-                        lhs: src.clone(),
-                        rhs: constant(src.ty(), 1),
-                        dst: src.clone(),
-                    });
-                    dst
-                }
-                ast::UnaryOperator::PostfixDecrement => {
-                    let dst = state.var(src.ty());
-                    state.push(Instruction::Copy {
-                        src: src.clone(),
-                        dst: dst.clone(),
-                    });
-                    state.push(Instruction::Binary {
-                        op: BinaryOperator::Add,
-                        lhs: src.clone(),
-                        rhs: constant(src.ty(), -1),
-                        dst: src.clone(),
-                    });
-                    dst
-                }
+                _ => unreachable!(),
             }
         }
         Expression::Binary {
@@ -360,11 +712,61 @@ fn walk<'i>(expr: &Expression, state: &mut State<'i>) -> Val {
 
             phi
         }
+        Expression::Binary {
+            op: BinaryOperator::Comma,
+            lhs,
+            rhs,
+            ..
+        } => {
+            walk(lhs, state);
+            walk(rhs, state)
+        }
         Expression::Binary { op, lhs, rhs, .. } => {
             let lhs = walk(lhs, state);
             let rhs = walk(rhs, state);
 
-            let dst = state.var(lhs.ty());
+            match (op, lhs.ty().is_pointer(), rhs.ty().is_pointer()) {
+                (BinaryOperator::Add, true, false) => return add_ptr(lhs, rhs, state),
+                (BinaryOperator::Add, false, true) => return add_ptr(rhs, lhs, state),
+                (BinaryOperator::Subtract, true, false) => {
+                    let index = state.var(rhs.ty());
+                    state.push(Instruction::Unary {
+                        op: UnaryOperator::Negate,
+                        src: rhs,
+                        dst: index.clone(),
+                    });
+                    return add_ptr(lhs, index, state);
+                }
+                (BinaryOperator::Subtract, true, true) => {
+                    let scale = pointee_size(&lhs);
+                    let diff = state.var(Type::Long);
+                    state.push(Instruction::Binary {
+                        op: BinaryOperator::Subtract,
+                        lhs,
+                        rhs,
+                        dst: diff.clone(),
+                    });
+                    let dst = state.var(Type::Long);
+                    state.push(Instruction::Binary {
+                        op: BinaryOperator::Divide,
+                        lhs: diff,
+                        rhs: constant(Type::Long, scale),
+                        dst: dst.clone(),
+                    });
+                    return dst;
+                }
+                _ => {}
+            }
+
+            let dst = match op {
+                BinaryOperator::Equals
+                | BinaryOperator::NotEqual
+                | BinaryOperator::LessThan
+                | BinaryOperator::LessThanOrEqual
+                | BinaryOperator::GreaterThan
+                | BinaryOperator::GreaterThanOrEqual => state.var(Type::Int),
+                _ => state.var(lhs.ty()),
+            };
             state.push(Instruction::Binary {
                 op: *op,
                 lhs,
@@ -373,30 +775,44 @@ fn walk<'i>(expr: &Expression, state: &mut State<'i>) -> Val {
             });
             dst
         }
-        Expression::Var { name, .. } => {
-            Val::Var(name.clone(), state.symbols.get(name).unwrap().ty.clone())
-        }
+        Expression::Var { .. }
+        | Expression::Dereference { .. }
+        | Expression::Subscript { .. }
+        | Expression::Dot { .. }
+        | Expression::Arrow { .. } => walk_lvalue(expr, state).rvalue(state),
         Expression::Assignment { lhs, rhs, .. } => {
             let rhs = walk(rhs, state);
-            let lhs = walk(lhs, state);
-            assert_eq!(lhs.ty(), rhs.ty(), "{lhs:?} {rhs:?}");
-            state.push(Instruction::Copy {
-                src: rhs,
-                dst: lhs.clone(),
-            });
-            lhs
+            let lvalue = walk_lvalue(lhs, state);
+            // signed char and boolean constants are represented as plain and unsigned chars
+            assert!(
+                lvalue.ty() == rhs.ty() || lvalue.ty().size() == 1 && rhs.ty().is_character(),
+                "{lhs:?} {rhs:?}"
+            );
+            lvalue.store(rhs.clone(), state);
+            match lvalue {
+                ExpResult::Plain(lhs) => lhs,
+                _ => rhs,
+            }
         }
         Expression::CompoundAssignment { lhs, op, rhs, .. } => {
             let rhs = walk(rhs, state);
-            let lhs = walk(lhs, state);
+            let lvalue = walk_lvalue(lhs, state);
+            let lhs = lvalue.load(state);
             assert_eq!(lhs.ty(), rhs.ty(), "{lhs:?} {op:?} {rhs:?}");
+            let dst = match &lvalue {
+                ExpResult::Plain(val) => val.clone(),
+                _ => state.var(lhs.ty()),
+            };
             state.push(Instruction::Binary {
                 op: *op,
-                lhs: lhs.clone(),
+                lhs,
                 rhs,
-                dst: lhs.clone(),
+                dst: dst.clone(),
             });
-            lhs
+            if !matches!(lvalue, ExpResult::Plain(_)) {
+                lvalue.store(dst.clone(), state);
+            }
+            dst
         }
         Expression::Ternary {
             cond,
@@ -412,113 +828,267 @@ fn walk<'i>(expr: &Expression, state: &mut State<'i>) -> Val {
             state.push(Instruction::JumpIfZero(cond, else_label.clone()));
             let src = walk(if_true, state);
             let phi = state.var(src.ty());
+            // void results are never read, so there is nothing to copy
+            let void = src.ty() == Type::Void;
 
-            state.push(Instruction::Copy {
-                src,
-                dst: phi.clone(),
-            });
+            if !void {
+                state.push(Instruction::Copy {
+                    src,
+                    dst: phi.clone(),
+                });
+            }
             state.push(Instruction::Jump(end_label.clone()));
             state.push(Instruction::Label(else_label));
             let src = walk(if_false, state);
-            state.push(Instruction::Copy {
-                src,
-                dst: phi.clone(),
-            });
+            if !void {
+                state.push(Instruction::Copy {
+                    src,
+                    dst: phi.clone(),
+                });
+            }
             state.push(Instruction::Label(end_label));
             phi
         }
         Expression::FunctionCall {
             function, params, ..
         } => {
-            let params = params.iter().map(|e| walk(e, state)).collect::<Vec<_>>();
-            match function.as_ref() {
-                Expression::Var { name, .. } => {
-                    let Type::Function { params: _, ref ret } = state.symbols.get(name).unwrap().ty
-                    else {
-                        unreachable!()
-                    };
-                    let dst = state.var(ret.as_ref().clone());
-                    state.push(Instruction::Call(name.clone(), params, dst.clone()));
-                    dst
+            // functions named directly are called without taking their address
+            let callee = match function.as_ref() {
+                Expression::Var { name, .. }
+                    if matches!(state.symbols[name].ty, Type::Function { .. }) =>
+                {
+                    Callee::Function(name.clone(), state.symbols[name].ty.strip_qualifiers())
                 }
-                _ => unreachable!(),
+                function => Callee::Pointer(walk(function, state)),
+            };
+            let Type::Function { ret, .. } = callee.ty() else {
+                unreachable!()
+            };
+            let params = params.iter().map(|e| walk(e, state)).collect::<Vec<_>>();
+            if *ret == Type::Void {
+                state.push(Instruction::Call(callee, params, None));
+                return state.var(Type::Void);
             }
+            let dst = state.var(*ret);
+            state.push(Instruction::Call(callee, params, Some(dst.clone())));
+            dst
+        }
+        Expression::Cast {
+            to: Type::Void,
+            expr,
+            ..
+        } => {
+            walk(expr, state);
+            state.var(Type::Void)
         }
         Expression::Cast { to, expr, .. } => {
-            use Type::*;
             let src = walk(expr, state);
-            match (src.ty(), to) {
-                (Function { .. }, _) | (_, Function { .. }) => unreachable!(),
-                (from, t) if from == *t => src,
-                (Int, Long | ULong) => {
-                    let dst = state.var(to.clone());
-                    state.push(Instruction::SignExtend {
-                        src,
-                        dst: dst.clone(),
-                    });
-                    dst
-                }
-                (Long | ULong, Int | UInt) => {
-                    let dst = state.var(to.clone());
-                    state.push(Instruction::Truncate {
-                        src,
-                        dst: dst.clone(),
-                    });
-                    dst
-                }
-                (UInt, Int) | (Int, UInt) | (Long, ULong) | (ULong, Long) => {
-                    let dst = state.var(to.clone());
-                    state.push(Instruction::Copy {
-                        src,
-                        dst: dst.clone(),
-                    });
-                    dst
-                }
+            convert(src, &to.strip_qualifiers(), state)
+        }
+        Expression::AddrOf { expr, .. } => walk_lvalue(expr, state).address(state),
+        Expression::String { .. } => unreachable!("string literals are replaced by type checking"),
+        Expression::SizeOf { .. } | Expression::SizeOfType { .. } => {
+            unreachable!("sizeof is replaced by type checking")
+        }
+        Expression::VaStart { ap, .. } => {
+            let ap = walk(ap, state);
+            state.push(Instruction::VaStart(ap));
+            state.var(Type::Void)
+        }
+        Expression::VaArg { ap, ty, .. } => {
+            let ap = walk(ap, state);
+            let dst = state.var(ty.strip_qualifiers());
+            state.push(Instruction::VaArg {
+                ap,
+                dst: dst.clone(),
+            });
+            dst
+        }
+        Expression::VaCopy { dst, src, .. } => {
+            let dst_ptr = walk(dst, state);
+            let src_ptr = walk(src, state);
+            let copy = state.var(Type::Struct(sema::StructType::va_list_tag()));
+            state.push(Instruction::Load {
+                src_ptr,
+                dst: copy.clone(),
+                volatile: false,
+            });
+            state.push(Instruction::Store {
+                src: copy,
+                dst_ptr,
+                volatile: false,
+            });
+            state.var(Type::Void)
+        }
+        // there is nothing to clean up
+        Expression::VaEnd { ap, .. } => {
+            walk(ap, state);
+            state.var(Type::Void)
+        }
+    }
+}
 
-                (UInt, Long | ULong) => {
-                    let dst = state.var(to.clone());
-                    state.push(Instruction::ZeroExtend {
-                        src,
-                        dst: dst.clone(),
-                    });
-                    dst
-                }
+fn convert<'i>(src: Val, to: &Type, state: &mut State<'i>) -> Val {
+    use Type::*;
+    let instruction: fn(Val, Val) -> Instruction = match (src.ty(), to) {
+        (Function { .. } | Array { .. } | Struct(_), _)
+        | (_, Function { .. } | Array { .. } | Struct(_)) => {
+            unreachable!("cast from {:?} to {to:?}", src.ty())
+        }
+        (from, to) if from == *to => return src,
+        // anything nonzero converts to true
+        (from, Bool) => {
+            let dst = state.var(Bool);
+            state.push(Instruction::Binary {
+                op: BinaryOperator::NotEqual,
+                lhs: src,
+                rhs: constant(from, 0),
+                dst: dst.clone(),
+            });
+            return dst;
+        }
+        // there are no conversions between small integers and floating types, so go through int
+        (from, to) if from.is_promotable() && to.is_floating() => {
+            let src = convert(src, &Int, state);
+            return convert(src, to, state);
+        }
+        (from, to) if from.is_floating() && to.is_promotable() => {
+            let src = convert(src, &Int, state);
+            return convert(src, to, state);
+        }
+        (Float, Double) => |src, dst| Instruction::FloatToDouble { src, dst },
+        (Double, Float) => |src, dst| Instruction::DoubleToFloat { src, dst },
+        // the integer conversions take the floating type from the other operand
+        (UInt | ULong, to) if to.is_floating() => |src, dst| Instruction::UIntToDouble { src, dst },
+        (_, to) if to.is_floating() => |src, dst| Instruction::IntToDouble { src, dst },
+        (from, UInt | ULong) if from.is_floating() => {
+            |src, dst| Instruction::DoubleToUInt { src, dst }
+        }
+        (from, _) if from.is_floating() => |src, dst| Instruction::DoubleToInt { src, dst },
+        (from, to) if from.size() == to.size() => |src, dst| Instruction::Copy { src, dst },
+        (from, to) if from.size() > to.size() => |src, dst| Instruction::Truncate { src, dst },
+        (from, _) if from.signed() => |src, dst| Instruction::SignExtend { src, dst },
+        _ => |src, dst| Instruction::ZeroExtend { src, dst },
+    };
+    let dst = state.var(to.clone());
+    state.push(instruction(src, dst.clone()));
+    dst
+}
 
-                (UInt | ULong, Double) => {
-                    let dst = state.var(Type::Double);
-                    state.push(Instruction::UIntToDouble {
-                        src,
-                        dst: dst.clone(),
-                    });
-                    dst
-                }
-                (Int | Long, Double) => {
-                    let dst = state.var(Type::Double);
-                    state.push(Instruction::IntToDouble {
-                        src,
-                        dst: dst.clone(),
-                    });
-                    dst
-                }
-                (Double, Int | Long) => {
-                    let dst = state.var(to.clone());
-                    state.push(Instruction::DoubleToInt {
-                        src,
-                        dst: dst.clone(),
-                    });
-                    dst
-                }
-                (Double, UInt | ULong) => {
-                    let dst = state.var(to.clone());
-                    state.push(Instruction::DoubleToUInt {
-                        src,
-                        dst: dst.clone(),
-                    });
-                    dst
+fn add_ptr<'i>(ptr: Val, index: Val, state: &mut State<'i>) -> Val {
+    let dst = state.var(ptr.ty());
+    state.push(Instruction::AddPtr {
+        scale: pointee_size(&ptr),
+        ptr,
+        index,
+        dst: dst.clone(),
+    });
+    dst
+}
+
+/// Offsets `ptr` by `offset` bytes, giving a pointer to an object of type `ty`.
+fn offset_ptr<'i>(ptr: Val, offset: i64, ty: Type, state: &mut State<'i>) -> Val {
+    let dst = state.var(Type::Pointer(ty.into()));
+    state.push(Instruction::AddPtr {
+        ptr,
+        index: constant(Type::Long, offset),
+        scale: 1,
+        dst: dst.clone(),
+    });
+    dst
+}
+
+/// The offset and type of `name` within the structure type `ty`, and whether it is volatile.
+fn member(ty: &Type, name: &str) -> (i64, Type, bool) {
+    let Type::Struct(s) = ty else {
+        unreachable!("member access on {ty:?}")
+    };
+    let member = s.layout_unchecked().member(name).unwrap();
+    (
+        member.offset as i64,
+        member.ty.strip_qualifiers(),
+        member.ty.is_volatile(),
+    )
+}
+
+fn walk_lvalue<'i>(expr: &Expression, state: &mut State<'i>) -> ExpResult {
+    match expr {
+        Expression::Var { name, .. } => {
+            let ty = &state.symbols[name].ty;
+            let base = Val::Var(name.clone(), ty.strip_qualifiers());
+            if ty.is_volatile() && !ty.is_array() {
+                ExpResult::SubObject {
+                    ty: base.ty(),
+                    base,
+                    offset: 0,
+                    volatile: true,
                 }
-                (from, to) => unreachable!("cast {expr:?} from {from:?} to {to:?}"),
+            } else {
+                ExpResult::Plain(base)
+            }
+        }
+        Expression::Dereference { expr, .. } => ExpResult::Dereferenced {
+            ptr: walk(expr, state),
+            volatile: sema::points_to_volatile(expr),
+        },
+        Expression::Subscript { array, index, .. } => {
+            let volatile = sema::points_to_volatile(array) || sema::points_to_volatile(index);
+            let array = walk(array, state);
+            let index = walk(index, state);
+            let ptr = if array.ty().is_pointer() {
+                add_ptr(array, index, state)
+            } else {
+                add_ptr(index, array, state)
+            };
+            ExpResult::Dereferenced { ptr, volatile }
+        }
+        Expression::Dot {
+            expr, member: name, ..
+        } => {
+            let lvalue = walk_lvalue(expr, state);
+            let (offset, ty, volatile) = member(&lvalue.ty(), name);
+            match lvalue {
+                ExpResult::Plain(base) => ExpResult::SubObject {
+                    base,
+                    offset,
+                    ty,
+                    volatile,
+                },
+                ExpResult::Dereferenced {
+                    ptr,
+                    volatile: base_volatile,
+                } => ExpResult::Dereferenced {
+                    ptr: offset_ptr(ptr, offset, ty, state),
+                    volatile: base_volatile || volatile,
+                },
+                ExpResult::SubObject {
+                    base,
+                    offset: base_offset,
+                    volatile: base_volatile,
+                    ..
+                } => ExpResult::SubObject {
+                    base,
+                    offset: base_offset + offset,
+                    ty,
+                    volatile: base_volatile || volatile,
+                },
+            }
+        }
+        Expression::Arrow {
+            expr, member: name, ..
+        } => {
+            let base_volatile = sema::points_to_volatile(expr);
+            let ptr = walk(expr, state);
+            let Type::Pointer(referenced) = ptr.ty() else {
+                unreachable!("member access through {ptr:?}")
+            };
+            let (offset, ty, volatile) = member(&referenced, name);
+            ExpResult::Dereferenced {
+                ptr: offset_ptr(ptr, offset, ty, state),
+                volatile: base_volatile || volatile,
             }
         }
+        expr => ExpResult::Plain(walk(expr, state)),
     }
 }
 
@@ -528,8 +1098,8 @@ fn walk_optional<'i>(expr: &Option<Expression>, state: &mut State<'i>) -> Option
 
 fn walk_statement<'i>(statement: &Statement, state: &mut State<'i>) {
     match statement {
-        Statement::Return(expression) => {
-            let ret = walk(expression, state);
+        Statement::Return { expr, .. } => {
+            let ret = walk_optional(expr, state);
             state.push(Instruction::Return(ret));
         }
         Statement::Expression(expression) => {
@@ -622,7 +1192,7 @@ fn walk_statement<'i>(statement: &Statement, state: &mut State<'i>) {
             let cond_label = format!("{end_label}.cond");
             let start_label = format!("{end_label}.start");
 
-            match init {
+            match init.as_ref() {
                 ast::ForInit::Decl(variable_declaration) => {
                     lower_variable_declaration(variable_declaration, state)
                 }
@@ -728,7 +1298,10 @@ fn walk_statement<'i>(statement: &Statement, state: &mut State<'i>) {
 fn walk_declaration<'i>(decl: &Declaration, state: &mut State<'i>) {
     match decl {
         Declaration::Variable(decl) => lower_variable_declaration(decl, state),
-        Declaration::Function(_) => {}
+        Declaration::Function(_)
+        | Declaration::Struct(_)
+        | Declaration::Enum(_)
+        | Declaration::Typedef(_) => {}
     }
 }
 
@@ -751,12 +1324,17 @@ fn lower_function<'a>(
     let params = function
         .params
         .iter()
-        .map(|(ty, name, _)| Val::Var(name.clone(), ty.clone()))
+        .map(|(ty, name, _)| Val::Var(name.clone(), ty.strip_qualifiers()))
         .collect();
 
     walk_block(body, &mut state);
 
-    state.push(Instruction::Return(constant(function.ret.clone(), 0)));
+    let ret = match function.ret.strip_qualifiers() {
+        // falling off the end leaves a structure result undefined
+        Type::Void | Type::Struct(_) => None,
+        ty => Some(constant(ty, 0)),
+    };
+    state.push(Instruction::Return(ret));
 
     for (key, sv) in state.static_variables {
         parent_state.static_variables.entry(key).or_insert(sv);
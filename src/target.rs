@@ -0,0 +1,72 @@
+/// The platform that code is generated for, which decides how symbols and labels are spelled,
+/// which sections and directives the assembly uses, and how it is assembled and linked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Target {
+    #[value(name = "x86_64-linux-gnu")]
+    Linux,
+    #[value(name = "x86_64-apple-darwin")]
+    Darwin,
+}
+
+impl Target {
+    /// The platform badcc itself was built for.
+    pub fn host() -> Self {
+        if cfg!(target_os = "macos") {
+            Target::Darwin
+        } else {
+            Target::Linux
+        }
+    }
+
+    /// The directories searched for `#include <...>` after the `-I` ones. They hold the C
+    /// library headers of the host, which do not match any other platform, so there are none
+    /// when compiling for one.
+    pub fn system_include_dirs(self) -> &'static [&'static str] {
+        if self != Target::host() {
+            return &[];
+        }
+        match self {
+            Target::Linux => &[
+                "/usr/local/include",
+                "/usr/include/x86_64-linux-gnu",
+                "/usr/include",
+            ],
+            Target::Darwin => &[
+                "/usr/local/include",
+                "/Library/Developer/CommandLineTools/SDKs/MacOSX.sdk/usr/include",
+            ],
+        }
+    }
+
+    /// The prefix of the assembly name of a C identifier.
+    pub fn symbol_prefix(self) -> &'static str {
+        match self {
+            Target::Linux => "",
+            Target::Darwin => "_",
+        }
+    }
+
+    /// The prefix of labels that the assembler keeps out of the symbol table.
+    pub fn local_label_prefix(self) -> &'static str {
+        match self {
+            Target::Linux => ".L",
+            Target::Darwin => "L",
+        }
+    }
+
+    /// The section directive for the compiler's own constants, such as string literals.
+    pub fn constant_section(self) -> &'static str {
+        match self {
+            Target::Linux => "section .rodata",
+            Target::Darwin => "cstring",
+        }
+    }
+
+    /// The section directive for `const` objects.
+    pub fn read_only_section(self) -> &'static str {
+        match self {
+            Target::Linux => "section .rodata",
+            Target::Darwin => "const",
+        }
+    }
+}
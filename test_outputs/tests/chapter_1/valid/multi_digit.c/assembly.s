@@ -1,14 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	movl $100, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,52 +0,0 @@
-	.globl _a
-	.data
-_a:
-	.long 5
-	.globl _return_a
-	.text
-_return_a:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	movl _a(%rip), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $3, -12(%rbp)
-	cmpl $5, _a(%rip)
-	movl $0, -16(%rbp)
-	setNE -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jE Lmain.0.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	movl $4, _a(%rip)
-	call _return_a
-	movl %eax, -20(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -24(%rbp)
-	movl -20(%rbp), %r10d
-	addl %r10d, -24(%rbp)
-	movl -24(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,29 +0,0 @@
-	.globl _foo
-	.data
-_foo:
-	.long 3
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	movl $1, -12(%rbp)
-	movl $0, -16(%rbp)
-	cmpl $0, -12(%rbp)
-	jE Lmain.0.true
-	movl _foo(%rip), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
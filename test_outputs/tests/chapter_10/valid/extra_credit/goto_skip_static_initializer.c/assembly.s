@@ -1,19 +0,0 @@
-	.data
-_x.1:
-	.long 10
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	jmp Lmain.end
-	Lmain.end:
-	movl _x.1(%rip), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
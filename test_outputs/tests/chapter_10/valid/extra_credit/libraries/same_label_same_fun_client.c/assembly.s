@@ -1,62 +0,0 @@
-	.globl _f
-	.text
-_f:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	jmp Lf.x
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	Lf.x:
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	call _f
-	movl %eax, -12(%rbp)
-	cmpl $1, -12(%rbp)
-	movl $0, -16(%rbp)
-	setNE -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jE Lmain.0.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	call _f_caller
-	movl %eax, -20(%rbp)
-	cmpl $2, -20(%rbp)
-	movl $0, -24(%rbp)
-	setNE -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jE Lmain.1.true
-	movl $2, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.1.end
-	Lmain.1.true:
-	Lmain.1.end:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,75 +0,0 @@
-	.globl _x
-	.bss
-_x:
-	.zero 4
-
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	call _update_x
-	movl %eax, -12(%rbp)
-	jmp Lswitch.0.cases
-	Lswitch.0.0:
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	Lswitch.0.1:
-	movl $2, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	Lswitch.0.4:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	Lswitch.0.default:
-	movl $4, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lswitch.0
-	Lswitch.0.cases:
-	movl $0, %r11d
-	cmpl _x(%rip), %r11d
-	movl $0, -16(%rbp)
-	setE -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jNE Lswitch.0.0
-	movl $1, %r11d
-	cmpl _x(%rip), %r11d
-	movl $0, -16(%rbp)
-	setE -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jNE Lswitch.0.1
-	movl $4, %r11d
-	cmpl _x(%rip), %r11d
-	movl $0, -16(%rbp)
-	setE -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jNE Lswitch.0.4
-	jmp Lswitch.0.default
-	Lswitch.0:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _update_x
-	.text
-_update_x:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	movl $4, _x(%rip)
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
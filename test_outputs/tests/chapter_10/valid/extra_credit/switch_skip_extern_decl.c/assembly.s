@@ -1,75 +0,0 @@
-	.globl _x
-	.data
-_x:
-	.long 15
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $10, -12(%rbp)
-	jmp Lswitch.0.cases
-	Lswitch.0.1:
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	Lswitch.0.2:
-	movl $2, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	Lswitch.0.10:
-	movl _x(%rip), %r10d
-	movl %r10d, -16(%rbp)
-	movl -16(%rbp), %r11d
-	imull $2, %r11d
-	movl %r11d, -16(%rbp)
-	cmpl $30, -16(%rbp)
-	movl $0, -20(%rbp)
-	setE -20(%rbp)
-	cmpl $0, -20(%rbp)
-	jE Lmain.0.true
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	Lswitch.0.default:
-	movl $5, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lswitch.0
-	Lswitch.0.cases:
-	movl $1, %r11d
-	cmpl -12(%rbp), %r11d
-	movl $0, -24(%rbp)
-	setE -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jNE Lswitch.0.1
-	movl $2, %r11d
-	cmpl -12(%rbp), %r11d
-	movl $0, -24(%rbp)
-	setE -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jNE Lswitch.0.2
-	movl $10, %r11d
-	cmpl -12(%rbp), %r11d
-	movl $0, -24(%rbp)
-	setE -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jNE Lswitch.0.10
-	jmp Lswitch.0.default
-	Lswitch.0:
-	movl $6, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
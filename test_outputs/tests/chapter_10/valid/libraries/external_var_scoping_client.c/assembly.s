@@ -1,57 +0,0 @@
-	.globl _x
-	.data
-_x:
-	.long 10
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $0, -12(%rbp)
-	cmpl $0, -12(%rbp)
-	movl $0, -16(%rbp)
-	setE -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jE Lmain.0.true
-	call _read_x
-	movl %eax, -20(%rbp)
-	cmpl $10, -20(%rbp)
-	movl $0, -24(%rbp)
-	setNE -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jE Lmain.1.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.1.end
-	Lmain.1.true:
-	Lmain.1.end:
-	cmpl $10, _x(%rip)
-	movl $0, -28(%rbp)
-	setNE -28(%rbp)
-	cmpl $0, -28(%rbp)
-	jE Lmain.2.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.2.end
-	Lmain.2.true:
-	Lmain.2.end:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
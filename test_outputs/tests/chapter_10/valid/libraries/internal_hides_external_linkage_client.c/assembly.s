@@ -1,72 +0,0 @@
-	.data
-_x:
-	.long 1
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	cmpl $1, _x(%rip)
-	movl $0, -12(%rbp)
-	setNE -12(%rbp)
-	cmpl $0, -12(%rbp)
-	jE Lmain.0.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	movl $2, _x(%rip)
-	call _read_internal_x
-	movl %eax, -16(%rbp)
-	cmpl $2, -16(%rbp)
-	movl $0, -20(%rbp)
-	setNE -20(%rbp)
-	cmpl $0, -20(%rbp)
-	jE Lmain.1.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.1.end
-	Lmain.1.true:
-	Lmain.1.end:
-	call _read_x
-	movl %eax, -24(%rbp)
-	cmpl $10, -24(%rbp)
-	movl $0, -28(%rbp)
-	setNE -28(%rbp)
-	cmpl $0, -28(%rbp)
-	jE Lmain.2.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.2.end
-	Lmain.2.true:
-	Lmain.2.end:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _read_internal_x
-	.text
-_read_internal_x:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	movl _x(%rip), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,70 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	call _call_static_my_fun
-	movl %eax, -12(%rbp)
-	cmpl $1, -12(%rbp)
-	movl $0, -16(%rbp)
-	setNE -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jE Lmain.0.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	call _my_fun
-	movl %eax, -20(%rbp)
-	cmpl $100, -20(%rbp)
-	movl $0, -24(%rbp)
-	setNE -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jE Lmain.1.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.1.end
-	Lmain.1.true:
-	Lmain.1.end:
-	call _call_static_my_fun_2
-	movl %eax, -28(%rbp)
-	cmpl $2, -28(%rbp)
-	movl $0, -32(%rbp)
-	setNE -32(%rbp)
-	cmpl $0, -32(%rbp)
-	jE Lmain.2.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.2.end
-	Lmain.2.true:
-	Lmain.2.end:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _my_fun
-	.text
-_my_fun:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	movl $100, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,51 +0,0 @@
-	.globl _foo
-	.text
-_foo:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $48, %rsp
-	movl %edi, -12(%rbp)
-	movl %esi, -16(%rbp)
-	movl %edx, -20(%rbp)
-	movl %ecx, -24(%rbp)
-	movl %r8d, -28(%rbp)
-	movl %r9d, -32(%rbp)
-	movl 16(%rbp), %r10d
-	movl %r10d, -36(%rbp)
-	movl -36(%rbp), %r10d
-	movl %r10d, -40(%rbp)
-	addl $1, -40(%rbp)
-	movl -40(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	subq $8, %rsp
-	movl $0, %edi
-	movl $0, %esi
-	movl $0, %edx
-	movl $0, %ecx
-	movl $0, %r8d
-	movl $0, %r9d
-	movl _zed(%rip), %eax
-	pushq %rax
-	call _foo
-	addq $16, %rsp
-	movl %eax, -12(%rbp)
-	movl -12(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
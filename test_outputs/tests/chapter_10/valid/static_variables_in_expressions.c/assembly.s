@@ -1,38 +0,0 @@
-	.data
-_i.1:
-	.long 2
-	.data
-_j.2:
-	.long 3
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl _j.2(%rip), %r10d
-	cmpl %r10d, _i.1(%rip)
-	movl $0, -12(%rbp)
-	setL -12(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -16(%rbp)
-	cmpl $0, -16(%rbp)
-	movl $0, -20(%rbp)
-	setE -20(%rbp)
-	cmpl $0, -20(%rbp)
-	jE Lmain.0.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
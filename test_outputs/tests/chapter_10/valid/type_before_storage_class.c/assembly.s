@@ -1,36 +0,0 @@
-	.data
-_bar:
-	.long 4
-	.text
-_foo:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	movl $3, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	call _foo
-	movl %eax, -12(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -16(%rbp)
-	movl _bar(%rip), %r10d
-	addl %r10d, -16(%rbp)
-	movl -16(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,239 +0,0 @@
-	.globl _ulong_to_int
-	.text
-_ulong_to_int:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movq %rdi, -16(%rbp)
-	movl %esi, -20(%rbp)
-	movl -16(%rbp), %r10d
-	movl %r10d, -24(%rbp)
-	movl -24(%rbp), %r10d
-	movl %r10d, -28(%rbp)
-	movl -20(%rbp), %r10d
-	cmpl %r10d, -28(%rbp)
-	movl $0, -32(%rbp)
-	setE -32(%rbp)
-	movl -32(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _ulong_to_uint
-	.text
-_ulong_to_uint:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movq %rdi, -16(%rbp)
-	movl %esi, -20(%rbp)
-	movl -16(%rbp), %r10d
-	movl %r10d, -24(%rbp)
-	movl -20(%rbp), %r10d
-	cmpl %r10d, -24(%rbp)
-	movl $0, -28(%rbp)
-	setE -28(%rbp)
-	movl -28(%rbp), %r10d
-	movl %r10d, -32(%rbp)
-	movl -32(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _long_to_uint
-	.text
-_long_to_uint:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movq %rdi, -16(%rbp)
-	movl %esi, -20(%rbp)
-	movl -16(%rbp), %r10d
-	movl %r10d, -24(%rbp)
-	movl -20(%rbp), %r10d
-	cmpl %r10d, -24(%rbp)
-	movl $0, -28(%rbp)
-	setE -28(%rbp)
-	movl -28(%rbp), %r10d
-	movl %r10d, -32(%rbp)
-	movl -32(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $112, %rsp
-	movq $100, %rdi
-	movl $100, %esi
-	call _long_to_uint
-	movl %eax, -12(%rbp)
-	cmpl $0, -12(%rbp)
-	movl $0, -16(%rbp)
-	setE -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jE Lmain.0.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	movq $9223372036854774574, %r10
-	movq %r10, -24(%rbp)
-	negq -24(%rbp)
-	movq -24(%rbp), %rdi
-	movl $1234, %esi
-	call _long_to_uint
-	movl %eax, -28(%rbp)
-	cmpl $0, -28(%rbp)
-	movl $0, -32(%rbp)
-	setE -32(%rbp)
-	cmpl $0, -32(%rbp)
-	jE Lmain.1.true
-	movl $2, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.1.end
-	Lmain.1.true:
-	Lmain.1.end:
-	movq $100, %rdi
-	movl $100, %esi
-	call _ulong_to_int
-	movl %eax, -36(%rbp)
-	cmpl $0, -36(%rbp)
-	movl $0, -40(%rbp)
-	setE -40(%rbp)
-	cmpl $0, -40(%rbp)
-	jE Lmain.2.true
-	movl $3, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.2.end
-	Lmain.2.true:
-	Lmain.2.end:
-	movq $100, %rdi
-	movl $100, %esi
-	call _ulong_to_uint
-	movl %eax, -44(%rbp)
-	cmpl $0, -44(%rbp)
-	movl $0, -48(%rbp)
-	setE -48(%rbp)
-	cmpl $0, -48(%rbp)
-	jE Lmain.3.true
-	movl $4, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.3.end
-	Lmain.3.true:
-	Lmain.3.end:
-	movq $4294967200, %rdi
-	movl $4294967200, %esi
-	call _ulong_to_uint
-	movl %eax, -52(%rbp)
-	cmpl $0, -52(%rbp)
-	movl $0, -56(%rbp)
-	setE -56(%rbp)
-	cmpl $0, -56(%rbp)
-	jE Lmain.4.true
-	movl $5, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.4.end
-	Lmain.4.true:
-	Lmain.4.end:
-	movl $96, -60(%rbp)
-	negl -60(%rbp)
-	movq $4294967200, %rdi
-	movl -60(%rbp), %esi
-	call _ulong_to_int
-	movl %eax, -64(%rbp)
-	cmpl $0, -64(%rbp)
-	movl $0, -68(%rbp)
-	setE -68(%rbp)
-	cmpl $0, -68(%rbp)
-	jE Lmain.5.true
-	movl $6, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.5.end
-	Lmain.5.true:
-	Lmain.5.end:
-	movq $1152921506754330624, %rdi
-	movl $2147483648, %esi
-	call _ulong_to_uint
-	movl %eax, -72(%rbp)
-	cmpl $0, -72(%rbp)
-	movl $0, -76(%rbp)
-	setE -76(%rbp)
-	cmpl $0, -76(%rbp)
-	jE Lmain.6.true
-	movl $7, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.6.end
-	Lmain.6.true:
-	Lmain.6.end:
-	movq $2147483648, %r10
-	movq %r10, -84(%rbp)
-	negq -84(%rbp)
-	movl -84(%rbp), %r10d
-	movl %r10d, -88(%rbp)
-	movq $1152921506754330624, %rdi
-	movl -88(%rbp), %esi
-	call _ulong_to_int
-	movl %eax, -92(%rbp)
-	cmpl $0, -92(%rbp)
-	movl $0, -96(%rbp)
-	setE -96(%rbp)
-	cmpl $0, -96(%rbp)
-	jE Lmain.7.true
-	movl $8, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.7.end
-	Lmain.7.true:
-	Lmain.7.end:
-	movl $5, -100(%rbp)
-	movl -100(%rbp), %r10d
-	movl %r10d, -104(%rbp)
-	cmpl $5, -104(%rbp)
-	movl $0, -108(%rbp)
-	setNE -108(%rbp)
-	cmpl $0, -108(%rbp)
-	jE Lmain.8.true
-	movl $9, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.8.end
-	Lmain.8.true:
-	Lmain.8.end:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
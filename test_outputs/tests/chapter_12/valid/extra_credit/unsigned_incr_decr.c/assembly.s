@@ -1,133 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $80, %rsp
-	movl $0, -12(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -16(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -12(%rbp)
-	movl $4294967295, %r10d
-	addl %r10d, -12(%rbp)
-	cmpl $0, -16(%rbp)
-	movl $0, -20(%rbp)
-	setNE -20(%rbp)
-	cmpl $0, -20(%rbp)
-	jE Lmain.0.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	movl $4294967295, %r10d
-	cmpl %r10d, -12(%rbp)
-	movl $0, -24(%rbp)
-	setNE -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jE Lmain.1.true
-	movl $2, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.1.end
-	Lmain.1.true:
-	Lmain.1.end:
-	movl -12(%rbp), %r10d
-	movl %r10d, -12(%rbp)
-	movl $4294967295, %r10d
-	addl %r10d, -12(%rbp)
-	movl $4294967294, %r10d
-	cmpl %r10d, -12(%rbp)
-	movl $0, -28(%rbp)
-	setNE -28(%rbp)
-	cmpl $0, -28(%rbp)
-	jE Lmain.2.true
-	movl $3, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.2.end
-	Lmain.2.true:
-	Lmain.2.end:
-	movl $4294967294, %r10d
-	cmpl %r10d, -12(%rbp)
-	movl $0, -32(%rbp)
-	setNE -32(%rbp)
-	cmpl $0, -32(%rbp)
-	jE Lmain.3.true
-	movl $4, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.3.end
-	Lmain.3.true:
-	Lmain.3.end:
-	movq $-2, -40(%rbp)
-	movq -40(%rbp), %r10
-	movq %r10, -48(%rbp)
-	movq -40(%rbp), %r10
-	movq %r10, -40(%rbp)
-	addq $1, -40(%rbp)
-	cmpq $-2, -48(%rbp)
-	movq $0, -56(%rbp)
-	setNE -56(%rbp)
-	cmpq $0, -56(%rbp)
-	jE Lmain.4.true
-	movl $5, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.4.end
-	Lmain.4.true:
-	Lmain.4.end:
-	cmpq $-1, -40(%rbp)
-	movq $0, -64(%rbp)
-	setNE -64(%rbp)
-	cmpq $0, -64(%rbp)
-	jE Lmain.5.true
-	movl $6, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.5.end
-	Lmain.5.true:
-	Lmain.5.end:
-	movq -40(%rbp), %r10
-	movq %r10, -40(%rbp)
-	addq $1, -40(%rbp)
-	cmpq $0, -40(%rbp)
-	movq $0, -72(%rbp)
-	setNE -72(%rbp)
-	cmpq $0, -72(%rbp)
-	jE Lmain.6.true
-	movl $7, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.6.end
-	Lmain.6.true:
-	Lmain.6.end:
-	cmpq $0, -40(%rbp)
-	movq $0, -80(%rbp)
-	setNE -80(%rbp)
-	cmpq $0, -80(%rbp)
-	jE Lmain.7.true
-	movl $8, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.7.end
-	Lmain.7.true:
-	Lmain.7.end:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,52 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movq $4607182418800017410, %r10
-	movq %r10, %xmm14
-	movq $4607182418800017410, %r10
-	movq %r10, %xmm15
-	comisd %xmm14, %xmm15
-	movq $0, -16(%rbp)
-	setNE -16(%rbp)
-	movq $0, %r10
-	movq %r10, %xmm14
-	movsd -16(%rbp), %xmm15
-	comisd %xmm14, %xmm15
-	jE Lmain.0.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	movq $4890909195324358657, %r10
-	movq %r10, %xmm14
-	movq $4890909195324358657, %r10
-	movq %r10, %xmm15
-	comisd %xmm14, %xmm15
-	movq $0, -24(%rbp)
-	setNE -24(%rbp)
-	movq $0, %r10
-	movq %r10, %xmm14
-	movsd -24(%rbp), %xmm15
-	comisd %xmm14, %xmm15
-	jE Lmain.1.true
-	movl $2, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.1.end
-	Lmain.1.true:
-	Lmain.1.end:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
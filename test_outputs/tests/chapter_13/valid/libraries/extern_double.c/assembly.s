@@ -1,4 +0,0 @@
-	.globl _d
-	.data
-_d:
-	.quad 0x4415af1d78b58c40
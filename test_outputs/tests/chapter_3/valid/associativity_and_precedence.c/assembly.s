@@ -1,33 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $5, -12(%rbp)
-	movl -12(%rbp), %r11d
-	imull $4, %r11d
-	movl %r11d, -12(%rbp)
-	movl -12(%rbp), %eax
-	cdq
-	movl $2, %r10d
-	idivl %r10d
-	movl %eax, -16(%rbp)
-	movl $2, -20(%rbp)
-	addl $1, -20(%rbp)
-	movl $3, %eax
-	cdq
-	idivl -20(%rbp)
-	movl %edx, -24(%rbp)
-	movl -16(%rbp), %r10d
-	movl %r10d, -28(%rbp)
-	movl -24(%rbp), %r10d
-	subl %r10d, -28(%rbp)
-	movl -28(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
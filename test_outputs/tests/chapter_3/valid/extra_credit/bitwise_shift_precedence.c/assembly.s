@@ -1,26 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $4, -12(%rbp)
-	addl $12, -12(%rbp)
-	movl $40, -16(%rbp)
-	movl -16(%rbp), %r11d
-	movl -12(%rbp), %ecx
-	sall %cl, %r11d
-	movl %r11d, -16(%rbp)
-	movl -16(%rbp), %r10d
-	movl %r10d, -20(%rbp)
-	movl -20(%rbp), %r11d
-	sarl $1, %r11d
-	movl %r11d, -20(%rbp)
-	movl -20(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,26 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $0, %r11d
-	cmpl $2, %r11d
-	movl $0, -12(%rbp)
-	setLE -12(%rbp)
-	movl $0, %r11d
-	cmpl $0, %r11d
-	movl $0, -16(%rbp)
-	setLE -16(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -20(%rbp)
-	movl -16(%rbp), %r10d
-	addl %r10d, -20(%rbp)
-	movl -20(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
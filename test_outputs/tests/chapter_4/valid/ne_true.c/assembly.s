@@ -1,22 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $1, -12(%rbp)
-	negl -12(%rbp)
-	movl $2, -16(%rbp)
-	negl -16(%rbp)
-	movl -16(%rbp), %r10d
-	cmpl %r10d, -12(%rbp)
-	movl $0, -20(%rbp)
-	setNE -20(%rbp)
-	movl -20(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
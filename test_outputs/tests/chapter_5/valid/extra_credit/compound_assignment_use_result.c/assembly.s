@@ -1,37 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $1, -12(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -16(%rbp)
-	addl $3, -16(%rbp)
-	movl -16(%rbp), %r10d
-	movl %r10d, -12(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -20(%rbp)
-	cmpl $4, -12(%rbp)
-	movl $0, -24(%rbp)
-	setE -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jE Lmain.0.false
-	cmpl $4, -20(%rbp)
-	movl $0, -28(%rbp)
-	setE -28(%rbp)
-	cmpl $0, -28(%rbp)
-	jE Lmain.0.false
-	movl $1, -32(%rbp)
-	jmp Lmain.0.end
-	Lmain.0.false:
-	movl $0, -32(%rbp)
-	Lmain.0.end:
-	movl -32(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,20 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $16, %rsp
-	movl $382574, -12(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -12(%rbp)
-	movl -12(%rbp), %r11d
-	sarl $4, %r11d
-	movl %r11d, -12(%rbp)
-	movl -12(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
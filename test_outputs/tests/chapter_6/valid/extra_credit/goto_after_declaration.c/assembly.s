@@ -1,36 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $1, -12(%rbp)
-	jmp Lmain.post_declaration
-	movl $0, -12(%rbp)
-	movl -12(%rbp), %r10d
-	movl %r10d, -16(%rbp)
-	Lmain.post_declaration:
-	movl $5, -16(%rbp)
-	cmpl $1, -12(%rbp)
-	movl $0, -20(%rbp)
-	setE -20(%rbp)
-	cmpl $0, -20(%rbp)
-	jE Lmain.0.false
-	cmpl $5, -16(%rbp)
-	movl $0, -24(%rbp)
-	setE -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jE Lmain.0.false
-	movl $1, -28(%rbp)
-	jmp Lmain.0.end
-	Lmain.0.false:
-	movl $0, -28(%rbp)
-	Lmain.0.end:
-	movl -28(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
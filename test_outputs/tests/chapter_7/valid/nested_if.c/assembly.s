@@ -1,46 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $0, -12(%rbp)
-	cmpl $0, -12(%rbp)
-	jE Lmain.0.true
-	movl $2, -16(%rbp)
-	movl -16(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	movl $3, -20(%rbp)
-	movl -20(%rbp), %r10d
-	cmpl %r10d, -12(%rbp)
-	movl $0, -24(%rbp)
-	setL -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jE Lmain.1.true
-	cmpl $0, -12(%rbp)
-	movl $0, -28(%rbp)
-	setE -28(%rbp)
-	movl -28(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.1.end
-	Lmain.1.true:
-	movl $5, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	Lmain.1.end:
-	Lmain.0.end:
-	movl -12(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,44 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $0, -12(%rbp)
-	jmp Lmain.target
-	movl $5, -12(%rbp)
-	Lloop.0.cond:
-	cmpl $10, -12(%rbp)
-	movl $0, -16(%rbp)
-	setL -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jE Lloop.0
-	Lmain.target:
-	cmpl $0, -12(%rbp)
-	movl $0, -20(%rbp)
-	setE -20(%rbp)
-	cmpl $0, -20(%rbp)
-	jE Lmain.0.true
-	movl $1, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	Lloop.0.start:
-	movl -12(%rbp), %r10d
-	movl %r10d, -24(%rbp)
-	addl $1, -24(%rbp)
-	movl -24(%rbp), %r10d
-	movl %r10d, -12(%rbp)
-	jmp Lloop.0.cond
-	Lloop.0:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
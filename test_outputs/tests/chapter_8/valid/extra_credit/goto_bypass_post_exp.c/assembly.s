@@ -1,42 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $0, -12(%rbp)
-	movl $0, -16(%rbp)
-	Lloop.0.cond:
-	Lmain.lbl:
-	movl -12(%rbp), %r10d
-	movl %r10d, -20(%rbp)
-	addl $1, -20(%rbp)
-	movl -20(%rbp), %r10d
-	movl %r10d, -12(%rbp)
-	movl -16(%rbp), %r10d
-	movl %r10d, -24(%rbp)
-	addl $1, -24(%rbp)
-	movl -24(%rbp), %r10d
-	movl %r10d, -16(%rbp)
-	cmpl $10, -16(%rbp)
-	movl $0, -28(%rbp)
-	setG -28(%rbp)
-	cmpl $0, -28(%rbp)
-	jE Lmain.0.true
-	jmp Lloop.0
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	jmp Lmain.lbl
-	Lloop.0.start:
-	movl $0, -16(%rbp)
-	jmp Lloop.0.cond
-	Lloop.0:
-	movl -12(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -1,36 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $5, -12(%rbp)
-	jmp Lswitch.0.cases
-	Lswitch.0.default:
-	movl $0, -12(%rbp)
-	Lswitch.0.1:
-	movl -12(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lswitch.0
-	Lswitch.0.cases:
-	jmp Lswitch.0.default
-	movl $1, %r11d
-	cmpl $0, %r11d
-	movl $0, -16(%rbp)
-	setE -16(%rbp)
-	cmpl $0, -16(%rbp)
-	jNE Lswitch.0.1
-	Lswitch.0:
-	movl -12(%rbp), %r10d
-	movl %r10d, -20(%rbp)
-	addl $1, -20(%rbp)
-	movl -20(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
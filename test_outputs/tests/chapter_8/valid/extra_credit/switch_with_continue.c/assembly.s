@@ -1,72 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $48, %rsp
-	jmp Lswitch.0.cases
-	Lswitch.0.0:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	Lswitch.0.4:
-	movl $0, -12(%rbp)
-	movl $0, -16(%rbp)
-	Lloop.1.cond:
-	cmpl $10, -16(%rbp)
-	movl $0, -20(%rbp)
-	setL -20(%rbp)
-	cmpl $0, -20(%rbp)
-	jE Lloop.1
-	movl -16(%rbp), %eax
-	cdq
-	movl $2, %r10d
-	idivl %r10d
-	movl %edx, -24(%rbp)
-	cmpl $0, -24(%rbp)
-	jE Lmain.0.true
-	jmp Lloop.1.start
-	jmp Lmain.0.end
-	Lmain.0.true:
-	Lmain.0.end:
-	movl -12(%rbp), %r10d
-	movl %r10d, -28(%rbp)
-	addl $1, -28(%rbp)
-	movl -28(%rbp), %r10d
-	movl %r10d, -12(%rbp)
-	Lloop.1.start:
-	movl -16(%rbp), %r10d
-	movl %r10d, -32(%rbp)
-	addl $1, -32(%rbp)
-	movl -32(%rbp), %r10d
-	movl %r10d, -16(%rbp)
-	jmp Lloop.1.cond
-	Lloop.1:
-	movl -12(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	jmp Lswitch.0
-	Lswitch.0.cases:
-	movl $0, %r11d
-	cmpl $4, %r11d
-	movl $0, -36(%rbp)
-	setE -36(%rbp)
-	cmpl $0, -36(%rbp)
-	jNE Lswitch.0.0
-	movl $4, %r11d
-	cmpl $4, %r11d
-	movl $0, -36(%rbp)
-	setE -36(%rbp)
-	cmpl $0, -36(%rbp)
-	jNE Lswitch.0.4
-	Lswitch.0:
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
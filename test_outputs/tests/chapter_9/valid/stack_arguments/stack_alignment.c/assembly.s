@@ -1,39 +0,0 @@
-	.globl _main
-	.text
-_main:
-	pushq %rbp
-	movq %rsp, %rbp
-	subq $32, %rsp
-	movl $3, -12(%rbp)
-	movl $1, %edi
-	movl $2, %esi
-	movl $3, %edx
-	movl $4, %ecx
-	movl $5, %r8d
-	movl $6, %r9d
-	pushq $8
-	pushq $7
-	call _even_arguments
-	addq $16, %rsp
-	movl %eax, -16(%rbp)
-	subq $8, %rsp
-	movl $1, %edi
-	movl $2, %esi
-	movl $3, %edx
-	movl $4, %ecx
-	movl $5, %r8d
-	movl $6, %r9d
-	pushq $9
-	pushq $8
-	pushq $7
-	call _odd_arguments
-	addq $32, %rsp
-	movl %eax, -20(%rbp)
-	movl -12(%rbp), %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
-	movl $0, %eax
-	movq %rbp, %rsp
-	popq %rbp
-	ret
@@ -11,6 +11,16 @@ fn badcc() -> Command {
     cmd
 }
 
+/// The host's C compiler, for building and linking the C code that badcc's output is tested
+/// against.
+fn gcc() -> Command {
+    let mut cmd = Command::new("gcc");
+    if cfg!(target_os = "macos") {
+        cmd.args(["-arch", "x86_64"]);
+    }
+    cmd
+}
+
 #[test]
 fn test_compiles() {
     let temp = assert_fs::TempDir::new().unwrap();
@@ -19,7 +29,11 @@ fn test_compiles() {
         .write_str("int main(void) { return 2; }")
         .unwrap();
 
-    badcc().arg(input_file.as_os_str()).assert().success();
+    badcc()
+        .args(["--target", "x86_64-apple-darwin", "-S"])
+        .arg(input_file.as_os_str())
+        .assert()
+        .success();
 
     // ... do something with input_file ...
     assert_snapshot!(read_to_string(temp.child("return_2.s")).unwrap(), @r"
@@ -39,8 +53,10 @@ fn test_compiles() {
     	ret
     ");
 
+    badcc().arg(input_file.as_os_str()).assert().success();
     let exec = temp.child("return_2");
     exec.assert(path::exists());
+    #[cfg(target_os = "macos")]
     Command::new("file")
         .arg(exec.as_os_str())
         .assert()
@@ -61,7 +77,11 @@ fn test_one_plus_one() {
         .write_str("int main(void) { return (1+(1)); }")
         .unwrap();
 
-    badcc().arg(input_file.as_os_str()).assert().success();
+    badcc()
+        .args(["--target", "x86_64-apple-darwin", "-S"])
+        .arg(input_file.as_os_str())
+        .assert()
+        .success();
 
     // ... do something with input_file ...
     assert_snapshot!(read_to_string(temp.child("return_2.s")).unwrap(), @r"
@@ -83,8 +103,10 @@ fn test_one_plus_one() {
     	ret
     ");
 
+    badcc().arg(input_file.as_os_str()).assert().success();
     let exec = temp.child("return_2");
     exec.assert(path::exists());
+    #[cfg(target_os = "macos")]
     Command::new("file")
         .arg(exec.as_os_str())
         .assert()
@@ -105,7 +127,11 @@ fn test_associativity_3() {
         .write_str("int main(void) { return 6 / 3 / 2; }")
         .unwrap();
 
-    badcc().arg(input_file.as_os_str()).assert().success();
+    badcc()
+        .args(["--target", "x86_64-apple-darwin", "-S"])
+        .arg(input_file.as_os_str())
+        .assert()
+        .success();
 
     // ... do something with input_file ...
     assert_snapshot!(read_to_string(temp.child("return_1.s")).unwrap(), @r"
@@ -135,6 +161,7 @@ fn test_associativity_3() {
     	ret
     ");
 
+    badcc().arg(input_file.as_os_str()).assert().success();
     Command::new(temp.child("return_1").as_os_str())
         .assert()
         .code(1);
@@ -150,7 +177,11 @@ fn test_unop_add() {
         .write_str("int main(void) { return ~2 + 3; }")
         .unwrap();
 
-    badcc().arg(input_file.as_os_str()).assert().success();
+    badcc()
+        .args(["--target", "x86_64-apple-darwin", "-S"])
+        .arg(input_file.as_os_str())
+        .assert()
+        .success();
 
     // ... do something with input_file ...
     assert_snapshot!(read_to_string(temp.child("return_0.s")).unwrap(), @r"
@@ -175,6 +206,7 @@ fn test_unop_add() {
     	ret
     ");
 
+    badcc().arg(input_file.as_os_str()).assert().success();
     Command::new(temp.child("return_0").as_os_str())
         .assert()
         .code(0);
@@ -190,7 +222,11 @@ fn test_shifts() {
         .write_str("int main(void) { return (1 << 2) >> 1 >> (1+0); }")
         .unwrap();
 
-    badcc().arg(input_file.as_os_str()).assert().success();
+    badcc()
+        .args(["--target", "x86_64-apple-darwin", "-S"])
+        .arg(input_file.as_os_str())
+        .assert()
+        .success();
 
     // ... do something with input_file ...
     assert_snapshot!(read_to_string(temp.child("return_1.s")).unwrap(), @r"
@@ -227,9 +263,1031 @@ fn test_shifts() {
     	ret
     ");
 
+    badcc().arg(input_file.as_os_str()).assert().success();
     Command::new(temp.child("return_1").as_os_str())
         .assert()
         .code(1);
 
     temp.close().unwrap();
 }
+
+#[test]
+fn test_struct_calling_convention() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let structs = r#"
+struct mixed { int i; double d; };
+struct pair { double x; double y; };
+struct chars { char c[3]; long l; };
+struct big { long a; long b; long c; };
+"#;
+
+    // gcc is the reference for the System V ABI: it passes and returns the structures
+    // in INTEGER and SSE registers, in MEMORY, and on the stack once registers run out
+    let helper = temp.child("helper.c");
+    helper
+        .write_str(&format!(
+            r#"{structs}
+struct mixed twice(struct mixed m);
+struct big shift(struct big b, long by);
+
+struct mixed make_mixed(int i, double d) {{ struct mixed m = {{i, d}}; return m; }}
+struct pair make_pair(double x, double y) {{ struct pair p = {{x, y}}; return p; }}
+struct big make_big(long a) {{ struct big b = {{a, a * 2, a * 3}}; return b; }}
+double sum_mixed(struct mixed m) {{ return m.i + m.d; }}
+long sum_big(struct big b) {{ return b.a + b.b + b.c; }}
+long spill(long a, long b, long c, long d, long e, struct mixed m, struct chars s) {{
+    return a + b + c + d + e + m.i + (long)m.d + s.c[0] + s.c[2] + s.l;
+}}
+double spill_sse(double a, double b, double c, double d, double e, double f, double g,
+                 struct pair p, int last) {{
+    return a + b + c + d + e + f + g + p.x * 10 + p.y * 100 + last;
+}}
+int callbacks(void) {{
+    struct mixed m = {{3, 1.5}};
+    struct mixed t = twice(m);
+    struct big b = {{1, 2, 3}};
+    struct big s = shift(b, 10);
+    return t.i == 6 && t.d == 3.0 && s.a == 11 && s.b == 12 && s.c == 13;
+}}
+"#
+        ))
+        .unwrap();
+    gcc()
+        .args(["-c", "-o"])
+        .arg(temp.child("helper.o").as_os_str())
+        .arg(helper.as_os_str())
+        .assert()
+        .success();
+
+    let main = temp.child("main.c");
+    main.write_str(&format!(
+        r#"{structs}
+struct mixed make_mixed(int i, double d);
+struct pair make_pair(double x, double y);
+struct big make_big(long a);
+double sum_mixed(struct mixed m);
+long sum_big(struct big b);
+long spill(long a, long b, long c, long d, long e, struct mixed m, struct chars s);
+double spill_sse(double a, double b, double c, double d, double e, double f, double g,
+                 struct pair p, int last);
+int callbacks(void);
+
+struct mixed twice(struct mixed m) {{
+    m.i = m.i * 2;
+    m.d = m.d * 2;
+    return m;
+}}
+
+struct big shift(struct big b, long by) {{
+    b.a = b.a + by;
+    b.b = b.b + by;
+    b.c = b.c + by;
+    return b;
+}}
+
+int main(void) {{
+    struct mixed m = make_mixed(7, 0.25);
+    if (m.i != 7 || m.d != 0.25)
+        return 1;
+    struct pair p = make_pair(1.5, -2.0);
+    if (p.x != 1.5 || p.y != -2.0)
+        return 2;
+    struct big b = make_big(5);
+    if (b.a != 5 || b.b != 10 || b.c != 15)
+        return 3;
+    if (sum_mixed(m) != 7.25)
+        return 4;
+    if (sum_big(b) != 30)
+        return 5;
+    struct chars s = {{{{1, 2, 3}}, 100}};
+    if (spill(1, 2, 3, 4, 5, m, s) != 126)
+        return 6;
+    if (spill_sse(1, 2, 3, 4, 5, 6, 7, p, 9) != -148)
+        return 7;
+    if (!callbacks())
+        return 8;
+    return 0;
+}}
+"#
+    ))
+    .unwrap();
+    badcc()
+        .args(["-c", "-o"])
+        .arg(temp.child("main.o").as_os_str())
+        .arg(main.as_os_str())
+        .assert()
+        .success();
+
+    let exec = temp.child("main");
+    gcc()
+        .arg(temp.child("main.o").as_os_str())
+        .arg(temp.child("helper.o").as_os_str())
+        .arg("-o")
+        .arg(exec.as_os_str())
+        .assert()
+        .success();
+    Command::new(exec.as_os_str()).assert().success();
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_variadic_functions() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    // gcc calls the variadic functions that badcc defines, and defines one that badcc calls
+    let helper = temp.child("helper.c");
+    helper
+        .write_str(
+            r#"
+#include <stdarg.h>
+long weigh_longs(int count, ...);
+double weigh_doubles(int count, ...);
+double weigh_mixed(int count, ...);
+
+double weigh_c(int count, ...) {
+    va_list ap;
+    va_start(ap, count);
+    double total = 0;
+    for (int i = 0; i < count; i++) {
+        total += va_arg(ap, long) * (i + 1);
+        total += va_arg(ap, double) * (i + 1);
+    }
+    va_end(ap);
+    return total;
+}
+
+int call_from_c(void) {
+    return weigh_longs(10, 1L, 2L, 3L, 4L, 5L, 6L, 7L, 8L, 9L, 10L) == 385
+        && weigh_doubles(12, 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.) == 650
+        && weigh_mixed(8, 1, 1., 2, 2., 3, 3., 4, 4., 5, 5., 6, 6., 7, 7., 8, 8.) == 408;
+}
+"#,
+        )
+        .unwrap();
+    gcc()
+        .args(["-c", "-o"])
+        .arg(temp.child("helper.o").as_os_str())
+        .arg(helper.as_os_str())
+        .assert()
+        .success();
+
+    // each argument is weighted by its position, so reading them out of order changes the
+    // result; with this many arguments va_arg runs past the register save area and onto
+    // the overflow area, for integers after five and for doubles after eight
+    let main = temp.child("main.c");
+    main.write_str(
+        r#"
+#include <stdarg.h>
+double weigh_c(int count, ...);
+int call_from_c(void);
+
+long weigh_longs(int count, ...) {
+    va_list ap;
+    va_start(ap, count);
+    long total = 0;
+    for (int i = 0; i < count; i++)
+        total += va_arg(ap, long) * (i + 1);
+    va_end(ap);
+    return total;
+}
+
+double weigh_doubles(int count, ...) {
+    va_list ap;
+    va_start(ap, count);
+    double total = 0;
+    for (int i = 0; i < count; i++)
+        total += va_arg(ap, double) * (i + 1);
+    va_end(ap);
+    return total;
+}
+
+// the copy is taken part way through, and must carry on from the same argument
+// independently of the original
+double weigh_mixed(int count, ...) {
+    va_list ap;
+    va_list copy;
+    va_start(ap, count);
+    double total = va_arg(ap, int);
+    total += va_arg(ap, double);
+    va_copy(copy, ap);
+    double copied = total;
+    for (int i = 1; i < count; i++) {
+        total += va_arg(ap, int) * (i + 1);
+        total += va_arg(ap, double) * (i + 1);
+    }
+    va_end(ap);
+    for (int i = 1; i < count; i++) {
+        copied += va_arg(copy, int) * (i + 1);
+        copied += va_arg(copy, double) * (i + 1);
+    }
+    va_end(copy);
+    return total == copied ? total : -1;
+}
+
+int main(void) {
+    if (weigh_longs(10, 1L, 2L, 3L, 4L, 5L, 6L, 7L, 8L, 9L, 10L) != 385)
+        return 1;
+    if (weigh_doubles(12, 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.) != 650)
+        return 2;
+    if (weigh_mixed(8, 1, 1., 2, 2., 3, 3., 4, 4., 5, 5., 6, 6., 7, 7., 8, 8.) != 408)
+        return 3;
+    if (weigh_c(7, 1L, 1., 2L, 2., 3L, 3., 4L, 4., 5L, 5., 6L, 6., 7L, 7.) != 280)
+        return 4;
+    if (!call_from_c())
+        return 5;
+    return 0;
+}
+"#,
+    )
+    .unwrap();
+    badcc()
+        .args(["-c", "-o"])
+        .arg(temp.child("main.o").as_os_str())
+        .arg(main.as_os_str())
+        .assert()
+        .success();
+
+    let exec = temp.child("main");
+    gcc()
+        .arg(temp.child("main.o").as_os_str())
+        .arg(temp.child("helper.o").as_os_str())
+        .arg("-o")
+        .arg(exec.as_os_str())
+        .assert()
+        .success();
+    Command::new(exec.as_os_str()).assert().success();
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_linux_target() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("linux.c");
+    input_file
+        .write_str("int putchar(int c); int n = 1; int main(void) { return n ? putchar(n) : 0; }")
+        .unwrap();
+
+    badcc()
+        .args(["--target", "x86_64-linux-gnu", "--codegen"])
+        .arg(input_file.as_os_str())
+        .assert()
+        .success();
+
+    assert_snapshot!(read_to_string(temp.child("linux.s")).unwrap(), @r#"
+    	.globl n
+    	.data
+    	.balign 4
+    	.type n, @object
+    n:
+    	.long 1
+    	.size n, .-n
+    	.globl main
+    	.text
+    	.type main, @function
+    main:
+    	pushq %rbp
+    	movq %rsp, %rbp
+    	subq $16, %rsp
+    	cmpl $0, n(%rip)
+    	jE .Lmain.0.true
+    	movl n(%rip), %edi
+    	call putchar@PLT
+    	movl %eax, -12(%rbp)
+    	movl -12(%rbp), %r10d
+    	movl %r10d, -16(%rbp)
+    	jmp .Lmain.0.end
+    	.Lmain.0.true:
+    	movl $0, -16(%rbp)
+    	.Lmain.0.end:
+    	movl -16(%rbp), %eax
+    	movq %rbp, %rsp
+    	popq %rbp
+    	ret
+    	movl $0, %eax
+    	movq %rbp, %rsp
+    	popq %rbp
+    	ret
+    	.size main, .-main
+    	.section .note.GNU-stack,"",@progbits
+    "#);
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_cross_target_headers() {
+    // the host's C library headers are not searched when compiling for another platform,
+    // while the compiler's own headers still are
+    let other = if cfg!(target_os = "macos") {
+        "x86_64-linux-gnu"
+    } else {
+        "x86_64-apple-darwin"
+    };
+    badcc()
+        .args(["--target", other, "-S", "-x", "c", "-"])
+        .write_stdin("#include <stddef.h>\n#include <stdio.h>\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "stdio.h: no such file or directory",
+        ));
+    badcc()
+        .args(["--target", other, "-S", "-x", "c", "-"])
+        .write_stdin("#include <stddef.h>\nsize_t n;\n")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_integrated_assembler() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("object.c");
+    input_file
+        .write_str("int putchar(int c); int main(void) { return putchar(10); }")
+        .unwrap();
+    let object = temp.child("object.o");
+
+    badcc()
+        .args(["--target", "x86_64-linux-gnu", "-c", "-o"])
+        .arg(object.as_os_str())
+        .arg(input_file.as_os_str())
+        .assert()
+        .success();
+
+    // a relocatable x86-64 ELF object
+    let header = std::fs::read(object.path()).unwrap();
+    assert_eq!(&header[..4], b"\x7fELF");
+    assert_eq!(&header[16..20], &[1, 0, 62, 0]);
+
+    temp.close().unwrap();
+}
+
+/// Links objects from the integrated assembler with one from the system compiler, and checks
+/// that they behave as the same program assembled by the system assembler does.
+#[cfg(target_os = "linux")]
+#[test]
+fn test_integrated_assembler_runs() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let helper = temp.child("helper.c");
+    helper
+        .write_str("int shared[2] = {5, 6}; int add(int a, int b) { return a + b; }")
+        .unwrap();
+    Command::new("gcc")
+        .args(["-c", "-o"])
+        .arg(temp.child("helper.o").as_os_str())
+        .arg(helper.as_os_str())
+        .assert()
+        .success();
+
+    // the loop body is too long for short jumps back to its start or out to its end, while
+    // the branches around it are short; the data is all reached relative to %rip, and the
+    // undefined functions and array through the PLT and GOT
+    let main = temp.child("main.c");
+    main.write_str(
+        r#"
+int puts(char *s);
+int add(int a, int b);
+extern int shared[2];
+long total;
+int table[4] = {1, 2, 3, 4};
+double scale = 2.5;
+char *message = "integrated";
+
+int main(void) {
+    for (int i = 0; i < 4; i++) {
+        total += table[i] * 3 + table[(i + 1) % 4] * 5 + table[(i + 2) % 4] * 7;
+        total += table[(i + 3) % 4] * 11 + (long)(scale * table[i]);
+        total -= table[i] * table[(i + 1) % 4] - table[(i + 2) % 4] * table[(i + 3) % 4];
+    }
+    if (total < 0)
+        total = -total;
+    int (*print)(char *) = puts;
+    print(message);
+    return total + add(shared[0], shared[1]);
+}
+"#,
+    )
+    .unwrap();
+
+    for (name, flags) in [
+        ("integrated", &[][..]),
+        ("system", &["--no-integrated-as"][..]),
+    ] {
+        let object = temp.child(format!("{name}.o"));
+        badcc()
+            .args(["--target", "x86_64-linux-gnu", "-c", "-o"])
+            .arg(object.as_os_str())
+            .args(flags)
+            .arg(main.as_os_str())
+            .assert()
+            .success();
+        let exec = temp.child(name);
+        Command::new("gcc")
+            .arg(object.as_os_str())
+            .arg(temp.child("helper.o").as_os_str())
+            .arg("-o")
+            .arg(exec.as_os_str())
+            .assert()
+            .success();
+        Command::new(exec.as_os_str())
+            .assert()
+            .code((284 + 11) % 256)
+            .stdout("integrated\n");
+    }
+
+    // both forms of jump were used
+    let disassembly = Command::new("objdump")
+        .arg("-d")
+        .arg(temp.child("integrated.o").as_os_str())
+        .output()
+        .unwrap()
+        .stdout;
+    let disassembly = String::from_utf8(disassembly).unwrap();
+    assert!(disassembly.contains("\teb "), "{disassembly}");
+    assert!(disassembly.contains("\te9 "), "{disassembly}");
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_multiple_inputs() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let main_file = temp.child("main.c");
+    main_file
+        .write_str("int twice(int x); int main(void) { return twice(21); }")
+        .unwrap();
+    let twice_file = temp.child("twice.c");
+    twice_file
+        .write_str("int twice(int x) { return 2 * x; }")
+        .unwrap();
+
+    badcc()
+        .arg(main_file.as_os_str())
+        .arg(twice_file.as_os_str())
+        .assert()
+        .success();
+
+    Command::new(temp.child("main").as_os_str())
+        .assert()
+        .code(42);
+
+    badcc()
+        .args(["-c", "-o"])
+        .arg(temp.child("both.o").as_os_str())
+        .arg(main_file.as_os_str())
+        .arg(twice_file.as_os_str())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "cannot specify -o with -c and multiple input files",
+        ));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_assembly_to_stdout() {
+    let temp = assert_fs::TempDir::new().unwrap();
+
+    badcc()
+        .current_dir(temp.path())
+        .args(["--target", "x86_64-linux-gnu", "-S", "-x", "c", "-"])
+        .write_stdin("int main(void) { return 3; }")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\tmovl $3, %eax\n"));
+
+    let input_file = temp.child("header.h");
+    input_file.write_str("int f(void) { return 4; }").unwrap();
+    badcc()
+        .args(["--target", "x86_64-linux-gnu", "-S", "-x", "c", "-o", "-"])
+        .arg(input_file.as_os_str())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\tmovl $4, %eax\n"));
+
+    // nothing is left next to the sources
+    temp.child("-.s").assert(path::missing());
+    temp.child("header.s").assert(path::missing());
+
+    badcc()
+        .args(["-o", "-"])
+        .arg(input_file.as_os_str())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("-o - is only supported with -S"));
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_emit_json() {
+    let output = badcc()
+        .args(["--emit=tokens,tacky", "-x", "c", "-"])
+        .write_stdin("int main(void) {\n  return 1 + 2;\n}\n")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<_> = output.lines().collect();
+    assert_eq!(lines.len(), 2);
+
+    assert!(lines[0].starts_with(r#"{"schema":1,"stage":"tokens","file":"<stdin>","tokens":["#));
+    assert!(lines[0].contains(
+        r#"{"kind":"plus","text":"+","span":{"offset":42,"length":1,"file":"<stdin>","line":2,"column":12}}"#
+    ));
+
+    assert!(lines[1].starts_with(r#"{"schema":1,"stage":"tacky","file":"<stdin>","#));
+    assert!(lines[1].contains(
+        r#"{"kind":"binary","op":"add","lhs":{"kind":"constant","type":"int","value":1},"rhs":{"kind":"constant","type":"int","value":2}"#
+    ));
+}
+
+#[test]
+fn test_emit_text() {
+    // the text form is an outline of the same fields as the JSON
+    badcc()
+        .args(["-O", "--emit=tacky", "--emit-format=text", "-x", "c", "-"])
+        .write_stdin("int main(void) {\n  return 1 + 2;\n}\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "schema: 1\nstage: tacky\nfile: <stdin>\nstatic_variables: []\n",
+        ))
+        .stdout(predicate::str::contains(
+            "      - return { value: var { name: main.tmp.0, type: int } }\n",
+        ));
+}
+
+#[test]
+fn test_fold_constants() {
+    badcc()
+        .args(["-O", "--emit=tacky", "-x", "c", "-"])
+        .write_stdin("int main(void) { if (0) return 1; return 1 + 1; }")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#"{"kind":"copy","src":{"kind":"constant","type":"int","value":2}"#,
+        ))
+        .stdout(predicate::str::contains(r#""kind":"binary""#).not())
+        .stdout(predicate::str::contains(r#""kind":"jump_if_zero""#).not());
+}
+
+#[test]
+fn test_volatile_accesses() {
+    badcc()
+        .args(["-O", "--emit=tacky", "-x", "c", "-"])
+        .write_stdin(
+            "volatile int flag;\n\
+             int main(void) {\n\
+                 volatile int *v = &flag;\n\
+                 int *p = (int *)&flag;\n\
+                 *v = 1;\n\
+                 *p = 2;\n\
+                 return flag;\n\
+             }\n",
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#""name":"v.2","type":{"kind":"pointer","to":{"kind":"int"}}},"volatile":true}"#,
+        ))
+        .stdout(predicate::str::contains(
+            r#""name":"p.3","type":{"kind":"pointer","to":{"kind":"int"}}},"volatile":false}"#,
+        ))
+        .stdout(predicate::str::contains(
+            r#"{"kind":"copy_from_offset","src":"flag","offset":0,"#,
+        ));
+}
+
+#[test]
+fn test_line_directive_and_warning() {
+    badcc()
+        .args(["-S", "-o", "-", "-x", "c", "-"])
+        .write_stdin("#warning \"careful\"\n#line 40 \"gen.c\"\nint main(void) { x = 1; }\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("#warning \"careful\""))
+        .stderr(predicate::str::contains("gen.c:40"));
+}
+
+#[test]
+fn test_address_constants() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("addresses.c");
+    input_file
+        .write_str(
+            r#"
+struct pair { int a; long b[2]; };
+int x = 3;
+int arr[4] = {10, 20, 30, 40};
+struct pair p = {1, {2, 3}};
+int *px = &x;
+int *pa = arr;
+int *pend = &arr[3];
+int *pmid = arr + 2 - 1;
+long *pb = &p.b[1];
+char *s = "hello" + 1;
+
+int main(void) {
+    static int *local = &arr[2];
+    if (px != &x || pa != arr || pend != arr + 3 || pmid != &arr[1] || pb != p.b + 1)
+        return 1;
+    return *px + *pa + *pend + *pmid + *pb + *s + *local;
+}
+"#,
+        )
+        .unwrap();
+
+    // the system assembler is given `.quad arr+12`, and the integrated one makes an
+    // R_X86_64_64 relocation with that addend
+    for (name, flags) in [
+        ("integrated", &[][..]),
+        ("system", &["--no-integrated-as"][..]),
+    ] {
+        let exec = temp.child(name);
+        badcc()
+            .arg("-o")
+            .arg(exec.as_os_str())
+            .args(flags)
+            .arg(input_file.as_os_str())
+            .assert()
+            .success();
+        Command::new(exec.as_os_str())
+            .assert()
+            .code((3 + 10 + 40 + 20 + 3 + 'e' as i32 + 30) % 256);
+    }
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_arrays() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("arrays.c");
+    input_file
+        .write_str(
+            r#"
+long global[2][3] = {{1, 2, 3}, {4}};
+static int partial[5] = {7, 8};
+
+int main(void) {
+    int local[2][2] = {{1, 2}, {3, 4}};
+    int zeros[4] = {9};
+    double doubles[3] = {0.5, 1.5};
+    long *p = &global[0][0];
+    if (*(p + 3) != 4 || p[5] != 0)
+        return 1;
+    if (&global[1][2] - &global[0][0] != 5 || (char *)&global[1] - (char *)global != 24)
+        return 2;
+    if (sizeof global != 48 || sizeof global[1] != 24 || sizeof local != 16)
+        return 3;
+    long (*row)[3] = global + 1;
+    if ((*row)[0] != 4 || row - global != 1 || row[-1][2] != 3)
+        return 4;
+    if (partial[1] != 8 || partial[4] != 0)
+        return 5;
+    if (local[1][0] != 3 || *(*(local + 1) + 1) != 4)
+        return 6;
+    if (zeros[0] != 9 || zeros[3] != 0)
+        return 7;
+    if (doubles[1] != 1.5 || doubles[2] != 0.0)
+        return 8;
+    int *q = &partial[4];
+    q -= 2;
+    if (q[-1] != 8 || 1[q] != 0)
+        return 9;
+    p++;
+    if (*p != 2 || p > &global[0][2] || p < global[0])
+        return 10;
+    return 0;
+}
+"#,
+        )
+        .unwrap();
+
+    badcc().arg(input_file.as_os_str()).assert().success();
+    Command::new(temp.child("arrays").as_os_str())
+        .assert()
+        .success();
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_characters() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("characters.c");
+    input_file
+        .write_str(
+            r#"
+int puts(char *s);
+char message[] = "hi\tthere";
+static unsigned char bytes[3] = "\xff\001";
+
+int main(void) {
+    char c = 'A';
+    signed char s = -3;
+    unsigned char u = 250;
+    char *str = "abc\n";
+    if (c + 1 != 66 || s * 2 != -6 || u + 10 != 260)
+        return 1;
+    u = u + 10;
+    if (u != 4)
+        return 2;
+    if (sizeof message != 9 || message[2] != '\t' || message[8] != 0)
+        return 3;
+    if (bytes[0] != 255 || bytes[1] != 1 || bytes[2] != 0)
+        return 4;
+    char neg = bytes[0];
+    long wide = s;
+    if (neg != -1 || wide != -3)
+        return 5;
+    if (str[3] != 10 || sizeof "abc" != 4 || "xyz"[1] != 'y')
+        return 6;
+    if ('\101' != 'A' || '\x41' != 65 || '\'' != 39 || sizeof 'a' != 4)
+        return 7;
+    char local[4] = "ok";
+    if (local[1] != 'k' || local[2] != 0 || local[3] != 0)
+        return 8;
+    puts(message);
+    return 0;
+}
+"#,
+        )
+        .unwrap();
+
+    badcc().arg(input_file.as_os_str()).assert().success();
+    Command::new(temp.child("characters").as_os_str())
+        .assert()
+        .success()
+        .stdout("hi\tthere\n");
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_unions_and_enums() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("unions.c");
+    input_file
+        .write_str(
+            r#"
+union value { char c[5]; int i; double d; };
+union small { char c; short s; };
+struct holder { char tag; union value value; };
+enum color { RED, GREEN = 5, BLUE, LAST = 12 };
+
+enum color favourite = BLUE;
+int table[3] = {RED, GREEN, LAST};
+static union value global = {{'a', 'b'}};
+
+int classify(enum color c) {
+    switch (c) {
+    case RED:
+        return 1;
+    case GREEN:
+        return 2;
+    case BLUE:
+        return 3;
+    default:
+        return 4;
+    }
+}
+
+int first(union value v) {
+    return v.c[0];
+}
+
+int main(void) {
+    if (sizeof(union value) != 8 || sizeof(struct holder) != 16 || sizeof(union small) != 2)
+        return 1;
+    union value x;
+    x.d = 0;
+    x.i = 0x41424344;
+    if (x.c[0] != 0x44 || x.c[3] != 0x41 || x.c[4] != 0)
+        return 2;
+    if (global.c[1] != 'b' || global.c[2] != 0 || first(global) != 'a')
+        return 3;
+    if (favourite != 6 || table[1] != 5 || table[2] != 12 || sizeof(enum color) != 4)
+        return 4;
+    if (classify(RED) + classify(GREEN) * 10 + classify(favourite) * 100 + classify(7) * 1000 !=
+        4321)
+        return 5;
+    union value *p = &x;
+    p->i = 7;
+    union value copy = x;
+    if (x.i != 7 || copy.i != 7 || first(copy) != 7)
+        return 6;
+    struct holder h = {'t', {{'z'}}};
+    if (h.value.c[0] != 'z' || h.value.i != 'z')
+        return 7;
+    return 0;
+}
+"#,
+        )
+        .unwrap();
+
+    badcc().arg(input_file.as_os_str()).assert().success();
+    Command::new(temp.child("unions").as_os_str())
+        .assert()
+        .success();
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_float_calling_convention() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let structs = r#"
+struct packed { float f; int i; };
+struct floats { float a; float b; float c; };
+"#;
+
+    // floats travel in the low lanes of XMM registers, and the structures above are
+    // classified as SSE eightbytes that pack two floats or a float with an int
+    let helper = temp.child("helper.c");
+    helper
+        .write_str(&format!(
+            r#"{structs}
+float scale(float x, double y, float z);
+
+float alternate(float a, float b, float c, float d, float e, float f, float g, float h,
+                float i, float j) {{
+    return a - b + c - d + e - f + g - h + i - j * 10;
+}}
+struct packed make_packed(float f, int i) {{ struct packed p = {{f, i}}; return p; }}
+struct floats make_floats(float a, float b, float c) {{ struct floats s = {{a, b, c}}; return s; }}
+float weigh_floats(struct floats s) {{ return s.a + s.b * 10 + s.c * 100; }}
+int call_scale(void) {{ return scale(1.5f, 2.0, 0.25f) == 3.25f; }}
+"#
+        ))
+        .unwrap();
+    gcc()
+        .args(["-c", "-o"])
+        .arg(temp.child("helper.o").as_os_str())
+        .arg(helper.as_os_str())
+        .assert()
+        .success();
+
+    let main = temp.child("main.c");
+    main.write_str(&format!(
+        r#"{structs}
+float alternate(float a, float b, float c, float d, float e, float f, float g, float h,
+                float i, float j);
+struct packed make_packed(float f, int i);
+struct floats make_floats(float a, float b, float c);
+float weigh_floats(struct floats s);
+int call_scale(void);
+
+float scale(float x, double y, float z) {{
+    return x * y + z;
+}}
+
+float third = 1.0f / 3;
+
+int main(void) {{
+    float f = 0.1f;
+    double d = f;
+    if (d == 0.1 || (float)d != f || third == 1.0 / 3 || third * 3 != 1)
+        return 1;
+    if (alternate(1, 2, 3, 4, 5, 6, 7, 8, 9, 10) != -95)
+        return 2;
+    struct packed k = make_packed(0.5f, -9);
+    if (k.f != 0.5 || k.i != -9)
+        return 3;
+    struct floats s = make_floats(1, 2, 3);
+    if (s.c != 3 || weigh_floats(s) != 321)
+        return 4;
+    float big = 16777217;
+    unsigned long huge = 18446744073709551615UL;
+    float from_huge = huge;
+    if (big != 16777216 || from_huge != 18446744073709551616.0)
+        return 5;
+    int truncated = -3.9f;
+    if (truncated != -3 || -f >= 0)
+        return 6;
+    if (!call_scale())
+        return 7;
+    return 0;
+}}
+"#
+    ))
+    .unwrap();
+    badcc()
+        .args(["-c", "-o"])
+        .arg(temp.child("main.o").as_os_str())
+        .arg(main.as_os_str())
+        .assert()
+        .success();
+
+    let exec = temp.child("main");
+    gcc()
+        .arg(temp.child("main.o").as_os_str())
+        .arg(temp.child("helper.o").as_os_str())
+        .arg("-o")
+        .arg(exec.as_os_str())
+        .assert()
+        .success();
+    Command::new(exec.as_os_str()).assert().success();
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_short_long_long_and_bool() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("ints.c");
+    input_file
+        .write_str(
+            r#"
+short negate(short s) {
+    return -s;
+}
+
+unsigned short wrap(unsigned short u) {
+    return u + 1;
+}
+
+static _Bool sb = 0.5;
+long long shifted = 1LL << 40;
+
+int main(void) {
+    short s = -2;
+    unsigned short us = 65535;
+    if (s * us != -131070 || sizeof(s * us) != 4)
+        return 1;
+    unsigned short x = 1;
+    long v = -x;
+    if (v != -1 || wrap(us) != 0 || negate(-32768) != -32768)
+        return 2;
+    short narrowed = 40000;
+    if (narrowed != 40000 - 65536)
+        return 3;
+    if (sizeof(short) != 2 || sizeof(long long) != 8 || sizeof(_Bool) != 1)
+        return 4;
+    _Bool b = 256;
+    if (b != 1 || !sb)
+        return 5;
+    _Bool p = &s;
+    b = b + 1;
+    if (p != 1 || b != 1)
+        return 6;
+    b = 0.0;
+    if (b || shifted != 1099511627776LL || (shifted >> 39) != 2)
+        return 7;
+    long long m = -1;
+    unsigned short back = m;
+    if (back != 65535)
+        return 8;
+    return 0;
+}
+"#,
+        )
+        .unwrap();
+
+    badcc().arg(input_file.as_os_str()).assert().success();
+    Command::new(temp.child("ints").as_os_str())
+        .assert()
+        .success();
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_volatile_objects() {
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("volatile.c");
+    input_file
+        .write_str(
+            r#"
+struct regs { int control; volatile int status; };
+volatile int flag;
+
+int main(void) {
+    volatile int x = 1;
+    volatile int *p = &flag;
+    struct regs r = {0, 0};
+    int plain = 2;
+    *p = 3;
+    x = x + *p;
+    r.status = r.control + plain;
+    volatile int a[2] = {4, 5};
+    x++;
+    r.status += 1;
+    return x + a[1] + r.status + flag != 16;
+}
+"#,
+        )
+        .unwrap();
+
+    badcc().arg(input_file.as_os_str()).assert().success();
+    Command::new(temp.child("volatile").as_os_str())
+        .assert()
+        .success();
+
+    temp.close().unwrap();
+}